@@ -96,22 +96,51 @@ class ExecAIClient:
 
             # Build vote instruction using Anchor discriminator from IDL (vote)
             disc = bytes([227,110,155,23,136,126,172,25])
-            data = disc + (b"\x01" if approve else b"\x00")
+            rationale = b""
+            data = disc + (b"\x01" if approve else b"\x00") + len(rationale).to_bytes(4, "little") + rationale
 
+            config = json.load(open('config.json'))
+            program_id = PublicKey(self.governance_program_id)
             proposal_pk = PublicKey(proposal.get('pubkey')) if proposal.get('pubkey') else PublicKey(proposal.get('id'))
-            vote_record_kp = Keypair()
+            dao_pk = PublicKey(proposal.get('dao'))
+            registry_pk = PublicKey(config['registry'])
+            member_pk = PublicKey(config['member_account'])
+            vote_record_pk, _ = PublicKey.find_program_address(
+                [b"vote", bytes(proposal_pk), bytes(member_pk)], program_id
+            )
+            governance_signer_pk, _ = PublicKey.find_program_address(
+                [b"governance", bytes(dao_pk)], program_id
+            )
+            # EXECAI is an AI member, so its human sponsor may have to co-sign.
+            # Without one, the program ID stands in for the optional account.
+            sponsor_kp = None
+            if config.get('sponsor_keypair_path'):
+                with open(config['sponsor_keypair_path'], 'r') as f:
+                    sponsor_kp = Keypair.from_secret_key(bytes(json.load(f)))
+            sponsor_pk = sponsor_kp.public_key if sponsor_kp else program_id
 
             keys = [
+                AccountMeta(pubkey=dao_pk, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=registry_pk, is_signer=False, is_writable=False),
                 AccountMeta(pubkey=proposal_pk, is_signer=False, is_writable=True),
-                AccountMeta(pubkey=vote_record_kp.public_key, is_signer=True, is_writable=True),
+                AccountMeta(pubkey=member_pk, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=vote_record_pk, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=kp.public_key, is_signer=True, is_writable=True),
+                AccountMeta(pubkey=governance_signer_pk, is_signer=False, is_writable=False),
+                AccountMeta(pubkey=PublicKey(self.membership_program_id), is_signer=False, is_writable=False),
                 AccountMeta(pubkey=SYS_PROGRAM_ID, is_signer=False, is_writable=False),
+                AccountMeta(pubkey=sponsor_pk, is_signer=bool(sponsor_kp), is_writable=False),
+                # No epoch snapshot: the program ID stands in for the optional account.
+                AccountMeta(pubkey=program_id, is_signer=False, is_writable=False),
+                # Nor a vote commitment, which only reveal_vote takes.
+                AccountMeta(pubkey=program_id, is_signer=False, is_writable=False),
             ]
-            ix = TransactionInstruction(keys=keys, program_id=PublicKey(self.governance_program_id), data=data)
+            ix = TransactionInstruction(keys=keys, program_id=program_id, data=data)
             tx = Transaction().add(ix)
 
-            client = Client(json.load(open('config.json')).get('rpc_url', 'https://api.devnet.solana.com'))
-            resp = client.send_transaction(tx, kp, vote_record_kp)
+            client = Client(config.get('rpc_url', 'https://api.devnet.solana.com'))
+            signers = [kp, sponsor_kp] if sponsor_kp else [kp]
+            resp = client.send_transaction(tx, *signers)
             if resp.get('result') or resp.get('signature') or resp.get('result', {}).get('txid'):
                 print("Vote transaction sent:", resp)
                 return True
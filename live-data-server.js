@@ -76,7 +76,7 @@ async function main() {
         if (!Buffer.isBuffer(data)) continue;
         try {
           let o = { offset: 8 };
-          // Proposal starts with id u64, not a pubkey
+          const dao = new PublicKey(data.slice(o.offset, o.offset + 32)); o.offset += 32;
           const id = readU64LE(data, o.offset); o.offset += 8;
           // Title
           const title = readStr(data, o);
@@ -87,10 +87,11 @@ async function main() {
           const votesAgainst = readU64LE(data, o.offset); o.offset += 8;
           const statusIdx = data.readUInt8(o.offset); o.offset += 1;
           const createdAt = readI64LE(data, o.offset); o.offset += 8;
+          const votingEndsAt = readI64LE(data, o.offset); o.offset += 8;
           // Heuristic: titles are ascii-ish and not too long
           if (title && title.length < 256 && description.length < 1024){
             results.push({
-              pubkey: pubkey.toBase58(), id, title, description, amount, proposer: proposer.toBase58(), votesFor, votesAgainst, status: statusIdx, createdAt
+              pubkey: pubkey.toBase58(), dao: dao.toBase58(), id, title, description, amount, proposer: proposer.toBase58(), votesFor, votesAgainst, status: statusIdx, createdAt, votingEndsAt
             });
           }
         } catch(_e){ /* not a proposal; skip */ }
@@ -1,17 +1,85 @@
+// The IDL instructions generated by `#[program]` still call `AccountInfo::realloc`.
+#![allow(deprecated)]
+// `create_proposal` takes each proposal setting as its own argument.
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program::{self, CreateAccount, Transfer};
+use membership::program::Membership;
+use membership::bps;
+use membership::{
+    AuditLog, DecayConfig, EventVerbosity, Member, MemberRegistry, MemberType, PowerTier,
+    RegistryField, Snapshot, SnapshotEntry, SpendingCategory,
+};
 
 declare_id!("6amHFyNoPK9MmbBKqthLMeoxTB4TV7CdVE5K4RXi1eDC");
 
+pub mod proposal;
+
+use proposal::{
+    current_result, electorate_power, is_decided, meets_quorum, outcome, quorum_base, tally,
+    voting_closes_at,
+};
+pub use proposal::{compute_result, Proposal, ProposalResult, ProposalStatus, VoteRecord};
+
+pub const DEFAULT_QUORUM_BPS: u16 = 2_000;
+pub const DEFAULT_THRESHOLD_BPS: u16 = 5_000;
+pub const DEFAULT_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+/// Shortest window a proposal stays open, even if the authority closes early.
+pub const DEFAULT_MIN_VOTING_SECS: i64 = 60 * 60;
+/// Longest minimum voting window a `SetConfig` proposal may set.
+pub const MAX_MIN_VOTING_SECS: i64 = 30 * 24 * 60 * 60;
+/// Longest reveal window a `SetConfig` proposal may set.
+pub const MAX_REVEAL_SECS: i64 = 30 * 24 * 60 * 60;
+/// How long a finished proposal stays on-chain before its rent can be reclaimed.
+pub const DEFAULT_RETENTION_PERIOD: i64 = 30 * 24 * 60 * 60;
+/// Delay between a proposal passing and it becoming executable.
+pub const DEFAULT_TIMELOCK: i64 = 24 * 60 * 60;
+pub const MAX_MULTISIG_SIGNERS: usize = 5;
+pub const DEFAULT_MAX_EXECUTION_ATTEMPTS: u8 = 3;
+pub const MAX_PROPOSAL_OPTIONS: usize = 8;
+pub const MAX_OPTION_LEN: usize = 64;
+/// Longest reason a voter may attach to their vote.
+pub const MAX_RATIONALE_LEN: usize = 140;
+/// Most members that may co-sponsor a single proposal.
+pub const MAX_COSPONSORS: usize = 5;
+/// Longest prefix of a proposal's human-readable reference.
+pub const MAX_PROPOSAL_PREFIX_LEN: usize = 16;
+pub const DEFAULT_PROPOSAL_PREFIX: &str = "PROP";
+/// Longest proposal reference: the prefix, a four-digit year and a u64 ID,
+/// separated by dashes.
+pub const MAX_PROPOSAL_REFERENCE_LEN: usize = MAX_PROPOSAL_PREFIX_LEN + 1 + 4 + 1 + 20;
+/// Most members a proposal's voter allowlist may name.
+pub const MAX_ALLOWLIST_LEN: usize = 10;
+/// Most proposal IDs `preallocate_proposals` can reserve at once.
+pub const MAX_RESERVED_PROPOSALS: u64 = 64;
+/// Longest comment a member may post on a proposal.
+pub const MAX_COMMENT_LEN: usize = 280;
+/// Longest state filing ID the DAO can record for its legal entity.
+pub const MAX_LEGAL_ENTITY_ID_LEN: usize = 64;
+/// Longest jurisdiction that fits the DAO account, after its length prefix.
+pub const MAX_JURISDICTION_LEN: usize = 64 - 4;
+
 #[program]
 pub mod governance {
     use super::*;
 
+    /// Creates the DAO. `legal_entity_id` and `jurisdiction` tie it to its
+    /// state filing; afterwards only a passed `SetLegalInfo` proposal can
+    /// change them.
     pub fn initialize(
         ctx: Context<Initialize>, 
         legal_name: String,
         registered_agent_address: String,
         principal_place_of_business: String,
+        legal_entity_id: String,
+        jurisdiction: String,
     ) -> Result<()> {
+        validate_legal_info(&legal_entity_id, &jurisdiction)?;
         let dao = &mut ctx.accounts.dao;
         dao.authority = ctx.accounts.authority.key();
         dao.proposal_count = 0;
@@ -20,126 +88,2810 @@ pub mod governance {
         dao.registered_agent_address = registered_agent_address;
         dao.principal_place_of_business = principal_place_of_business;
         dao.formation_date = Clock::get()?.unix_timestamp;
-        dao.jurisdiction = "Wyoming".to_string();
+        dao.jurisdiction = jurisdiction;
         dao.entity_type = "DAO LLC".to_string();
+        dao.registry = Pubkey::default();
+        dao.quorum_bps = DEFAULT_QUORUM_BPS;
+        dao.threshold_bps = DEFAULT_THRESHOLD_BPS;
+        dao.voting_period = DEFAULT_VOTING_PERIOD;
+        dao.min_voting_secs = DEFAULT_MIN_VOTING_SECS;
+        dao.max_execution_attempts = DEFAULT_MAX_EXECUTION_ATTEMPTS;
+        dao.retention_period = DEFAULT_RETENTION_PERIOD;
+        dao.timelock = DEFAULT_TIMELOCK;
+        dao.multisig_signers = Vec::new();
+        dao.multisig_threshold = 0;
+        dao.max_open_proposals = 0;
+        dao.open_proposal_count = 0;
+        dao.quadratic_voting = false;
+        dao.quad_rounding = QuadRounding::Floor;
+        dao.min_cosponsors = 0;
+        dao.proposal_bond = 0;
+        dao.early_close = false;
+        dao.proposal_prefix = DEFAULT_PROPOSAL_PREFIX.to_string();
+        dao.current_epoch = 0;
+        dao.epoch_snapshot = Pubkey::default();
+        dao.epoch_root = [0; 32];
+        dao.epoch_total_power = 0;
+        dao.reveal_secs = 0;
+        dao.event_verbosity = EventVerbosity::All;
+        dao.legal_entity_id = legal_entity_id;
+        dao.event_seq = 0;
+        dao.reserved_start = 0;
+        dao.reserved_count = 0;
+        dao.reserved_taken = 0;
+        dao.keeper_reward = 0;
+        dao.max_keeper_reward = 0;
+        dao.keeper_pool = 0;
+        Ok(())
+    }
+
+    /// Starts the next voting-power epoch from a membership `Snapshot` of
+    /// the DAO's registry. Proposals created during the epoch are weighed
+    /// by the snapshot's powers instead of live ones. The snapshot's hash
+    /// is kept as the epoch's root so its weights can be audited later.
+    pub fn advance_epoch(ctx: Context<AdvanceEpoch>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let snapshot = &ctx.accounts.snapshot;
+        let dao = &mut ctx.accounts.dao;
+        require!(snapshot.key() != dao.epoch_snapshot, ErrorCode::EpochSnapshotMismatch);
+
+        let root = hash(&anchor_lang::prelude::borsh::to_vec(&snapshot.entries)?).to_bytes();
+        dao.current_epoch += 1;
+        dao.epoch_snapshot = snapshot.key();
+        dao.epoch_root = root;
+        dao.epoch_total_power = snapshot
+            .entries
+            .iter()
+            .map(|entry| snapshot_power(entry, registry.ai_power_multiplier_bps))
+            .fold(0u64, u64::saturating_add);
+
+        if dao.logs(EventVerbosity::All) {
+            emit!(EpochAdvanced {
+                dao: dao.key(),
+                epoch: dao.current_epoch,
+                snapshot: snapshot.key(),
+                root,
+                sequence: dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Sets the prefix new proposals' references start with. Existing
+    /// references keep the prefix they were created with.
+    pub fn set_proposal_prefix(ctx: Context<SetProposalPrefix>, prefix: String) -> Result<()> {
+        require!(
+            !prefix.is_empty()
+                && prefix.len() <= MAX_PROPOSAL_PREFIX_LEN
+                && prefix.bytes().all(|b| b.is_ascii_alphanumeric()),
+            ErrorCode::InvalidProposalPrefix
+        );
+        ctx.accounts.dao.proposal_prefix = prefix;
+        Ok(())
+    }
+
+    /// Sets the signers allowed to execute passed proposals during an emergency.
+    pub fn set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(signers.len() <= MAX_MULTISIG_SIGNERS, ErrorCode::InvalidMultisig);
+        require!(
+            threshold >= 1 && threshold as usize <= signers.len(),
+            ErrorCode::InvalidMultisig
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(!signers[..i].contains(signer), ErrorCode::InvalidMultisig);
+        }
+
+        let dao = &mut ctx.accounts.dao;
+        dao.multisig_signers = signers;
+        dao.multisig_threshold = threshold;
+        Ok(())
+    }
+
+    /// Links the DAO to the membership registry whose members it governs.
+    pub fn link_registry(ctx: Context<LinkRegistry>) -> Result<()> {
+        ctx.accounts.dao.registry = ctx.accounts.registry.key();
+        Ok(())
+    }
+
+    /// Opens a proposal. A proposal restricted by `eligibility` needs the
+    /// DAO's registry, and an allowlist also needs each listed member's
+    /// account in `remaining_accounts`, in list order, so the power its
+    /// quorum is measured against can be fixed now.
+    pub fn create_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateProposal<'info>>,
+        title: String,
+        description: String,
+        amount: u64,
+        payload: ExecutionPayload,
+        quorum_bps_override: Option<u16>,
+        category: SpendingCategory,
+        eligibility: VoterEligibility,
+    ) -> Result<()> {
+        let dao = &mut ctx.accounts.dao;
+        let id = dao.proposal_count;
+        open_proposal(
+            dao,
+            &mut ctx.accounts.proposal,
+            id,
+            ctx.accounts.proposer.key(),
+            ctx.accounts.registry.as_ref(),
+            ctx.remaining_accounts,
+            title,
+            description,
+            amount,
+            payload,
+            quorum_bps_override,
+            category,
+            eligibility,
+        )?;
+        escrow_bond(
+            &ctx.accounts.proposal.to_account_info(),
+            ctx.accounts.proposal.bond,
+            &ctx.accounts.proposer,
+            &ctx.accounts.system_program,
+        )?;
+        ctx.accounts.dao.proposal_count += 1;
+
         Ok(())
     }
 
-    pub fn create_proposal(
-        ctx: Context<CreateProposal>,
+    /// Reserves the next `count` proposal IDs, so a planned run of
+    /// proposals keeps its numbering however many others are created
+    /// meanwhile. Each uninitialized account in `remaining_accounts`, which
+    /// must sign, becomes a `Pending` placeholder for the next reserved ID
+    /// in turn. A new reservation replaces the last one; placeholders
+    /// already created stay fillable.
+    pub fn preallocate_proposals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PreallocateProposals<'info>>,
+        count: u64,
+    ) -> Result<()> {
+        require!(
+            (1..=MAX_RESERVED_PROPOSALS).contains(&count)
+                && ctx.remaining_accounts.len() as u64 <= count,
+            ErrorCode::InvalidReservation
+        );
+        let dao = &mut ctx.accounts.dao;
+        dao.reserved_start = dao.proposal_count;
+        dao.reserved_count = count;
+        dao.reserved_taken = 0;
+        dao.proposal_count += count;
+
+        let start = dao.reserved_start;
+        for (id, account) in (start..).zip(ctx.remaining_accounts) {
+            ctx.accounts.dao.take_reserved_id(id)?;
+            create_placeholder(
+                account,
+                ctx.accounts.dao.key(),
+                id,
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Gives reserved proposal `proposal_id` its terms and opens it for
+    /// voting, as `create_proposal` would with the same arguments.
+    /// `proposal` is the ID's `Pending` placeholder, or a new account that
+    /// signs if it has none yet.
+    pub fn fill_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FillProposal<'info>>,
+        proposal_id: u64,
         title: String,
         description: String,
         amount: u64,
+        payload: ExecutionPayload,
+        quorum_bps_override: Option<u16>,
+        category: SpendingCategory,
+        eligibility: VoterEligibility,
+    ) -> Result<()> {
+        let info = ctx.accounts.proposal.to_account_info();
+        if info.owner != &crate::ID {
+            ctx.accounts.dao.take_reserved_id(proposal_id)?;
+            create_placeholder(
+                &info,
+                ctx.accounts.dao.key(),
+                proposal_id,
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+            )?;
+        }
+        require!(info.owner == &crate::ID, ErrorCode::ProposalMismatch);
+        let mut proposal = Proposal::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            proposal.dao == ctx.accounts.dao.key() && proposal.id == proposal_id,
+            ErrorCode::ProposalMismatch
+        );
+        proposal.status.advance(ProposalStatus::Active)?;
+
+        open_proposal(
+            &mut ctx.accounts.dao,
+            &mut proposal,
+            proposal_id,
+            ctx.accounts.authority.key(),
+            ctx.accounts.registry.as_ref(),
+            ctx.remaining_accounts,
+            title,
+            description,
+            amount,
+            payload,
+            quorum_bps_override,
+            category,
+            eligibility,
+        )?;
+        escrow_bond(&info, proposal.bond, &ctx.accounts.authority, &ctx.accounts.system_program)?;
+        proposal.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+
+    /// Casts the voter's weight, plus that of every member in
+    /// `remaining_accounts` whose delegation chain resolves to the voter.
+    /// Intermediate links of a chain must be passed as well. `rationale` may
+    /// be empty.
+    ///
+    /// A member with an Ethereum address may instead authorize the vote by
+    /// signing `eth_vote_message` in a Secp256k1 instruction placed just
+    /// before this one, passing the Instructions sysvar; `voter` then only
+    /// pays for the vote record.
+    pub fn vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+        support: bool,
+        rationale: String,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        require!(proposal.reveal_secs == 0, ErrorCode::CommitRevealRequired);
+        require!(
+            Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+            ErrorCode::VotingEnded
+        );
+        require!(rationale.len() <= MAX_RATIONALE_LEN, ErrorCode::RationaleTooLong);
+
+        cast_vote(ctx, support, rationale)
+    }
+
+    /// Commits to a vote on a commit-reveal proposal without disclosing it.
+    /// `commitment` is `vote_commitment(support, salt)`; the vote only counts
+    /// once `reveal_vote` discloses a matching choice and salt.
+    pub fn commit_vote(ctx: Context<CommitVote>, proposal_id: u64, commitment: [u8; 32]) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        let member = &ctx.accounts.member;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        require!(proposal.reveal_secs > 0, ErrorCode::NotCommitReveal);
+        require!(
+            Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+            ErrorCode::VotingEnded
+        );
+        require!(
+            proposal.cosponsors.len() >= ctx.accounts.dao.min_cosponsors as usize,
+            ErrorCode::InsufficientCosponsors
+        );
+        membership::check_vote_eligibility(&ctx.accounts.registry, member, proposal.created_at)?;
+        require!(
+            proposal.eligibility.admits(member),
+            membership::MembershipError::NotEligibleForProposal
+        );
+
+        let record = &mut ctx.accounts.commitment;
+        record.proposal = proposal.key();
+        record.voter = ctx.accounts.voter.key();
+        record.commitment = commitment;
+        Ok(())
+    }
+
+    /// Moves a commit-reveal proposal whose voting window has ended into its
+    /// reveal window. Anyone may call this, so a keeper can advance the
+    /// proposal without the authority.
+    pub fn begin_reveal_phase(ctx: Context<BeginRevealPhase>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        require!(proposal.reveal_secs > 0, ErrorCode::NotCommitReveal);
+        require!(proposal.reveal_ends_at == 0, ErrorCode::RevealPhaseStarted);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= proposal.voting_ends_at, ErrorCode::VotingNotEnded);
+
+        proposal.reveal_ends_at = now
+            .checked_add(proposal.reveal_secs)
+            .ok_or(ErrorCode::RevealWindowOverflow)?;
+        if ctx.accounts.dao.logs(EventVerbosity::All) {
+            emit!(RevealPhaseBegun {
+                dao: ctx.accounts.dao.key(),
+                proposal_id,
+                reveal_ends_at: proposal.reveal_ends_at,
+                sequence: ctx.accounts.dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Discloses a committed vote during the reveal window and tallies it with
+    /// the voter's weight at reveal time, taking the same accounts as `vote`
+    /// plus the commitment. Votes still unrevealed when the reveal window
+    /// closes are not counted.
+    pub fn reveal_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+        proposal_id: u64,
+        support: bool,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        require!(proposal.reveal_secs > 0, ErrorCode::NotCommitReveal);
+        require!(
+            proposal.reveal_ends_at > 0 && Clock::get()?.unix_timestamp < proposal.reveal_ends_at,
+            ErrorCode::RevealNotOpen
+        );
+
+        let record = ctx
+            .accounts
+            .commitment
+            .as_ref()
+            .filter(|record| {
+                record.proposal == proposal.key() && record.voter == ctx.accounts.voter.key()
+            })
+            .ok_or(ErrorCode::CommitmentMismatch)?;
+        require!(
+            record.commitment == vote_commitment(support, &salt),
+            ErrorCode::InvalidReveal
+        );
+
+        cast_vote(ctx, support, String::new())
+    }
+
+    /// Adds the signing member as a co-sponsor of an active proposal. Votes
+    /// are refused until a proposal has `min_cosponsors` of them.
+    pub fn cosponsor_proposal(ctx: Context<CosponsorProposal>, proposal_id: u64) -> Result<()> {
+        let proposal: &mut Proposal = &mut ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        add_cosponsor(
+            &mut proposal.cosponsors,
+            &proposal.status,
+            proposal.proposer,
+            ctx.accounts.cosponsor.key(),
+        )
+    }
+
+    /// Posts a member's comment on a proposal as the next record in its
+    /// thread, at `[b"comment", proposal, index]` with indexes counting up
+    /// from 0. Comments cannot be edited or removed.
+    pub fn add_comment(ctx: Context<AddComment>, proposal_id: u64, text: String) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(text.len() <= MAX_COMMENT_LEN, ErrorCode::CommentTooLong);
+
+        let comment = &mut ctx.accounts.comment;
+        comment.proposal = proposal.key();
+        comment.index = proposal.comment_count;
+        comment.author = ctx.accounts.author.key();
+        comment.posted_at = Clock::get()?.unix_timestamp;
+        comment.text = text;
+
+        proposal.comment_count += 1;
+        Ok(())
+    }
+
+    /// Withdraws an active proposal that has no votes yet. Only the proposer
+    /// may cancel, and any bond they posted is returned.
+    pub fn cancel_proposal(ctx: Context<CancelProposal>, proposal_id: u64) -> Result<()> {
+        let dao = &mut ctx.accounts.dao;
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        require!(
+            proposal.votes_for == 0 && proposal.votes_against == 0,
+            ErrorCode::ProposalHasVotes
+        );
+
+        if proposal.bond_state == BondState::Held {
+            release_bond(
+                &proposal.to_account_info(),
+                proposal.bond,
+                &ctx.accounts.proposer.to_account_info(),
+            )?;
+            proposal.bond_state = BondState::Refunded;
+        }
+        proposal.status.advance(ProposalStatus::Cancelled)?;
+        proposal.closed_at = Clock::get()?.unix_timestamp;
+        dao.release_open_slot()?;
+
+        if dao.logs(EventVerbosity::All) {
+            emit!(ProposalClosed {
+                dao: dao.key(),
+                proposal_id: proposal.id,
+                status: ProposalStatus::Cancelled,
+                sequence: dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a held bond to the proposer once the proposal has been tallied
+    /// and reached quorum, whether or not it passed.
+    pub fn refund_bond(ctx: Context<SettleBond>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        check_bond_refundable(&proposal.bond_state, &proposal.status, proposal.quorum_met)?;
+        require_keys_eq!(
+            ctx.accounts.proposer.key(),
+            proposal.proposer,
+            ErrorCode::InvalidBondRecipient
+        );
+
+        release_bond(
+            &proposal.to_account_info(),
+            proposal.bond,
+            &ctx.accounts.proposer.to_account_info(),
+        )?;
+        ctx.accounts.proposal.bond_state = BondState::Refunded;
+        Ok(())
+    }
+
+    /// Moves the held bond of a proposal rejected without reaching quorum to
+    /// the DAO treasury, where it funds keeper rewards. Anyone may call this.
+    pub fn forfeit_bond(ctx: Context<SettleBond>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        check_bond_forfeitable(&proposal.bond_state, &proposal.status, proposal.quorum_met)?;
+
+        let amount = proposal.bond;
+        release_bond(
+            &proposal.to_account_info(),
+            amount,
+            &ctx.accounts.treasury.to_account_info(),
+        )?;
+        ctx.accounts.proposal.bond_state = BondState::Forfeited;
+        let dao = &mut ctx.accounts.dao;
+        dao.keeper_pool = dao.keeper_pool.saturating_add(amount);
+        if ctx.accounts.dao.logs(EventVerbosity::All) {
+            emit!(TreasuryDeposit {
+                dao: ctx.accounts.dao.key(),
+                amount,
+                mint: None,
+                balance_after: ctx.accounts.treasury.lamports(),
+                sequence: ctx.accounts.dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Tallies a proposal once its voting period is over. The DAO authority
+    /// may close early, but not inside the minimum voting window.
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        let dao = &mut ctx.accounts.dao;
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        check_closable(
+            dao,
+            ctx.accounts.closer.key(),
+            proposal.created_at,
+            voting_closes_at(proposal),
+        )?;
+
+        let total_voting_power = quorum_base(proposal, &ctx.accounts.registry);
+        tally(dao, proposal, total_voting_power, Clock::get()?.unix_timestamp)
+    }
+
+    /// Names a program to be told the proposal's result once it is closed,
+    /// or clears it. Only the proposer may set it, while voting is open.
+    /// Closing never calls the program itself; it marks the callback
+    /// pending for `notify_callback` to deliver.
+    pub fn set_callback_program(
+        ctx: Context<SetCallbackProgram>,
+        proposal_id: u64,
+        callback_program: Option<Pubkey>,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        proposal.callback_program = callback_program;
+        Ok(())
+    }
+
+    /// Delivers a closed proposal's pending callback by calling
+    /// `proposal_result_callback` on its callback program, which must be
+    /// passed as `callback_program`, with the proposal account read-only.
+    /// A callback that fails fails this instruction and leaves the callback
+    /// pending, so it can be retried; the close itself is never held up.
+    /// Anyone may call this.
+    pub fn notify_callback(ctx: Context<NotifyCallback>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.callback_pending, ErrorCode::NoCallbackPending);
+
+        let mut data = hash(b"global:proposal_result_callback").to_bytes()[..8].to_vec();
+        ProposalResultCallback {
+            dao: proposal.dao,
+            proposal_id: proposal.id,
+            result: proposal.status.clone(),
+        }
+        .serialize(&mut data)?;
+        invoke(
+            &Instruction {
+                program_id: ctx.accounts.callback_program.key(),
+                accounts: vec![AccountMeta::new_readonly(proposal.key(), false)],
+                data,
+            },
+            &[proposal.to_account_info(), ctx.accounts.callback_program.to_account_info()],
+        )?;
+        ctx.accounts.proposal.callback_pending = false;
+        Ok(())
+    }
+
+    /// Tallies every proposal in `remaining_accounts` whose voting period is
+    /// over, skipping those still open or already closed. Anyone may call
+    /// this, since it only enforces deadlines that have already passed.
+    ///
+    /// The keeper earns `keeper_reward` for each proposal closed, up to
+    /// `max_keeper_reward` per call. Rewards are paid from the treasury, but
+    /// only out of `keeper_pool`, the forfeited bonds not yet paid out, and
+    /// never below the treasury's rent-exempt minimum and
+    /// `treasury_min_balance`.
+    pub fn close_expired_proposals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseExpiredProposals<'info>>,
+    ) -> Result<()> {
+        let dao = &mut ctx.accounts.dao;
+        let registry = &ctx.accounts.registry;
+        let now = Clock::get()?.unix_timestamp;
+
+        let mut closed = 0u32;
+        let mut skipped = 0u32;
+        for info in ctx.remaining_accounts.iter() {
+            let mut proposal = Account::<Proposal>::try_from(info)?;
+            require_keys_eq!(proposal.dao, dao.key(), ErrorCode::ProposalMismatch);
+
+            if proposal.status != ProposalStatus::Active || now < voting_closes_at(&proposal) {
+                skipped += 1;
+                continue;
+            }
+            let total_voting_power = quorum_base(&proposal, registry);
+            tally(dao, &mut proposal, total_voting_power, now)?;
+            proposal.exit(&crate::ID)?;
+            closed += 1;
+        }
+
+        if dao.logs(EventVerbosity::All) {
+            msg!("Closed {} expired proposals, skipped {}", closed, skipped);
+        }
+
+        let mut reward = dao.keeper_reward.saturating_mul(closed as u64);
+        if dao.max_keeper_reward > 0 {
+            reward = reward.min(dao.max_keeper_reward);
+        }
+        let floor = Rent::get()?
+            .minimum_balance(0)
+            .saturating_add(registry.treasury_min_balance);
+        reward = reward
+            .min(dao.keeper_pool)
+            .min(ctx.accounts.treasury.lamports().saturating_sub(floor));
+        if reward > 0 {
+            dao.keeper_pool -= reward;
+            let dao_key = dao.key();
+            let seeds: &[&[u8]] = &[b"treasury", dao_key.as_ref(), &[ctx.bumps.treasury]];
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.treasury.to_account_info(),
+                        to: ctx.accounts.keeper.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                reward,
+            )?;
+            if dao.logs(EventVerbosity::All) {
+                emit!(TreasuryWithdraw {
+                    dao: dao_key,
+                    amount: reward,
+                    mint: None,
+                    balance_after: ctx.accounts.treasury.lamports(),
+                    sequence: dao.next_event_seq(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Opens a proposal choosing among `options`, under the same rules as
+    /// `create_proposal`: it takes the DAO's bond, is restricted by
+    /// `eligibility`, belongs to the current epoch and keeps the DAO's
+    /// quorum and vote mode as they are now.
+    pub fn create_multi_choice_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateMultiChoiceProposal<'info>>,
+        title: String,
+        description: String,
+        options: Vec<String>,
+        eligibility: VoterEligibility,
     ) -> Result<()> {
+        require!(
+            (2..=MAX_PROPOSAL_OPTIONS).contains(&options.len())
+                && options.iter().all(|option| option.len() <= MAX_OPTION_LEN),
+            ErrorCode::InvalidOptions
+        );
+
         let dao = &mut ctx.accounts.dao;
+        let eligible_power = take_open_slot(
+            dao,
+            ctx.accounts.registry.as_ref(),
+            ctx.remaining_accounts,
+            &eligibility,
+            None,
+        )?;
         let proposal = &mut ctx.accounts.proposal;
+        let now = Clock::get()?.unix_timestamp;
 
+        proposal.dao = dao.key();
         proposal.id = dao.proposal_count;
         proposal.title = title;
         proposal.description = description;
-        proposal.amount = amount;
         proposal.proposer = ctx.accounts.proposer.key();
-        proposal.votes_for = 0;
-        proposal.votes_against = 0;
+        proposal.tallies = vec![0; options.len()];
+        proposal.options = options;
         proposal.status = ProposalStatus::Active;
-        proposal.created_at = Clock::get()?.unix_timestamp;
-
+        proposal.created_at = now;
+        proposal.voting_ends_at = voting_deadline(dao, now)?;
+        proposal.closed_at = 0;
+        proposal.winning_option = None;
+        proposal.reference = proposal_reference(&dao.proposal_prefix, now, proposal.id);
+        proposal.bond = dao.proposal_bond;
+        proposal.bond_state = if proposal.bond > 0 { BondState::Held } else { BondState::None };
+        proposal.quorum_met = false;
+        proposal.cosponsors = Vec::new();
+        proposal.eligibility = eligibility;
+        proposal.eligible_power = eligible_power;
+        proposal.epoch = dao.current_epoch;
+        proposal.epoch_snapshot = (dao.current_epoch > 0).then_some(dao.epoch_snapshot);
+        proposal.epoch_total_power = dao.epoch_total_power;
+        proposal.quorum_bps = dao.quorum_bps;
+        proposal.vote_mode = dao.vote_mode();
+        proposal.threshold_bps = dao.threshold_bps;
         dao.proposal_count += 1;
 
-        Ok(())
+        escrow_bond(
+            &ctx.accounts.proposal.to_account_info(),
+            ctx.accounts.proposal.bond,
+            &ctx.accounts.proposer,
+            &ctx.accounts.system_program,
+        )
+    }
+
+    /// Adds the signing member as a co-sponsor of an active multi-choice
+    /// proposal, as `cosponsor_proposal` does for a binary one.
+    pub fn cosponsor_multi_choice_proposal(
+        ctx: Context<CosponsorMultiChoiceProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let proposal: &mut MultiChoiceProposal = &mut ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        add_cosponsor(
+            &mut proposal.cosponsors,
+            &proposal.status,
+            proposal.proposer,
+            ctx.accounts.cosponsor.key(),
+        )
     }
 
-    pub fn vote(ctx: Context<Vote>, support: bool) -> Result<()> {
+    /// Casts the voter's weight for one option, plus that of every member
+    /// in `remaining_accounts` whose delegation chain resolves to the voter,
+    /// with the same checks and weighting as `vote`.
+    pub fn cast_multi_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastMultiVote<'info>>,
+        proposal_id: u64,
+        option_index: u8,
+    ) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
         let vote_record = &mut ctx.accounts.vote_record;
+        let member = &ctx.accounts.member;
+
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+        require!(
+            Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+            ErrorCode::VotingEnded
+        );
+        require!(
+            (option_index as usize) < proposal.options.len(),
+            ErrorCode::InvalidOptionIndex
+        );
+        check_voter(
+            &ctx.accounts.dao,
+            &ctx.accounts.registry,
+            member,
+            ctx.accounts.sponsor.as_ref(),
+            &proposal.cosponsors,
+            proposal.created_at,
+            &proposal.eligibility,
+        )?;
+
+        let delegators = membership::split_member_signers(
+            member,
+            &ctx.accounts.voter.key(),
+            ctx.remaining_accounts,
+        )?;
+        let weight = proposal_weight(
+            &proposal.vote_mode,
+            member,
+            &ctx.accounts.registry,
+            delegators,
+            proposal.created_at,
+            &proposal.eligibility,
+            proposal.epoch_snapshot,
+            ctx.accounts.epoch_snapshot.as_ref(),
+        )?;
+        record_activity(
+            &ctx.accounts.dao,
+            &ctx.accounts.registry,
+            member,
+            proposal.id,
+            &ctx.accounts.governance_signer,
+            ctx.bumps.governance_signer,
+            &ctx.accounts.membership_program,
+        )?;
+        proposal.tallies[option_index as usize] += weight;
+
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.option_index = option_index;
+        vote_record.weight = weight;
+
+        Ok(())
+    }
+
+    /// Picks the option with the most weighted votes. The proposal is
+    /// rejected if quorum is missed or the leader holds no more than
+    /// `threshold_bps` of its and the runner-up's votes combined; at the
+    /// default half, a tie never passes. Quorum, threshold and electorate are
+    /// those the proposal was opened with.
+    pub fn close_multi_choice_proposal(ctx: Context<CloseMultiChoiceProposal>) -> Result<()> {
+        let dao = &mut ctx.accounts.dao;
+        let proposal = &mut ctx.accounts.proposal;
 
         require!(proposal.status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
-        require!(!vote_record.has_voted, ErrorCode::AlreadyVoted);
+        check_closable(
+            dao,
+            ctx.accounts.closer.key(),
+            proposal.created_at,
+            proposal.voting_ends_at,
+        )?;
 
-        if support {
-            proposal.votes_for += 1;
+        let cast = proposal.tallies.iter().fold(0u64, |sum, &tally| sum.saturating_add(tally));
+        let (top, leaders) = leading_options(&proposal.tallies);
+        let total_voting_power = electorate_power(
+            &proposal.eligibility,
+            proposal.epoch_snapshot,
+            proposal.epoch_total_power,
+            proposal.eligible_power,
+            &ctx.accounts.registry,
+        );
+        proposal.quorum_met = meets_quorum(cast, proposal.quorum_bps, total_voting_power);
+
+        let runner_up = if leaders.len() > 1 {
+            top
+        } else {
+            proposal.tallies.iter().copied().filter(|&tally| tally < top).max().unwrap_or(0)
+        };
+
+        if proposal.quorum_met
+            && top > 0
+            && bps::exceeds_bps(top, top.saturating_add(runner_up), proposal.threshold_bps)
+        {
+            proposal.status.advance(ProposalStatus::Passed)?;
+            proposal.winning_option = Some(leaders[0] as u8);
         } else {
-            proposal.votes_against += 1;
+            proposal.status.advance(ProposalStatus::Rejected)?;
         }
+        proposal.closed_at = Clock::get()?.unix_timestamp;
+        dao.release_open_slot()?;
 
-        vote_record.has_voted = true;
-        vote_record.support = support;
-        vote_record.voter = ctx.accounts.voter.key();
+        if dao.logs(EventVerbosity::All) {
+            emit!(ProposalClosed {
+                dao: dao.key(),
+                proposal_id: proposal.id,
+                status: proposal.status.clone(),
+                sequence: dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a multi-choice proposal's held bond to the proposer, on the
+    /// same terms as `refund_bond`.
+    pub fn refund_multi_choice_bond(ctx: Context<SettleMultiChoiceBond>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        check_bond_refundable(&proposal.bond_state, &proposal.status, proposal.quorum_met)?;
+        require_keys_eq!(
+            ctx.accounts.proposer.key(),
+            proposal.proposer,
+            ErrorCode::InvalidBondRecipient
+        );
 
+        release_bond(
+            &proposal.to_account_info(),
+            proposal.bond,
+            &ctx.accounts.proposer.to_account_info(),
+        )?;
+        ctx.accounts.proposal.bond_state = BondState::Refunded;
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(init, payer = authority, space = 8 + 32 + 8 + 8 + 256 + 512 + 512 + 8 + 64 + 64)]
-    pub dao: Account<'info, Dao>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// Moves a multi-choice proposal's forfeited bond to the DAO treasury,
+    /// on the same terms as `forfeit_bond`. Anyone may call this.
+    pub fn forfeit_multi_choice_bond(ctx: Context<SettleMultiChoiceBond>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        check_bond_forfeitable(&proposal.bond_state, &proposal.status, proposal.quorum_met)?;
 
-#[derive(Accounts)]
-pub struct CreateProposal<'info> {
-    #[account(mut)]
-    pub dao: Account<'info, Dao>,
-    #[account(init, payer = proposer, space = 8 + 8 + 256 + 512 + 8 + 32 + 8 + 8 + 1 + 8)]
-    pub proposal: Account<'info, Proposal>,
-    #[account(mut)]
-    pub proposer: Signer<'info>,
-    pub system_program: Program<'info, System>,
+        let amount = proposal.bond;
+        release_bond(
+            &proposal.to_account_info(),
+            amount,
+            &ctx.accounts.treasury.to_account_info(),
+        )?;
+        ctx.accounts.proposal.bond_state = BondState::Forfeited;
+        let dao = &mut ctx.accounts.dao;
+        dao.keeper_pool = dao.keeper_pool.saturating_add(amount);
+        if ctx.accounts.dao.logs(EventVerbosity::All) {
+            emit!(TreasuryDeposit {
+                dao: ctx.accounts.dao.key(),
+                amount,
+                mint: None,
+                balance_after: ctx.accounts.treasury.lamports(),
+                sequence: ctx.accounts.dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns, via return data, the option currently in the lead. On a tie
+    /// the lowest tied index is reported with `tied` set; with no votes cast
+    /// there is no leader.
+    pub fn get_multi_choice_leader(
+        ctx: Context<GetMultiChoiceLeader>,
+        proposal_id: u64,
+    ) -> Result<MultiChoiceLeader> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+
+        let (top, leaders) = leading_options(&proposal.tallies);
+        let leader = MultiChoiceLeader {
+            option_index: if top > 0 { leaders.first().map(|&i| i as u8) } else { None },
+            weight: top,
+            tied: top > 0 && leaders.len() > 1,
+        };
+        if ctx.accounts.dao.logs(EventVerbosity::All) {
+            msg!(
+                "Leader {:?} with weight {}, tied: {}",
+                leader.option_index,
+                leader.weight,
+                leader.tied
+            );
+        }
+        Ok(leader)
+    }
+
+    /// Carries out a passed proposal once its timelock has elapsed. Anyone may
+    /// call this.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        require!(
+            ctx.accounts.proposal.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassed
+        );
+        require!(
+            Clock::get()?.unix_timestamp
+                >= ctx.accounts.proposal.closed_at + ctx.accounts.dao.timelock,
+            ErrorCode::TimelockActive
+        );
+
+        if ctx.accounts.apply_payload(&ctx.bumps)? && ctx.accounts.dao.logs(EventVerbosity::All) {
+            emit!(ProposalExecuted {
+                dao: ctx.accounts.dao.key(),
+                proposal_id: ctx.accounts.proposal.id,
+                sequence: ctx.accounts.dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Executes a passed proposal immediately, skipping the timelock. Requires
+    /// the multisig threshold of signers in `remaining_accounts`.
+    pub fn emergency_execute(ctx: Context<ExecuteProposal>) -> Result<()> {
+        require!(
+            ctx.accounts.proposal.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassed
+        );
+
+        let dao = &ctx.accounts.dao;
+        let mut approvals: Vec<Pubkey> = Vec::new();
+        for info in ctx.remaining_accounts.iter() {
+            if info.is_signer && dao.multisig_signers.contains(info.key) && !approvals.contains(info.key) {
+                approvals.push(info.key());
+            }
+        }
+        require!(
+            dao.multisig_threshold > 0 && approvals.len() >= dao.multisig_threshold as usize,
+            ErrorCode::MultisigThresholdNotMet
+        );
+
+        if ctx.accounts.apply_payload(&ctx.bumps)? {
+            ctx.accounts.proposal.emergency = true;
+            if ctx.accounts.dao.logs(EventVerbosity::All) {
+                emit!(EmergencyExecuted {
+                    dao: ctx.accounts.dao.key(),
+                    proposal_id: ctx.accounts.proposal.id,
+                    signers: approvals,
+                    sequence: ctx.accounts.dao.next_event_seq(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Pays lamports into the DAO treasury. Anyone may deposit, at least the
+    /// registry's `min_deposit`, so the treasury's history cannot be filled
+    /// with dust.
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        ctx.accounts.registry.check_deposit(amount)?;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        if ctx.accounts.dao.logs(EventVerbosity::All) {
+            emit!(TreasuryDeposit {
+                dao: ctx.accounts.dao.key(),
+                amount,
+                mint: None,
+                balance_after: ctx.accounts.treasury.lamports(),
+                sequence: ctx.accounts.dao.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the proposal's status via return data so clients can read it
+    /// from a simulation instead of parsing logs.
+    pub fn get_proposal_status(ctx: Context<GetProposalStatus>) -> Result<ProposalStatus> {
+        Ok(ctx.accounts.proposal.status.clone())
+    }
+
+    /// Returns, via return data, the seconds left in the proposal's voting
+    /// window, zero once it has ended, and whether it is still open to
+    /// votes, for countdown displays.
+    pub fn get_time_remaining(ctx: Context<GetTimeRemaining>, proposal_id: u64) -> Result<TimeRemaining> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+
+        let now = Clock::get()?.unix_timestamp;
+        let seconds = proposal.voting_ends_at.saturating_sub(now).max(0) as u64;
+        Ok(TimeRemaining {
+            seconds,
+            open: proposal.status == ProposalStatus::Active && seconds > 0,
+        })
+    }
+
+    /// Returns, via return data, everything a detailed proposal view shows:
+    /// its status, tallies, rules, timestamps and proposer, together with
+    /// the result it would be tallied to now. Once a proposal is closed that
+    /// result is simply its status.
+    pub fn get_proposal_details(
+        ctx: Context<GetProposalDetails>,
+        proposal_id: u64,
+    ) -> Result<ProposalDetails> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+
+        let quorum_base = quorum_base(proposal, &ctx.accounts.registry);
+        let (quorum_met, threshold_met) = outcome(proposal, quorum_base);
+        let current_result = match proposal.status {
+            ProposalStatus::Active => current_result(proposal, quorum_base).status(),
+            ref status => status.clone(),
+        };
+        Ok(ProposalDetails {
+            id: proposal.id,
+            status: proposal.status.clone(),
+            proposer: proposal.proposer,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+            quorum_bps: proposal.quorum_bps,
+            threshold_bps: proposal.threshold_bps,
+            quorum_base,
+            created_at: proposal.created_at,
+            voting_ends_at: proposal.voting_ends_at,
+            reveal_ends_at: proposal.reveal_ends_at,
+            closed_at: proposal.closed_at,
+            quorum_met,
+            threshold_met,
+            current_result,
+        })
+    }
+
+    /// Returns, via return data, whether the members passed in
+    /// `remaining_accounts` (the registry's whole roster) could between them
+    /// reach quorum, so clients can skip creating a proposal that cannot
+    /// pass. Members under dispute or decayed to nothing add no weight. With
+    /// quadratic voting each member is weighed alone, which overstates what
+    /// delegations that pool power would produce.
+    pub fn can_reach_quorum<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CanReachQuorum<'info>>,
+        quorum_bps_override: Option<u16>,
+    ) -> Result<bool> {
+        let dao = &ctx.accounts.dao;
+        let registry = &ctx.accounts.registry;
+        require!(
+            ctx.remaining_accounts.len() as u64 == registry.member_count,
+            ErrorCode::IncompleteMemberSet
+        );
+
+        let clock = Clock::get()?;
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut eligible = 0u64;
+        for info in ctx.remaining_accounts.iter() {
+            require!(!seen.contains(info.key), ErrorCode::IncompleteMemberSet);
+            seen.push(info.key());
+
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), ErrorCode::InvalidMember);
+            if !member.is_active || member.pending || member.under_dispute {
+                continue;
+            }
+            let power = registry.effective_power_tiered(membership::scaled_power(
+                &member,
+                membership::decayed_power(
+                    &member,
+                    membership::vested_power(&member, clock.unix_timestamp),
+                    clock.slot,
+                    &registry.decay,
+                ),
+                registry.ai_power_multiplier_bps,
+            ));
+            eligible += if dao.quadratic_voting {
+                quadratic_weight(power, &dao.quad_rounding)
+            } else {
+                power
+            };
+        }
+
+        let quorum_bps = quorum_bps_override.unwrap_or(dao.quorum_bps);
+        let reachable = meets_quorum(eligible, quorum_bps, registry.effective_total_power());
+        if dao.logs(EventVerbosity::All) {
+            msg!(
+                "Eligible power {} of {}, quorum {} bps reachable: {}",
+                eligible,
+                registry.effective_total_power(),
+                quorum_bps,
+                reachable
+            );
+        }
+        Ok(reachable)
+    }
+
+    /// Returns every configurable DAO and registry value in one struct, so
+    /// clients need not track either account layout.
+    pub fn get_config(ctx: Context<GetConfig>) -> Result<Config> {
+        let dao = &ctx.accounts.dao;
+        let registry = &ctx.accounts.registry;
+        Ok(Config {
+            authority: dao.authority,
+            registry: dao.registry,
+            quorum_bps: dao.quorum_bps,
+            threshold_bps: dao.threshold_bps,
+            voting_period: dao.voting_period,
+            min_voting_secs: dao.min_voting_secs,
+            timelock: dao.timelock,
+            retention_period: dao.retention_period,
+            multisig_signers: dao.multisig_signers.clone(),
+            multisig_threshold: dao.multisig_threshold,
+            max_execution_attempts: dao.max_execution_attempts,
+            max_members: registry.max_members,
+            max_delegation_depth: registry.max_delegation_depth,
+            reuse_ids: registry.reuse_ids,
+            registration_fee: registry.registration_fee,
+            treasury: registry.treasury,
+            max_open_proposals: dao.max_open_proposals,
+            open_proposal_count: dao.open_proposal_count,
+            quadratic_voting: dao.quadratic_voting,
+            quad_rounding: dao.quad_rounding.clone(),
+            min_cosponsors: dao.min_cosponsors,
+            proposal_bond: dao.proposal_bond,
+            early_close: dao.early_close,
+            proposal_prefix: dao.proposal_prefix.clone(),
+            reveal_secs: dao.reveal_secs,
+            event_verbosity: dao.event_verbosity,
+            keeper_reward: dao.keeper_reward,
+            max_keeper_reward: dao.max_keeper_reward,
+            decay: registry.decay.clone(),
+            self_registration: registry.self_registration,
+            default_voting_power: registry.default_voting_power,
+            require_ai_sponsor: registry.require_ai_sponsor,
+            normalized_total: registry.normalized_total,
+            ai_power_multiplier_bps: registry.ai_power_multiplier_bps,
+            rent_buffer_lamports: registry.rent_buffer_lamports,
+            max_member_power: registry.max_member_power,
+            require_model_id: registry.require_model_id,
+            voting_eligibility_delay_secs: registry.voting_eligibility_delay_secs,
+            require_contact_hash: registry.require_contact_hash,
+            operational_cap: registry.operational_cap,
+            financial_cap: registry.financial_cap,
+            treasury_min_balance: registry.treasury_min_balance,
+            power_tiers: registry.power_tiers.clone(),
+            require_human_majority_count: registry.require_human_majority_count,
+            registry_event_verbosity: registry.event_verbosity,
+            dues_amount: registry.dues_amount,
+            dues_period_secs: registry.dues_period_secs,
+            unique_names: registry.unique_names,
+            max_votes_per_window: registry.max_votes_per_window,
+            vote_window_slots: registry.vote_window_slots,
+            agreement_hash: registry.agreement_hash,
+            successor: registry.successor,
+            succession_timeout_slots: registry.succession_timeout_slots,
+            min_deposit: registry.min_deposit,
+        })
+    }
+
+    /// Returns the DAO's legal-entity record via return data.
+    pub fn get_legal_info(ctx: Context<GetLegalInfo>) -> Result<LegalInfo> {
+        let dao = &ctx.accounts.dao;
+        Ok(LegalInfo {
+            legal_name: dao.legal_name.clone(),
+            entity_type: dao.entity_type.clone(),
+            jurisdiction: dao.jurisdiction.clone(),
+            legal_entity_id: dao.legal_entity_id.clone(),
+            registered_agent_address: dao.registered_agent_address.clone(),
+            principal_place_of_business: dao.principal_place_of_business.clone(),
+            formation_date: dao.formation_date,
+        })
+    }
+
+    /// Reclaims the rent of a finished proposal once the retention period has
+    /// passed. Passed proposals still awaiting execution cannot be closed.
+    /// As with members, the closed account cannot be reloaded as a proposal.
+    pub fn close_proposal_account(ctx: Context<CloseProposalAccount>, proposal_id: u64) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+
+        require!(proposal.id == proposal_id, ErrorCode::ProposalMismatch);
+        require!(proposal.status.is_final(), ErrorCode::ProposalNotFinished);
+        require!(proposal.bond_state != BondState::Held, ErrorCode::BondNotSettled);
+        require!(
+            Clock::get()?.unix_timestamp >= proposal.closed_at + ctx.accounts.dao.retention_period,
+            ErrorCode::RetentionPeriodActive
+        );
+
+        Ok(())
+    }
 }
 
-#[derive(Accounts)]
-pub struct Vote<'info> {
-    #[account(mut)]
-    pub proposal: Account<'info, Proposal>,
-    #[account(init, payer = voter, space = 8 + 1 + 1 + 32)]
-    pub vote_record: Account<'info, VoteRecord>,
-    #[account(mut)]
-    pub voter: Signer<'info>,
-    pub system_program: Program<'info, System>,
+/// Weight of a vote on a proposal from an epoch: the voter's power in the
+/// epoch's snapshot, scaled, tiered and, if enabled, square-rooted as live
+/// power would be. Delegations are not carried into epoch weights, so only
+/// members who have not delegated may vote.
+fn epoch_weight(
+    mode: &VoteMode,
+    registry: &MemberRegistry,
+    member: &Member,
+    snapshot: &Snapshot,
+) -> Result<u64> {
+    require!(member.delegate.is_none(), ErrorCode::VoteDelegated);
+    let entry = snapshot
+        .entries
+        .iter()
+        .find(|entry| entry.pubkey == member.pubkey)
+        .ok_or(ErrorCode::NotInEpochSnapshot)?;
+    let power = registry.effective_power_tiered(snapshot_power(entry, registry.ai_power_multiplier_bps));
+    Ok(mode.weigh(power))
 }
 
-#[account]
-pub struct Dao {
-    pub authority: Pubkey,
-    pub proposal_count: u64,
-    pub member_count: u64,
-    // Wyoming DAO LLC Compliance Fields
-    pub legal_name: String,
-    pub registered_agent_address: String,
-    pub principal_place_of_business: String,
-    pub formation_date: i64,
-    pub jurisdiction: String,
-    pub entity_type: String,
+/// A snapshot entry's power with AI members' share scaled by the multiplier.
+fn snapshot_power(entry: &SnapshotEntry, ai_multiplier_bps: u16) -> u64 {
+    if entry.is_ai {
+        bps::apply_bps(entry.voting_power, ai_multiplier_bps).unwrap_or(u64::MAX)
+    } else {
+        entry.voting_power
+    }
 }
 
-#[account]
-pub struct Proposal {
-    pub id: u64,
-    pub title: String,
-    pub description: String,
-    pub amount: u64,
-    pub proposer: Pubkey,
-    pub votes_for: u64,
-    pub votes_against: u64,
-    pub status: ProposalStatus,
-    pub created_at: i64,
+/// Voting weight of `member` on a proposal created at `proposal_created_at`:
+/// their own power, reduced for inactivity, plus everything delegated to them
+/// by the members in `delegators` who were eligible by then.
+fn voting_weight<'info>(
+    mode: &VoteMode,
+    member: &Account<'info, Member>,
+    registry: &MemberRegistry,
+    delegators: &'info [AccountInfo<'info>],
+    proposal_created_at: i64,
+) -> Result<u64> {
+    require!(member.delegate.is_none(), ErrorCode::VoteDelegated);
+    let clock = Clock::get()?;
+    let own = membership::scaled_power(
+        member,
+        membership::decayed_power(
+            member,
+            membership::vested_power(member, clock.unix_timestamp),
+            clock.slot,
+            &registry.decay,
+        ),
+        registry.ai_power_multiplier_bps,
+    );
+    let delegated = membership::delegated_power(
+        member.key(),
+        member.registry,
+        delegators,
+        registry.max_delegation_depth,
+        registry.ai_power_multiplier_bps,
+        membership::eligibility_cutoff(registry, proposal_created_at),
+    )?;
+    let power = registry.effective_power_tiered(own + delegated);
+    Ok(mode.weigh(power))
 }
 
-#[account]
-pub struct VoteRecord {
-    pub has_voted: bool,
-    pub support: bool,
-    pub voter: Pubkey,
+/// Integer square root of `power`, rounded as the DAO has configured.
+pub fn quadratic_weight(power: u64, rounding: &QuadRounding) -> u64 {
+    let root = isqrt(power);
+    let exact = root * root == power;
+    match rounding {
+        QuadRounding::Floor => root,
+        QuadRounding::Ceil if !exact => root + 1,
+        QuadRounding::Ceil => root,
+        // sqrt(power) >= root + 0.5 exactly when power > root^2 + root.
+        QuadRounding::Round if power > root * root + root => root + 1,
+        QuadRounding::Round => root,
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-pub enum ProposalStatus {
-    Active,
-    Executed,
-    Rejected,
+/// Largest `r` with `r * r <= n`, by Newton's method from an overestimate.
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = 1u64 << (64 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Marks the voter active and counts their vote on `proposal_id` in the
+/// membership program, signing as the DAO's governance PDA.
+fn record_activity<'info>(
+    dao: &Account<'info, Dao>,
+    registry: &Account<'info, MemberRegistry>,
+    member: &Account<'info, Member>,
+    proposal_id: u64,
+    governance_signer: &UncheckedAccount<'info>,
+    governance_signer_bump: u8,
+    membership_program: &Program<'info, Membership>,
+) -> Result<()> {
+    let dao_key = dao.key();
+    let seeds: &[&[u8]] = &[b"governance", dao_key.as_ref(), &[governance_signer_bump]];
+    membership::cpi::record_activity(
+        CpiContext::new_with_signer(
+            membership_program.to_account_info(),
+            membership::cpi::accounts::RecordActivity {
+                registry: registry.to_account_info(),
+                member: member.to_account_info(),
+                signer: governance_signer.to_account_info(),
+            },
+            &[seeds],
+        ),
+        proposal_id,
+        dao.proposal_count,
+    )
+}
+
+/// Counts a vote already checked against the proposal's voting or reveal
+/// window, and records it so the member cannot vote again.
+fn cast_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+    support: bool,
+    rationale: String,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let member = &ctx.accounts.member;
+
+    require!(!vote_record.has_voted, ErrorCode::AlreadyVoted);
+    check_voter(
+        &ctx.accounts.dao,
+        &ctx.accounts.registry,
+        member,
+        ctx.accounts.sponsor.as_ref(),
+        &proposal.cosponsors,
+        proposal.created_at,
+        &proposal.eligibility,
+    )?;
+
+    // A member the voter cannot act for got past the account constraints
+    // only by having an Ethereum address, which must then sign the vote.
+    let remaining = if member.controlled_by(&ctx.accounts.voter.key()) {
+        membership::split_member_signers(member, &ctx.accounts.voter.key(), ctx.remaining_accounts)?
+    } else {
+        let instructions = ctx
+            .accounts
+            .instructions
+            .as_ref()
+            .ok_or(membership::MembershipError::InvalidEthSignature)?;
+        membership::verify_eth_signature(
+            instructions,
+            &member.eth_address,
+            &eth_vote_message(&proposal.key(), &member.key(), support),
+        )?;
+        ctx.remaining_accounts
+    };
+    let weight = proposal_weight(
+        &proposal.vote_mode,
+        member,
+        &ctx.accounts.registry,
+        remaining,
+        proposal.created_at,
+        &proposal.eligibility,
+        proposal.epoch_snapshot,
+        ctx.accounts.epoch_snapshot.as_ref(),
+    )?;
+    record_activity(
+        &ctx.accounts.dao,
+        &ctx.accounts.registry,
+        member,
+        proposal.id,
+        &ctx.accounts.governance_signer,
+        ctx.bumps.governance_signer,
+        &ctx.accounts.membership_program,
+    )?;
+
+    if support {
+        proposal.votes_for += weight;
+    } else {
+        proposal.votes_against += weight;
+    }
+
+    vote_record.has_voted = true;
+    vote_record.support = support;
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.weight = weight;
+    vote_record.rationale = rationale;
+
+    let dao = &mut ctx.accounts.dao;
+    let total_voting_power = quorum_base(proposal, &ctx.accounts.registry);
+    if dao.early_close && support && is_decided(proposal, total_voting_power) {
+        tally(dao, proposal, total_voting_power, Clock::get()?.unix_timestamp)?;
+    }
+
+    Ok(())
+}
+
+/// The checks a vote on any kind of proposal must pass: the proposal has
+/// its `min_cosponsors`, an AI member brings a sponsor if the registry asks
+/// for one, and the member may vote on a proposal created at `created_at`
+/// and restricted to `eligibility`.
+fn check_voter(
+    dao: &Dao,
+    registry: &MemberRegistry,
+    member: &Member,
+    sponsor: Option<&Signer>,
+    cosponsors: &[Pubkey],
+    created_at: i64,
+    eligibility: &VoterEligibility,
+) -> Result<()> {
+    require!(
+        cosponsors.len() >= dao.min_cosponsors as usize,
+        ErrorCode::InsufficientCosponsors
+    );
+    membership::check_sponsor(registry, member, sponsor.map(|sponsor| sponsor.key()))?;
+    membership::check_vote_eligibility(registry, member, created_at)?;
+    require!(
+        eligibility.admits(member),
+        membership::MembershipError::NotEligibleForProposal
+    );
+    Ok(())
+}
+
+/// Weight of `member`'s vote on a proposal of any kind, under the vote
+/// mode, electorate and epoch it recorded when opened: the member's power
+/// in the epoch's snapshot if it has one, else `voting_weight` with
+/// `delegators`. Restricted proposals count only the voter's own power,
+/// since delegators outside the eligible set must not vote through them.
+fn proposal_weight<'info>(
+    mode: &VoteMode,
+    member: &Account<'info, Member>,
+    registry: &MemberRegistry,
+    delegators: &'info [AccountInfo<'info>],
+    created_at: i64,
+    eligibility: &VoterEligibility,
+    epoch_snapshot: Option<Pubkey>,
+    snapshot: Option<&Account<'info, Snapshot>>,
+) -> Result<u64> {
+    let delegators = if *eligibility == VoterEligibility::All {
+        delegators
+    } else {
+        &[]
+    };
+    match epoch_snapshot {
+        Some(expected) => {
+            let snapshot = snapshot
+                .filter(|snapshot| snapshot.key() == expected)
+                .ok_or(ErrorCode::EpochSnapshotMismatch)?;
+            epoch_weight(mode, registry, member, snapshot)
+        }
+        None => voting_weight(mode, member, registry, delegators, created_at),
+    }
+}
+
+/// Commitment a voter submits with `commit_vote`: the hash of the choice
+/// byte followed by their salt.
+pub fn vote_commitment(support: bool, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&[support as u8], salt]).to_bytes()
+}
+
+/// Message a member's Ethereum key signs, through the Secp256k1 program, to
+/// authorize a `vote` on `proposal` with choice `support`.
+pub fn eth_vote_message(proposal: &Pubkey, member: &Pubkey, support: bool) -> [u8; 32] {
+    hashv(&[b"eth_vote", proposal.as_ref(), member.as_ref(), &[support as u8]]).to_bytes()
+}
+
+/// When voting on a proposal created at `now` ends: after the DAO's voting
+/// period, but never before its minimum voting window.
+fn voting_deadline(dao: &Dao, now: i64) -> Result<i64> {
+    now.checked_add(dao.voting_period.max(dao.min_voting_secs))
+        .ok_or_else(|| error!(ErrorCode::VotingWindowOverflow))
+}
+
+/// Voting closes at `voting_ends_at`; the authority may close earlier, but
+/// never before `min_voting_secs` have passed since creation.
+fn check_closable(dao: &Dao, closer: Pubkey, created_at: i64, voting_ends_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let earliest_close = created_at
+        .checked_add(dao.min_voting_secs)
+        .ok_or(ErrorCode::VotingWindowOverflow)?;
+    require!(now >= earliest_close, ErrorCode::MinimumVotingPeriodActive);
+    require!(
+        now >= voting_ends_at || closer == dao.authority,
+        ErrorCode::VotingNotEnded
+    );
+    Ok(())
+}
+
+/// Formats a proposal's human-readable reference, such as `PROP-2024-0001`,
+/// from the DAO's prefix, the UTC year of `created_at` and the proposal ID.
+pub fn proposal_reference(prefix: &str, created_at: i64, id: u64) -> String {
+    format!("{}-{:04}-{:04}", prefix, utc_year(created_at).clamp(0, 9999), id)
+}
+
+/// Gregorian year of a Unix timestamp, using the days-to-civil algorithm
+/// from Howard Hinnant's date library.
+fn utc_year(unix_timestamp: i64) -> i64 {
+    let z = unix_timestamp.div_euclid(86_400) + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Years run from March, so January and February belong to the next one.
+    let march_based_month = (5 * day_of_year + 2) / 153;
+    era * 400 + year_of_era + i64::from(march_based_month >= 10)
+}
+
+/// Highest tally and the indices of every option sharing it, in order.
+fn leading_options(tallies: &[u64]) -> (u64, Vec<usize>) {
+    let top = tallies.iter().copied().max().unwrap_or(0);
+    let leaders = (0..tallies.len()).filter(|&i| tallies[i] == top).collect();
+    (top, leaders)
+}
+
+/// Checks a new proposal's terms against the DAO and fills in `proposal`
+/// as Active with ID `id`. A proposal restricted by `eligibility` needs the
+/// DAO's registry, and an allowlist also needs each listed member's
+/// account in `members`, in list order. The caller escrows the bond.
+fn open_proposal<'info>(
+    dao: &mut Account<'info, Dao>,
+    proposal: &mut Proposal,
+    id: u64,
+    proposer: Pubkey,
+    registry: Option<&Account<'info, MemberRegistry>>,
+    members: &'info [AccountInfo<'info>],
+    title: String,
+    description: String,
+    amount: u64,
+    payload: ExecutionPayload,
+    quorum_bps_override: Option<u16>,
+    category: SpendingCategory,
+    eligibility: VoterEligibility,
+) -> Result<()> {
+    match &payload {
+        ExecutionPayload::SetConfig { field, value } => validate_config_value(field, *value)?,
+        ExecutionPayload::Transfer { amount, .. } => {
+            require!(*amount > 0, ErrorCode::InvalidTransfer)
+        }
+        ExecutionPayload::SetLegalInfo { legal_entity_id, jurisdiction } => {
+            validate_legal_info(legal_entity_id, jurisdiction)?
+        }
+        ExecutionPayload::None => {}
+    }
+    let eligible_power = take_open_slot(dao, registry, members, &eligibility, quorum_bps_override)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    proposal.dao = dao.key();
+    proposal.id = id;
+    proposal.title = title;
+    proposal.description = description;
+    proposal.amount = amount;
+    proposal.proposer = proposer;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.status = ProposalStatus::Active;
+    proposal.created_at = now;
+    proposal.voting_ends_at = voting_deadline(dao, now)?;
+    proposal.payload = payload;
+    proposal.closed_at = 0;
+    proposal.emergency = false;
+    proposal.executed_at_slot = 0;
+    proposal.execution_attempts = 0;
+    proposal.quorum_bps_override = quorum_bps_override;
+    proposal.cosponsors = Vec::new();
+    proposal.bond = dao.proposal_bond;
+    proposal.bond_state = if proposal.bond > 0 { BondState::Held } else { BondState::None };
+    proposal.quorum_met = false;
+    proposal.callback_program = None;
+    proposal.callback_pending = false;
+    proposal.category = category;
+    proposal.reference = proposal_reference(&dao.proposal_prefix, now, proposal.id);
+    proposal.eligibility = eligibility;
+    proposal.eligible_power = eligible_power;
+    proposal.comment_count = 0;
+    proposal.epoch = dao.current_epoch;
+    proposal.epoch_snapshot = (dao.current_epoch > 0).then_some(dao.epoch_snapshot);
+    proposal.epoch_total_power = dao.epoch_total_power;
+    proposal.reveal_secs = dao.reveal_secs;
+    proposal.reveal_ends_at = 0;
+    proposal.quorum_bps = quorum_bps_override.unwrap_or(dao.quorum_bps);
+    proposal.threshold_bps = dao.threshold_bps;
+    proposal.vote_mode = dao.vote_mode();
+    Ok(())
+}
+
+/// Takes an open-proposal slot for a new proposal of any kind, after
+/// checking the DAO has one free and that `eligibility` and any quorum
+/// override are valid. Returns the power a restricted proposal admits,
+/// which needs the DAO's registry and, for an allowlist, each listed
+/// member's account in `members`, in list order; 0 when all may vote.
+fn take_open_slot<'info>(
+    dao: &mut Dao,
+    registry: Option<&Account<'info, MemberRegistry>>,
+    members: &'info [AccountInfo<'info>],
+    eligibility: &VoterEligibility,
+    quorum_bps_override: Option<u16>,
+) -> Result<u64> {
+    require!(
+        dao.max_open_proposals == 0 || dao.open_proposal_count < dao.max_open_proposals,
+        ErrorCode::TooManyOpenProposals
+    );
+    if let VoterEligibility::Allowlist(pubkeys) = eligibility {
+        require!(
+            (1..=MAX_ALLOWLIST_LEN).contains(&pubkeys.len())
+                && pubkeys.iter().enumerate().all(|(i, key)| !pubkeys[..i].contains(key)),
+            ErrorCode::InvalidAllowlist
+        );
+    }
+    let eligible_power = match (eligibility, registry) {
+        (VoterEligibility::All, _) => 0,
+        (restricted, Some(registry)) => eligible_power(registry, restricted, members)?,
+        (_, None) => return err!(ErrorCode::RegistryRequired),
+    };
+    // An override may only raise the bar set by the DAO-wide quorum.
+    if let Some(quorum_bps) = quorum_bps_override {
+        require!(
+            quorum_bps >= dao.quorum_bps && quorum_bps <= bps::MAX_BPS,
+            ErrorCode::InvalidQuorumOverride
+        );
+    }
+
+    dao.open_proposal_count += 1;
+    Ok(eligible_power)
+}
+
+/// Moves a new proposal's bond from `proposer` into the proposal account,
+/// which escrows it.
+fn escrow_bond<'info>(
+    proposal: &AccountInfo<'info>,
+    bond: u64,
+    proposer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: proposer.to_account_info(),
+                    to: proposal.clone(),
+                },
+            ),
+            bond,
+        )?;
+    }
+    Ok(())
+}
+
+/// Creates `account`, which must sign, as a `Pending` placeholder for
+/// reserved proposal ID `id`, paid for by `payer`.
+fn create_placeholder<'info>(
+    account: &AccountInfo<'info>,
+    dao: Pubkey,
+    id: u64,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    require!(account.is_signer && account.is_writable, ErrorCode::InvalidPlaceholder);
+    system_program::create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(Proposal::MAX_ACCOUNT_SIZE),
+        Proposal::MAX_ACCOUNT_SIZE as u64,
+        &crate::ID,
+    )?;
+    Proposal::placeholder(dao, id, payer.key())
+        .try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+/// Scaled power of the members `eligibility` admits. Member types are read
+/// from the registry's aggregates; allowlisted members from `members`,
+/// which must hold each listed member's account in list order.
+fn eligible_power<'info>(
+    registry: &Account<'info, MemberRegistry>,
+    eligibility: &VoterEligibility,
+    members: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+    Ok(match eligibility {
+        VoterEligibility::All => registry.effective_total_power(),
+        VoterEligibility::MemberType(MemberType::AI) => {
+            bps::apply_bps(registry.ai_voting_power, registry.ai_power_multiplier_bps)
+                .unwrap_or(u64::MAX)
+        }
+        VoterEligibility::MemberType(MemberType::Human) => registry.human_voting_power,
+        VoterEligibility::MemberType(MemberType::Organization) => registry
+            .total_voting_power
+            .saturating_sub(registry.ai_voting_power + registry.human_voting_power),
+        VoterEligibility::Allowlist(pubkeys) => {
+            require!(members.len() == pubkeys.len(), ErrorCode::IncompleteMemberSet);
+            let mut total = 0u64;
+            for (info, pubkey) in members.iter().zip(pubkeys) {
+                let member = Account::<Member>::try_from(info)?;
+                require!(
+                    member.registry == registry.key() && member.pubkey == *pubkey,
+                    ErrorCode::InvalidMember
+                );
+                if member.is_active {
+                    total += membership::scaled_power(
+                        &member,
+                        member.voting_power,
+                        registry.ai_power_multiplier_bps,
+                    );
+                }
+            }
+            total
+        }
+    })
+}
+
+/// Pays a proposal's escrowed `bond` out to `recipient`. The proposal
+/// account belongs to this program, so its lamports can be moved directly.
+fn release_bond(proposal: &AccountInfo, bond: u64, recipient: &AccountInfo) -> Result<()> {
+    **proposal.try_borrow_mut_lamports()? -= bond;
+    **recipient.try_borrow_mut_lamports()? += bond;
+    Ok(())
+}
+
+/// A held bond is refunded once its proposal has closed having reached
+/// quorum, whether or not it passed.
+fn check_bond_refundable(bond_state: &BondState, status: &ProposalStatus, quorum_met: bool) -> Result<()> {
+    require!(*bond_state == BondState::Held, ErrorCode::BondNotHeld);
+    require!(*status != ProposalStatus::Active, ErrorCode::ProposalNotFinished);
+    require!(quorum_met, ErrorCode::BondForfeited);
+    Ok(())
+}
+
+/// A held bond is forfeited once its proposal has closed without quorum.
+fn check_bond_forfeitable(bond_state: &BondState, status: &ProposalStatus, quorum_met: bool) -> Result<()> {
+    require!(*bond_state == BondState::Held, ErrorCode::BondNotHeld);
+    require!(*status != ProposalStatus::Active, ErrorCode::ProposalNotFinished);
+    require!(!quorum_met, ErrorCode::BondRefundable);
+    Ok(())
+}
+
+/// Adds `cosponsor` to an active proposal's co-sponsors, once, up to
+/// `MAX_COSPONSORS`. The proposer cannot co-sponsor their own proposal.
+fn add_cosponsor(
+    cosponsors: &mut Vec<Pubkey>,
+    status: &ProposalStatus,
+    proposer: Pubkey,
+    cosponsor: Pubkey,
+) -> Result<()> {
+    require!(*status == ProposalStatus::Active, ErrorCode::ProposalNotActive);
+    require!(
+        cosponsor != proposer && !cosponsors.contains(&cosponsor),
+        ErrorCode::AlreadyCosponsored
+    );
+    require!(cosponsors.len() < MAX_COSPONSORS, ErrorCode::TooManyCosponsors);
+    cosponsors.push(cosponsor);
+    Ok(())
+}
+
+fn validate_legal_info(legal_entity_id: &str, jurisdiction: &str) -> Result<()> {
+    require!(
+        legal_entity_id.len() <= MAX_LEGAL_ENTITY_ID_LEN
+            && !jurisdiction.is_empty()
+            && jurisdiction.len() <= MAX_JURISDICTION_LEN,
+        ErrorCode::InvalidLegalInfo
+    );
+    Ok(())
+}
+
+fn validate_config_value(field: &ConfigField, value: u64) -> Result<()> {
+    match field {
+        ConfigField::QuorumBps | ConfigField::ThresholdBps => {
+            require!(value <= bps::MAX_BPS as u64, ErrorCode::InvalidConfigValue)
+        }
+        ConfigField::MaxMembers
+        | ConfigField::MaxOpenProposals
+        | ConfigField::ProposalBond
+        | ConfigField::KeeperReward
+        | ConfigField::MaxKeeperReward => {}
+        ConfigField::MinVotingSecs => {
+            require!(value <= MAX_MIN_VOTING_SECS as u64, ErrorCode::InvalidConfigValue)
+        }
+        ConfigField::RevealSecs => {
+            require!(value <= MAX_REVEAL_SECS as u64, ErrorCode::InvalidConfigValue)
+        }
+        ConfigField::QuadraticVoting | ConfigField::EarlyClose => {
+            require!(value <= 1, ErrorCode::InvalidConfigValue)
+        }
+        ConfigField::QuadRounding | ConfigField::EventVerbosity => {
+            require!(value <= 2, ErrorCode::InvalidConfigValue)
+        }
+        ConfigField::MinCosponsors => {
+            require!(value <= MAX_COSPONSORS as u64, ErrorCode::InvalidConfigValue)
+        }
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = authority, space = 8 + 32 + 8 + 8 + 256 + 512 + 512 + 8 + 64 + 64 + 32 + 2 + 2 + 8 + 8 + 8 + 4 + MAX_MULTISIG_SIGNERS * 32 + 1 + 8 + 1 + 8 + 8 + 1 + 1 + 1 + 8 + 1 + 4 + MAX_PROPOSAL_PREFIX_LEN + 8 + 32 + 32 + 8 + 8 + 1 + 4 + MAX_LEGAL_ENTITY_ID_LEN + 8 + 8 + 8 + 8 + 8 + 8 + 8)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisig<'info> {
+    #[account(mut, has_one = authority)]
+    pub dao: Account<'info, Dao>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetProposalPrefix<'info> {
+    #[account(mut, has_one = authority)]
+    pub dao: Account<'info, Dao>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdvanceEpoch<'info> {
+    #[account(mut, has_one = authority, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(constraint = snapshot.registry == registry.key() @ ErrorCode::EpochSnapshotMismatch)]
+    pub snapshot: Account<'info, Snapshot>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LinkRegistry<'info> {
+    #[account(mut, has_one = authority)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+    #[account(init, payer = proposer, space = Proposal::MAX_ACCOUNT_SIZE)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(address = dao.registry)]
+    pub registry: Option<Account<'info, MemberRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct PreallocateProposals<'info> {
+    #[account(mut, has_one = authority)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FillProposal<'info> {
+    #[account(mut, has_one = authority)]
+    pub dao: Account<'info, Dao>,
+    /// CHECK: a `Pending` placeholder, checked by `fill_proposal`, or a new
+    /// account it creates
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(address = dao.registry)]
+    pub registry: Option<Account<'info, MemberRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct Vote<'info> {
+    #[account(mut, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        mut,
+        constraint = (member.controlled_by(&voter.key()) || member.eth_address != [0; 20])
+            && member.registry == registry.key()
+            && member.is_active @ ErrorCode::InvalidMember,
+        constraint = !member.under_dispute @ ErrorCode::MemberUnderDispute
+    )]
+    pub member: Account<'info, Member>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + 1 + 1 + 32 + 8 + 4 + MAX_RATIONALE_LEN,
+        seeds = [b"vote", proposal.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    /// CHECK: PDA signer recorded as `registry.governance`; holds no data
+    #[account(seeds = [b"governance", dao.key().as_ref()], bump)]
+    pub governance_signer: UncheckedAccount<'info>,
+    pub membership_program: Program<'info, Membership>,
+    pub system_program: Program<'info, System>,
+    /// Co-signer required for AI members when the registry asks for one
+    pub sponsor: Option<Signer<'info>>,
+    /// Snapshot of the proposal's epoch, required when it has one
+    pub epoch_snapshot: Option<Account<'info, Snapshot>>,
+    /// Commitment being disclosed; `reveal_vote` only
+    pub commitment: Option<Account<'info, VoteCommitment>>,
+    /// CHECK: the Instructions sysvar, required when an Ethereum signature
+    /// rather than `voter` authorizes the member's vote
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        constraint = member.controlled_by(&voter.key())
+            && member.registry == registry.key()
+            && member.is_active @ ErrorCode::InvalidMember,
+        constraint = !member.under_dispute @ ErrorCode::MemberUnderDispute
+    )]
+    pub member: Account<'info, Member>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + 32 + 32 + 32,
+        seeds = [b"commit", proposal.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub commitment: Account<'info, VoteCommitment>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleBond<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Only receives lamports; checked against `proposal.proposer` on refund
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddComment<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        constraint = member.pubkey == author.key()
+            && member.registry == registry.key()
+            && member.is_active @ ErrorCode::InvalidMember
+    )]
+    pub member: Account<'info, Member>,
+    #[account(
+        init,
+        payer = author,
+        space = 8 + 32 + 8 + 32 + 8 + 4 + MAX_COMMENT_LEN,
+        seeds = [b"comment", proposal.key().as_ref(), &proposal.comment_count.to_le_bytes()],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CosponsorProposal<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        constraint = member.pubkey == cosponsor.key()
+            && member.registry == registry.key()
+            && member.is_active @ ErrorCode::InvalidMember
+    )]
+    pub member: Account<'info, Member>,
+    pub cosponsor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMultiChoiceProposal<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+    #[account(init, payer = proposer, space = MultiChoiceProposal::MAX_ACCOUNT_SIZE)]
+    pub proposal: Account<'info, MultiChoiceProposal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(address = dao.registry)]
+    pub registry: Option<Account<'info, MemberRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct CosponsorMultiChoiceProposal<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, MultiChoiceProposal>,
+    #[account(
+        constraint = member.pubkey == cosponsor.key()
+            && member.registry == registry.key()
+            && member.is_active @ ErrorCode::InvalidMember
+    )]
+    pub member: Account<'info, Member>,
+    pub cosponsor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleMultiChoiceBond<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, MultiChoiceProposal>,
+    /// CHECK: Only receives lamports; checked against `proposal.proposer` on refund
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CastMultiVote<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, MultiChoiceProposal>,
+    #[account(
+        mut,
+        constraint = member.controlled_by(&voter.key())
+            && member.registry == registry.key()
+            && member.is_active @ ErrorCode::InvalidMember,
+        constraint = !member.under_dispute @ ErrorCode::MemberUnderDispute
+    )]
+    pub member: Account<'info, Member>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + 32 + 1 + 8,
+        seeds = [b"vote", proposal.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, MultiVoteRecord>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    /// CHECK: PDA signer recorded as `registry.governance`; holds no data
+    #[account(seeds = [b"governance", dao.key().as_ref()], bump)]
+    pub governance_signer: UncheckedAccount<'info>,
+    pub membership_program: Program<'info, Membership>,
+    pub system_program: Program<'info, System>,
+    /// Co-signer required for AI members when the registry asks for one
+    pub sponsor: Option<Signer<'info>>,
+    /// Snapshot of the proposal's epoch, required when it has one
+    pub epoch_snapshot: Option<Account<'info, Snapshot>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseMultiChoiceProposal<'info> {
+    #[account(mut, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, MultiChoiceProposal>,
+    pub closer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GetMultiChoiceLeader<'info> {
+    pub dao: Account<'info, Dao>,
+    #[account(has_one = dao)]
+    pub proposal: Account<'info, MultiChoiceProposal>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(mut, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    pub closer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct NotifyCallback<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Must be the proposal's callback program; only invoked
+    #[account(
+        executable,
+        constraint = proposal.callback_program == Some(callback_program.key())
+            @ ErrorCode::CallbackProgramMismatch
+    )]
+    pub callback_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCallbackProgram<'info> {
+    #[account(mut, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseExpiredProposals<'info> {
+    #[account(mut, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: SystemAccount<'info>,
+    /// Receives the keeper reward.
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    /// CHECK: PDA signer recorded as `registry.governance`; holds no data
+    #[account(seeds = [b"governance", dao.key().as_ref()], bump)]
+    pub governance_signer: UncheckedAccount<'info>,
+    pub membership_program: Program<'info, Membership>,
+    // Besides keeper rewards, which `close_expired_proposals` pays only out
+    // of forfeited bonds, no other instruction moves lamports out of the
+    // treasury, so every withdrawal of DAO funds is a passed proposal's
+    // `Transfer` payload.
+    #[account(mut, seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: SystemAccount<'info>,
+    /// CHECK: Only receives lamports; must match a `Transfer` payload's destination
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    /// Marks the proposal executed and applies its payload, returning whether
+    /// it ran. Config changes are made with the program's own authority, so
+    /// no human signer is needed.
+    ///
+    /// A failing CPI reverts the whole transaction, attempt counter included,
+    /// so the target's known failure conditions are checked first. If they
+    /// do not hold, a failed attempt is recorded and the proposal stays
+    /// `Passed` for a retry, until `max_execution_attempts` marks it
+    /// `ExecutionFailed`.
+    fn apply_payload(&mut self, bumps: &ExecuteProposalBumps) -> Result<bool> {
+        require!(self.proposal.executed_at_slot == 0, ErrorCode::AlreadyExecuted);
+        if let ExecutionPayload::Transfer { destination, amount } = &self.proposal.payload {
+            require!(
+                self.destination.as_ref().map(|account| account.key()) == Some(*destination),
+                ErrorCode::InvalidTransfer
+            );
+            self.registry.check_spending_cap(&self.proposal.category, *amount)?;
+            self.registry.check_treasury_minimum(
+                self.treasury.lamports(),
+                *amount,
+                Rent::get()?.minimum_balance(0),
+            )?;
+        }
+
+        if !self.payload_ready()? {
+            self.proposal.execution_attempts += 1;
+            if self.proposal.execution_attempts >= self.dao.max_execution_attempts {
+                self.proposal.status.advance(ProposalStatus::ExecutionFailed)?;
+            }
+            if self.dao.logs(EventVerbosity::Errors) {
+                emit!(ExecutionAttemptFailed {
+                    dao: self.dao.key(),
+                    proposal_id: self.proposal.id,
+                    attempts: self.proposal.execution_attempts,
+                    sequence: self.dao.next_event_seq(),
+                });
+            }
+            return Ok(false);
+        }
+
+        self.proposal.status.advance(ProposalStatus::Executed)?;
+        self.proposal.executed_at_slot = Clock::get()?.slot;
+        // Anchor only serializes accounts when the instruction returns, so
+        // persist now: a target re-entering through a CPI must see the
+        // proposal as executed.
+        self.proposal.exit(&crate::ID)?;
+
+        if let ExecutionPayload::Transfer { amount, .. } = self.proposal.payload {
+            let dao_key = self.dao.key();
+            let seeds: &[&[u8]] = &[b"treasury", dao_key.as_ref(), &[bumps.treasury]];
+            let destination = self.destination.as_ref().ok_or(ErrorCode::InvalidTransfer)?;
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.treasury.to_account_info(),
+                        to: destination.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+            )?;
+            if self.dao.logs(EventVerbosity::All) {
+                emit!(TreasuryWithdraw {
+                    dao: dao_key,
+                    amount,
+                    mint: None,
+                    balance_after: self.treasury.lamports(),
+                    sequence: self.dao.next_event_seq(),
+                });
+            }
+        }
+
+        if let ExecutionPayload::SetLegalInfo { legal_entity_id, jurisdiction } =
+            self.proposal.payload.clone()
+        {
+            self.dao.legal_entity_id = legal_entity_id;
+            self.dao.jurisdiction = jurisdiction;
+        }
+
+        if let ExecutionPayload::SetConfig { field, value } = self.proposal.payload.clone() {
+            match field {
+                ConfigField::QuorumBps => self.dao.quorum_bps = value as u16,
+                ConfigField::ThresholdBps => self.dao.threshold_bps = value as u16,
+                ConfigField::MinVotingSecs => self.dao.min_voting_secs = value as i64,
+                ConfigField::MaxOpenProposals => self.dao.max_open_proposals = value,
+                ConfigField::QuadraticVoting => self.dao.quadratic_voting = value != 0,
+                ConfigField::MinCosponsors => self.dao.min_cosponsors = value as u8,
+                ConfigField::ProposalBond => self.dao.proposal_bond = value,
+                ConfigField::EarlyClose => self.dao.early_close = value != 0,
+                ConfigField::RevealSecs => self.dao.reveal_secs = value as i64,
+                ConfigField::KeeperReward => self.dao.keeper_reward = value,
+                ConfigField::MaxKeeperReward => self.dao.max_keeper_reward = value,
+                ConfigField::EventVerbosity => {
+                    self.dao.event_verbosity = match value {
+                        0 => EventVerbosity::Off,
+                        1 => EventVerbosity::Errors,
+                        _ => EventVerbosity::All,
+                    }
+                }
+                ConfigField::QuadRounding => {
+                    self.dao.quad_rounding = match value {
+                        0 => QuadRounding::Floor,
+                        1 => QuadRounding::Round,
+                        _ => QuadRounding::Ceil,
+                    }
+                }
+                ConfigField::MaxMembers => {
+                    let dao_key = self.dao.key();
+                    let seeds: &[&[u8]] = &[b"governance", dao_key.as_ref(), &[bumps.governance_signer]];
+                    membership::cpi::update_config(
+                        CpiContext::new_with_signer(
+                            self.membership_program.to_account_info(),
+                            membership::cpi::accounts::UpdateConfig {
+                                registry: self.registry.to_account_info(),
+                                audit_log: self.audit_log.to_account_info(),
+                                signer: self.governance_signer.to_account_info(),
+                            },
+                            &[seeds],
+                        ),
+                        RegistryField::MaxMembers,
+                        value,
+                    )?;
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Whether the payload's target can accept it right now.
+    fn payload_ready(&self) -> Result<bool> {
+        Ok(match &self.proposal.payload {
+            ExecutionPayload::SetConfig { field: ConfigField::MaxMembers, .. } => {
+                self.registry.governance == self.governance_signer.key()
+                    && self.registry.audit_log == self.audit_log.key()
+                    && (self.audit_log.next_offset as usize) < membership::AUDIT_LOG_CAPACITY
+                    && membership::assert_capability(&self.registry, membership::Capability::UpdateConfig)
+                        .is_ok()
+            }
+            // The treasury may be emptied, but not left below rent exemption.
+            ExecutionPayload::Transfer { amount, .. } => {
+                let rent_exempt = Rent::get()?.minimum_balance(0);
+                self.treasury
+                    .lamports()
+                    .checked_sub(*amount)
+                    .is_some_and(|left| left == 0 || left >= rent_exempt)
+            }
+            _ => true,
+        })
+    }
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, seeds = [b"treasury", dao.key().as_ref()], bump)]
+    pub treasury: SystemAccount<'info>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetProposalStatus<'info> {
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct GetProposalDetails<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct BeginRevealPhase<'info> {
+    #[account(mut)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct GetTimeRemaining<'info> {
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+pub struct GetConfig<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct CanReachQuorum<'info> {
+    #[account(has_one = registry)]
+    pub dao: Account<'info, Dao>,
+    pub registry: Account<'info, MemberRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct GetLegalInfo<'info> {
+    pub dao: Account<'info, Dao>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProposalAccount<'info> {
+    #[account(has_one = authority)]
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao, close = destination)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[account]
+pub struct Dao {
+    pub authority: Pubkey,
+    pub proposal_count: u64,
+    pub member_count: u64,
+    // Wyoming DAO LLC Compliance Fields
+    pub legal_name: String,
+    pub registered_agent_address: String,
+    pub principal_place_of_business: String,
+    pub formation_date: i64,
+    pub jurisdiction: String,
+    pub entity_type: String,
+    // Governance configuration
+    pub registry: Pubkey,
+    pub quorum_bps: u16,
+    pub threshold_bps: u16,
+    pub voting_period: i64,
+    pub retention_period: i64,
+    pub timelock: i64,
+    pub multisig_signers: Vec<Pubkey>,
+    pub multisig_threshold: u8,
+    pub min_voting_secs: i64,
+    pub max_execution_attempts: u8,
+    pub max_open_proposals: u64, // 0 means no cap
+    pub open_proposal_count: u64,
+    pub quadratic_voting: bool, // weigh votes by the integer square root of power
+    pub quad_rounding: QuadRounding,
+    pub min_cosponsors: u8, // co-sponsors a proposal needs before it can be voted on
+    pub proposal_bond: u64, // lamports escrowed by each new proposal, 0 for none
+    pub early_close: bool, // close a proposal on the vote that decides it
+    pub proposal_prefix: String, // starts every proposal reference
+    // Voting-power epoch new proposals are created in; 0 before the first
+    // `advance_epoch`, meaning live power.
+    pub current_epoch: u64,
+    pub epoch_snapshot: Pubkey,
+    pub epoch_root: [u8; 32], // hash of the snapshot's entries
+    pub epoch_total_power: u64,
+    pub reveal_secs: i64, // reveal window after voting on new proposals, 0 for open voting
+    pub event_verbosity: EventVerbosity, // which events and logs instructions emit
+    pub legal_entity_id: String, // state filing ID; changed only by a passed `SetLegalInfo` proposal
+    pub event_seq: u64, // `sequence` of the next event emitted for this DAO
+    // IDs `reserved_start..reserved_start + reserved_count` from the last
+    // `preallocate_proposals`; bit `i` of `reserved_taken` is set once ID
+    // `reserved_start + i` has a proposal account.
+    pub reserved_start: u64,
+    pub reserved_count: u64,
+    pub reserved_taken: u64,
+    pub keeper_reward: u64, // lamports per proposal `close_expired_proposals` closes, 0 for none
+    pub max_keeper_reward: u64, // most lamports one `close_expired_proposals` call pays, 0 for no cap
+    pub keeper_pool: u64, // forfeited bond lamports in the treasury not yet paid to keepers
+}
+
+impl Dao {
+    /// The weighting a proposal created now is voted under.
+    pub fn vote_mode(&self) -> VoteMode {
+        VoteMode {
+            quadratic_voting: self.quadratic_voting,
+            quad_rounding: self.quad_rounding.clone(),
+        }
+    }
+
+    /// Whether instructions on this DAO emit events and logs of `level`.
+    pub fn logs(&self, level: EventVerbosity) -> bool {
+        self.event_verbosity >= level
+    }
+
+    /// Frees the open-proposal slot of a proposal leaving `Active`. Fails
+    /// with `OpenProposalCountMismatch` if none is held, rather than
+    /// wrapping and blocking every new proposal.
+    pub fn release_open_slot(&mut self) -> Result<()> {
+        self.open_proposal_count = self
+            .open_proposal_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::OpenProposalCountMismatch)?;
+        Ok(())
+    }
+
+    /// Claims reserved ID `id` for a new proposal account, failing with
+    /// `ProposalIdNotReserved` if the current reservation does not hold it
+    /// or it has already been claimed.
+    pub fn take_reserved_id(&mut self, id: u64) -> Result<()> {
+        let offset = id.wrapping_sub(self.reserved_start);
+        require!(
+            id >= self.reserved_start
+                && offset < self.reserved_count
+                && self.reserved_taken & (1 << offset) == 0,
+            ErrorCode::ProposalIdNotReserved
+        );
+        self.reserved_taken |= 1 << offset;
+        Ok(())
+    }
+
+    /// Hands out the `sequence` for an event about to be emitted. Numbers
+    /// run from 0 without gaps across instructions, so a client that sees
+    /// one skipped knows it missed an event.
+    pub fn next_event_seq(&mut self) -> u64 {
+        let sequence = self.event_seq;
+        self.event_seq += 1;
+        sequence
+    }
+}
+
+#[account]
+pub struct MultiChoiceProposal {
+    pub dao: Pubkey,
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub proposer: Pubkey,
+    pub options: Vec<String>,
+    pub tallies: Vec<u64>, // weighted votes per option, same order as `options`
+    pub status: ProposalStatus,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub closed_at: i64,
+    pub winning_option: Option<u8>,
+    pub reference: String, // human-readable citation, see `proposal_reference`
+    // Bond, co-sponsors, electorate and rules work as on `Proposal`.
+    pub bond: u64,
+    pub bond_state: BondState,
+    pub quorum_met: bool,
+    pub cosponsors: Vec<Pubkey>,
+    pub eligibility: VoterEligibility,
+    pub eligible_power: u64,
+    pub epoch: u64,
+    pub epoch_snapshot: Option<Pubkey>,
+    pub epoch_total_power: u64,
+    pub quorum_bps: u16,
+    pub vote_mode: VoteMode,
+    pub threshold_bps: u16, // share of the top two options' votes the winner needs
+}
+
+impl MultiChoiceProposal {
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 8 + 256 + 512 + 32
+        + 4 + MAX_PROPOSAL_OPTIONS * (4 + MAX_OPTION_LEN)
+        + 4 + MAX_PROPOSAL_OPTIONS * 8
+        + 1 + 8 + 8 + 8 + 2
+        + 4 + MAX_PROPOSAL_REFERENCE_LEN
+        + 8 + 1 + 1
+        + 4 + MAX_COSPONSORS * 32
+        + 1 + 4 + MAX_ALLOWLIST_LEN * 32
+        + 8 + 8 + 33 + 8 + 2 + 2 + 2;
+}
+
+#[account]
+pub struct MultiVoteRecord {
+    pub voter: Pubkey,
+    pub option_index: u8,
+    pub weight: u64,
+}
+
+#[account]
+pub struct Comment {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub author: Pubkey,
+    pub posted_at: i64,
+    pub text: String,
+}
+
+#[account]
+pub struct VoteCommitment {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub commitment: [u8; 32],
+}
+
+/// Which members may vote on a proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum VoterEligibility {
+    All,
+    MemberType(MemberType),
+    Allowlist(Vec<Pubkey>), // member pubkeys
+}
+
+impl VoterEligibility {
+    pub fn admits(&self, member: &Member) -> bool {
+        match self {
+            VoterEligibility::All => true,
+            VoterEligibility::MemberType(member_type) => member.member_type == *member_type,
+            VoterEligibility::Allowlist(pubkeys) => pubkeys.contains(&member.pubkey),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum BondState {
+    None,
+    Held,
+    Refunded,
+    Forfeited,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ExecutionPayload {
+    None,
+    SetConfig { field: ConfigField, value: u64 },
+    Transfer { destination: Pubkey, amount: u64 }, // lamports from the DAO treasury
+    SetLegalInfo { legal_entity_id: String, jurisdiction: String },
+}
+
+/// Room a proposal needs beyond the `Transfer` payload to hold a
+/// `SetLegalInfo` one at its limits.
+const LEGAL_INFO_PAYLOAD_EXTRA: usize =
+    4 + MAX_LEGAL_ENTITY_ID_LEN + 4 + MAX_JURISDICTION_LEN - (32 + 8);
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ConfigField {
+    QuorumBps,
+    ThresholdBps,
+    MaxMembers,
+    MinVotingSecs,
+    MaxOpenProposals,
+    QuadraticVoting,
+    QuadRounding,
+    MinCosponsors,
+    ProposalBond,
+    EarlyClose,
+    RevealSecs,
+    EventVerbosity, // 0 off, 1 errors only, 2 all
+    KeeperReward,
+    MaxKeeperReward,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum QuadRounding {
+    Floor,
+    Round,
+    Ceil,
+}
+
+/// How voting power turns into vote weight.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct VoteMode {
+    pub quadratic_voting: bool,
+    pub quad_rounding: QuadRounding,
+}
+
+impl VoteMode {
+    pub fn weigh(&self, power: u64) -> u64 {
+        if self.quadratic_voting {
+            quadratic_weight(power, &self.quad_rounding)
+        } else {
+            power
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Config {
+    pub authority: Pubkey,
+    pub registry: Pubkey,
+    pub quorum_bps: u16,
+    pub threshold_bps: u16,
+    pub voting_period: i64,
+    pub min_voting_secs: i64,
+    pub timelock: i64,
+    pub retention_period: i64,
+    pub multisig_signers: Vec<Pubkey>,
+    pub multisig_threshold: u8,
+    pub max_execution_attempts: u8,
+    pub max_members: u64,
+    pub max_delegation_depth: u8,
+    pub reuse_ids: bool,
+    pub registration_fee: u64,
+    pub treasury: Pubkey,
+    pub max_open_proposals: u64,
+    pub open_proposal_count: u64,
+    pub quadratic_voting: bool,
+    pub quad_rounding: QuadRounding,
+    pub min_cosponsors: u8,
+    pub proposal_bond: u64,
+    pub early_close: bool,
+    pub proposal_prefix: String,
+    pub reveal_secs: i64,
+    pub event_verbosity: EventVerbosity,
+    pub keeper_reward: u64,
+    pub max_keeper_reward: u64,
+    // The rest of the registry's settings; role holders are left to the
+    // registry account itself, which would not fit in return data.
+    pub decay: DecayConfig,
+    pub self_registration: bool,
+    pub default_voting_power: u64,
+    pub require_ai_sponsor: bool,
+    pub normalized_total: Option<u64>,
+    pub ai_power_multiplier_bps: u16,
+    pub rent_buffer_lamports: u64,
+    pub max_member_power: u64,
+    pub require_model_id: bool,
+    pub voting_eligibility_delay_secs: i64,
+    pub require_contact_hash: bool,
+    pub operational_cap: u64,
+    pub financial_cap: u64,
+    pub treasury_min_balance: u64,
+    pub power_tiers: Vec<PowerTier>,
+    pub require_human_majority_count: bool,
+    pub registry_event_verbosity: EventVerbosity,
+    pub dues_amount: u64,
+    pub dues_period_secs: i64,
+    pub unique_names: bool,
+    pub max_votes_per_window: u8,
+    pub vote_window_slots: u64,
+    pub agreement_hash: [u8; 32],
+    pub successor: Pubkey,
+    pub succession_timeout_slots: u64,
+    pub min_deposit: u64,
+}
+
+/// Arguments of the `proposal_result_callback` instruction a callback
+/// program must expose, encoded the way Anchor encodes instruction data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalResultCallback {
+    pub dao: Pubkey,
+    pub proposal_id: u64,
+    pub result: ProposalStatus,
+}
+
+/// The DAO's legal-entity record, as returned by `get_legal_info`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegalInfo {
+    pub legal_name: String,
+    pub entity_type: String,
+    pub jurisdiction: String,
+    pub legal_entity_id: String,
+    pub registered_agent_address: String,
+    pub principal_place_of_business: String,
+    pub formation_date: i64,
+}
+
+/// A proposal's full lifecycle state, as returned by `get_proposal_details`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalDetails {
+    pub id: u64,
+    pub status: ProposalStatus,
+    pub proposer: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub quorum_bps: u16,
+    pub threshold_bps: u16,
+    pub quorum_base: u64, // power quorum and threshold are measured against
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub reveal_ends_at: i64,
+    pub closed_at: i64,
+    pub quorum_met: bool,
+    pub threshold_met: bool,
+    pub current_result: ProposalStatus, // Passed or Rejected while still Active
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TimeRemaining {
+    pub seconds: u64,
+    pub open: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MultiChoiceLeader {
+    pub option_index: Option<u8>,
+    pub weight: u64,
+    pub tied: bool,
+}
+
+#[event]
+pub struct EpochAdvanced {
+    pub dao: Pubkey,
+    pub epoch: u64,
+    pub snapshot: Pubkey,
+    pub root: [u8; 32],
+    pub sequence: u64,
+}
+
+#[event]
+pub struct RevealPhaseBegun {
+    pub dao: Pubkey,
+    pub proposal_id: u64,
+    pub reveal_ends_at: i64,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct ProposalClosed {
+    pub dao: Pubkey,
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub dao: Pubkey,
+    pub proposal_id: u64,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct ExecutionAttemptFailed {
+    pub dao: Pubkey,
+    pub proposal_id: u64,
+    pub attempts: u8,
+    pub sequence: u64,
+}
+
+/// Lamport movements in and out of the DAO treasury. `mint` is `None` for
+/// lamports and names the token mint for SPL transfers.
+#[event]
+pub struct TreasuryDeposit {
+    pub dao: Pubkey,
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+    pub balance_after: u64,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct TreasuryWithdraw {
+    pub dao: Pubkey,
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+    pub balance_after: u64,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct EmergencyExecuted {
+    pub dao: Pubkey,
+    pub proposal_id: u64,
+    pub signers: Vec<Pubkey>,
+    pub sequence: u64,
 }
 
 #[error_code]
@@ -148,4 +2900,110 @@ pub enum ErrorCode {
     ProposalNotActive,
     #[msg("Already voted on this proposal")]
     AlreadyVoted,
+    #[msg("Voting period has ended")]
+    VotingEnded,
+    #[msg("Voting period has not ended yet")]
+    VotingNotEnded,
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+    #[msg("Config value is out of range")]
+    InvalidConfigValue,
+    #[msg("Voter is not an active member of this DAO")]
+    InvalidMember,
+    #[msg("Member has delegated their vote")]
+    VoteDelegated,
+    #[msg("Proposal account does not match the given proposal id")]
+    ProposalMismatch,
+    #[msg("Proposal has not reached a terminal state")]
+    ProposalNotFinished,
+    #[msg("Proposal is still within its retention period")]
+    RetentionPeriodActive,
+    #[msg("Proposal is still in its timelock")]
+    TimelockActive,
+    #[msg("Multisig signers or threshold are invalid")]
+    InvalidMultisig,
+    #[msg("Not enough multisig signers approved this action")]
+    MultisigThresholdNotMet,
+    #[msg("Proposal needs 2 to 8 options of at most 64 bytes each")]
+    InvalidOptions,
+    #[msg("Option index is out of range")]
+    InvalidOptionIndex,
+    #[msg("Proposal is still inside its minimum voting window")]
+    MinimumVotingPeriodActive,
+    #[msg("Voting window ends past the largest timestamp")]
+    VotingWindowOverflow,
+    #[msg("Proposal has already been executed")]
+    AlreadyExecuted,
+    #[msg("Too many proposals are open at once")]
+    TooManyOpenProposals,
+    #[msg("Vote rationale exceeds the maximum length")]
+    RationaleTooLong,
+    #[msg("Quorum override must be between the DAO quorum and 10000 bps")]
+    InvalidQuorumOverride,
+    #[msg("Member is under dispute and cannot vote")]
+    MemberUnderDispute,
+    #[msg("Transfer amount or destination is invalid")]
+    InvalidTransfer,
+    #[msg("Proposal does not have enough co-sponsors to be voted on")]
+    InsufficientCosponsors,
+    #[msg("Member is already the proposer or a co-sponsor")]
+    AlreadyCosponsored,
+    #[msg("Proposal already has the maximum number of co-sponsors")]
+    TooManyCosponsors,
+    #[msg("Proposal holds no bond")]
+    BondNotHeld,
+    #[msg("Proposal missed quorum, so its bond is forfeited")]
+    BondForfeited,
+    #[msg("Proposal reached quorum, so its bond is refundable")]
+    BondRefundable,
+    #[msg("Bond can only be refunded to the proposer")]
+    InvalidBondRecipient,
+    #[msg("Proposal bond must be refunded or forfeited first")]
+    BondNotSettled,
+    #[msg("Every member account must be passed exactly once")]
+    IncompleteMemberSet,
+    #[msg("Proposal already has votes")]
+    ProposalHasVotes,
+    #[msg("Proposal prefix must be 1 to 16 ASCII letters or digits")]
+    InvalidProposalPrefix,
+    #[msg("Voter allowlist is empty, too long or repeats a member")]
+    InvalidAllowlist,
+    #[msg("A restricted proposal needs the DAO's registry")]
+    RegistryRequired,
+    #[msg("Comment exceeds the maximum length")]
+    CommentTooLong,
+    #[msg("Snapshot does not belong to this epoch or registry")]
+    EpochSnapshotMismatch,
+    #[msg("Voter is not in the epoch's snapshot")]
+    NotInEpochSnapshot,
+    #[msg("Proposal takes committed votes; use commit_vote and reveal_vote")]
+    CommitRevealRequired,
+    #[msg("Proposal does not use commit-reveal voting")]
+    NotCommitReveal,
+    #[msg("Reveal window is not open")]
+    RevealNotOpen,
+    #[msg("Commitment does not belong to this voter and proposal")]
+    CommitmentMismatch,
+    #[msg("Choice and salt do not match the commitment")]
+    InvalidReveal,
+    #[msg("Proposal's reveal phase has already begun")]
+    RevealPhaseStarted,
+    #[msg("Reveal window ends past the largest timestamp")]
+    RevealWindowOverflow,
+    #[msg("Legal entity ID or jurisdiction is empty or too long")]
+    InvalidLegalInfo,
+    #[msg("Proposal cannot move to that status from its current one")]
+    InvalidStatusTransition,
+    #[msg("Proposal ID is not reserved or already has an account")]
+    ProposalIdNotReserved,
+    #[msg("Reservation must be between 1 and the maximum, with no more placeholders than IDs")]
+    InvalidReservation,
+    #[msg("Placeholder account must be a new account that signs")]
+    InvalidPlaceholder,
+    #[msg("Open proposal count does not match the proposals still open")]
+    OpenProposalCountMismatch,
+    #[msg("Proposal has no callback waiting to be delivered")]
+    NoCallbackPending,
+    #[msg("Account is not the proposal's callback program")]
+    CallbackProgramMismatch,
 }
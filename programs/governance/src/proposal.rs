@@ -0,0 +1,358 @@
+//! Binary proposals: the account, its lifecycle, and how its votes are
+//! tallied. Instructions in `lib.rs` move a proposal between statuses only
+//! through `ProposalStatus::advance`, and settle it only through `tally`.
+
+use anchor_lang::prelude::*;
+use membership::bps;
+use membership::{EventVerbosity, MemberRegistry, SpendingCategory};
+
+use crate::{
+    BondState, Dao, ErrorCode, ExecutionPayload, ProposalClosed, QuadRounding, VoteMode,
+    VoterEligibility, LEGAL_INFO_PAYLOAD_EXTRA, MAX_ALLOWLIST_LEN, MAX_COSPONSORS,
+    MAX_PROPOSAL_REFERENCE_LEN,
+};
+
+#[account]
+pub struct Proposal {
+    pub dao: Pubkey,
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub amount: u64,
+    pub proposer: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub status: ProposalStatus,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub payload: ExecutionPayload,
+    pub closed_at: i64,
+    pub emergency: bool,
+    pub executed_at_slot: u64,
+    pub execution_attempts: u8,
+    pub quorum_bps_override: Option<u16>,
+    pub cosponsors: Vec<Pubkey>,
+    pub bond: u64,
+    pub bond_state: BondState,
+    pub quorum_met: bool, // set on tally; decides whether the bond is refunded
+    pub callback_program: Option<Pubkey>, // told the result by `notify_callback`
+    pub callback_pending: bool, // set on tally while `callback_program` has yet to be told
+    pub category: SpendingCategory, // picks the registry spending cap a transfer is held to
+    pub reference: String, // human-readable citation, see `proposal_reference`
+    pub eligibility: VoterEligibility,
+    pub eligible_power: u64, // quorum base for a restricted proposal, fixed at creation
+    pub comment_count: u64, // next comment index
+    pub epoch: u64,
+    pub epoch_snapshot: Option<Pubkey>, // weights votes instead of live power when set
+    pub epoch_total_power: u64,
+    pub reveal_secs: i64, // votes are committed, then revealed for this long after voting ends
+    pub reveal_ends_at: i64, // set by `begin_reveal_phase`, 0 before it
+    // The DAO's rules when the proposal was created, so later config changes
+    // cannot move its goalposts.
+    pub quorum_bps: u16, // the override if one was given
+    pub threshold_bps: u16,
+    pub vote_mode: VoteMode,
+}
+
+impl Proposal {
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 8 + 256 + 512 + 8 + 32 + 8 + 8 + 1 + 8 + 8
+        + 1 + 1 + 8 + 8 + 1 + 8 + 1 + 3 + 31
+        + 4 + MAX_COSPONSORS * 32
+        + 8 + 1 + 1 + 33 + 1 + 1
+        + 4 + MAX_PROPOSAL_REFERENCE_LEN
+        + 1 + 4 + MAX_ALLOWLIST_LEN * 32
+        + 8 + 8 + 8 + 33 + 8 + 8 + 8 + 2 + 2 + 2
+        + LEGAL_INFO_PAYLOAD_EXTRA;
+
+    /// A `Pending` proposal holding reserved ID `id` until `fill_proposal`
+    /// gives it its terms.
+    pub(crate) fn placeholder(dao: Pubkey, id: u64, proposer: Pubkey) -> Proposal {
+        Proposal {
+            dao,
+            id,
+            title: String::new(),
+            description: String::new(),
+            amount: 0,
+            proposer,
+            votes_for: 0,
+            votes_against: 0,
+            status: ProposalStatus::Pending,
+            created_at: 0,
+            voting_ends_at: 0,
+            payload: ExecutionPayload::None,
+            closed_at: 0,
+            emergency: false,
+            executed_at_slot: 0,
+            execution_attempts: 0,
+            quorum_bps_override: None,
+            cosponsors: Vec::new(),
+            bond: 0,
+            bond_state: BondState::None,
+            quorum_met: false,
+            callback_program: None,
+            callback_pending: false,
+            category: SpendingCategory::Operational,
+            reference: String::new(),
+            eligibility: VoterEligibility::All,
+            eligible_power: 0,
+            comment_count: 0,
+            epoch: 0,
+            epoch_snapshot: None,
+            epoch_total_power: 0,
+            reveal_secs: 0,
+            reveal_ends_at: 0,
+            quorum_bps: 0,
+            threshold_bps: 0,
+            vote_mode: VoteMode {
+                quadratic_voting: false,
+                quad_rounding: QuadRounding::Floor,
+            },
+        }
+    }
+}
+
+#[account]
+pub struct VoteRecord {
+    pub has_voted: bool,
+    pub support: bool,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub rationale: String,
+}
+
+/// Where a proposal is in its lifecycle. Proposals are created `Active`,
+/// or `Pending` as placeholders for IDs reserved by
+/// `preallocate_proposals`; `can_advance_to` lists every move after that.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Executed,
+    Rejected,
+    ExecutionFailed,
+    Cancelled,
+    Pending,
+}
+
+impl ProposalStatus {
+    /// Whether a proposal may move from this status to `next`: a Pending
+    /// placeholder is filled in, an Active proposal is tallied or
+    /// cancelled, a Passed one is executed or gives up after its last
+    /// failed attempt, and nothing leaves the others.
+    pub fn can_advance_to(&self, next: &ProposalStatus) -> bool {
+        use ProposalStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Active)
+                | (Active, Passed | Rejected | Cancelled)
+                | (Passed, Executed | ExecutionFailed)
+        )
+    }
+
+    /// Moves to `next`, failing with `InvalidStatusTransition` if
+    /// `can_advance_to` does not allow it.
+    pub fn advance(&mut self, next: ProposalStatus) -> Result<()> {
+        require!(self.can_advance_to(&next), ErrorCode::InvalidStatusTransition);
+        *self = next;
+        Ok(())
+    }
+
+    /// Whether no further status change is possible.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            ProposalStatus::Executed
+                | ProposalStatus::Rejected
+                | ProposalStatus::ExecutionFailed
+                | ProposalStatus::Cancelled
+        )
+    }
+}
+
+/// How a set of votes settles a proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum ProposalResult {
+    Passed,
+    QuorumNotMet,
+    ThresholdNotMet,
+}
+
+impl ProposalResult {
+    /// Status a proposal is tallied to with this result.
+    pub fn status(&self) -> ProposalStatus {
+        match self {
+            ProposalResult::Passed => ProposalStatus::Passed,
+            _ => ProposalStatus::Rejected,
+        }
+    }
+}
+
+/// Settles a vote. Every vote cast, abstentions included, counts towards
+/// quorum, measured against `total_power`; the proposal then passes if
+/// `yes` is strictly more than `threshold_bps` of the yes and no votes.
+/// Quorum is checked first, so a vote missing both reports `QuorumNotMet`.
+pub fn compute_result(
+    yes: u64,
+    no: u64,
+    abstain: u64,
+    total_power: u64,
+    quorum_bps: u16,
+    threshold_bps: u16,
+) -> ProposalResult {
+    let cast = yes.saturating_add(no).saturating_add(abstain);
+    if !meets_quorum(cast, quorum_bps, total_power) {
+        ProposalResult::QuorumNotMet
+    } else if !bps::exceeds_bps(yes, yes.saturating_add(no), threshold_bps) {
+        ProposalResult::ThresholdNotMet
+    } else {
+        ProposalResult::Passed
+    }
+}
+
+/// When a proposal can be tallied: the end of voting, or of the reveal
+/// window on commit-reveal proposals, which cannot close before
+/// `begin_reveal_phase` has opened one.
+pub(crate) fn voting_closes_at(proposal: &Proposal) -> i64 {
+    match (proposal.reveal_secs, proposal.reveal_ends_at) {
+        (0, _) => proposal.voting_ends_at,
+        (_, 0) => i64::MAX,
+        (_, reveal_ends_at) => reveal_ends_at,
+    }
+}
+
+/// Power a proposal's quorum and threshold are measured against: the
+/// whole registry, or its epoch's snapshot if it has one, or for a
+/// restricted proposal the power it admitted when created.
+pub(crate) fn quorum_base(proposal: &Proposal, registry: &MemberRegistry) -> u64 {
+    electorate_power(
+        &proposal.eligibility,
+        proposal.epoch_snapshot,
+        proposal.epoch_total_power,
+        proposal.eligible_power,
+        registry,
+    )
+}
+
+/// `quorum_base` for any kind of proposal, from the electorate it recorded
+/// when opened.
+pub(crate) fn electorate_power(
+    eligibility: &VoterEligibility,
+    epoch_snapshot: Option<Pubkey>,
+    epoch_total_power: u64,
+    eligible_power: u64,
+    registry: &MemberRegistry,
+) -> u64 {
+    match eligibility {
+        VoterEligibility::All if epoch_snapshot.is_some() => epoch_total_power,
+        VoterEligibility::All => registry.effective_total_power(),
+        _ => eligible_power,
+    }
+}
+
+pub(crate) fn meets_quorum(cast: u64, quorum_bps: u16, total_power: u64) -> bool {
+    bps::meets_bps(cast, total_power, quorum_bps)
+}
+
+/// Whether a proposal has passed no matter how the power yet to vote is
+/// cast: quorum is met, and the yes votes clear the threshold even if every
+/// remaining unit of power votes against.
+pub(crate) fn is_decided(proposal: &Proposal, total_voting_power: u64) -> bool {
+    let cast = proposal.votes_for + proposal.votes_against;
+    meets_quorum(cast, proposal.quorum_bps, total_voting_power)
+        && bps::exceeds_bps(proposal.votes_for, cast.max(total_voting_power), proposal.threshold_bps)
+}
+
+/// Whether the votes cast so far meet the proposal's quorum and its
+/// threshold, measured against `total_voting_power`.
+pub(crate) fn outcome(proposal: &Proposal, total_voting_power: u64) -> (bool, bool) {
+    let cast = proposal.votes_for + proposal.votes_against;
+    (
+        meets_quorum(cast, proposal.quorum_bps, total_voting_power),
+        bps::exceeds_bps(proposal.votes_for, cast, proposal.threshold_bps),
+    )
+}
+
+/// The result the votes cast so far would settle `proposal` to.
+pub(crate) fn current_result(proposal: &Proposal, total_voting_power: u64) -> ProposalResult {
+    compute_result(
+        proposal.votes_for,
+        proposal.votes_against,
+        0,
+        total_voting_power,
+        proposal.quorum_bps,
+        proposal.threshold_bps,
+    )
+}
+
+/// Settles an Active proposal as Passed or Rejected and frees its open slot.
+/// A proposal with a callback program is left with its callback pending.
+pub(crate) fn tally(
+    dao: &mut Dao,
+    proposal: &mut Proposal,
+    total_voting_power: u64,
+    now: i64,
+) -> Result<()> {
+    let result = current_result(proposal, total_voting_power);
+    proposal.quorum_met = result != ProposalResult::QuorumNotMet;
+    proposal.status.advance(result.status())?;
+    proposal.closed_at = now;
+    proposal.callback_pending = proposal.callback_program.is_some();
+    // Only an Active proposal gets here, so each one frees its slot once.
+    dao.release_open_slot()?;
+
+    if dao.logs(EventVerbosity::All) {
+        emit!(ProposalClosed {
+            dao: proposal.dao,
+            proposal_id: proposal.id,
+            status: proposal.status.clone(),
+            sequence: dao.next_event_seq(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_result_checks_quorum_before_threshold() {
+        assert_eq!(compute_result(10, 9, 0, 100, 2_000, 5_000), ProposalResult::QuorumNotMet);
+        assert_eq!(compute_result(0, 19, 0, 100, 2_000, 5_000), ProposalResult::QuorumNotMet);
+        assert_eq!(compute_result(0, 20, 0, 100, 2_000, 5_000), ProposalResult::ThresholdNotMet);
+    }
+
+    #[test]
+    fn compute_result_needs_strictly_more_than_the_threshold() {
+        assert_eq!(compute_result(51, 49, 0, 100, 2_000, 5_000), ProposalResult::Passed);
+        assert_eq!(compute_result(50, 50, 0, 100, 2_000, 5_000), ProposalResult::ThresholdNotMet);
+        assert_eq!(compute_result(66, 34, 0, 100, 2_000, 6_667), ProposalResult::ThresholdNotMet);
+        assert_eq!(compute_result(67, 33, 0, 100, 2_000, 6_667), ProposalResult::Passed);
+    }
+
+    #[test]
+    fn abstentions_count_towards_quorum_only() {
+        assert_eq!(compute_result(10, 5, 85, 100, 2_000, 5_000), ProposalResult::Passed);
+        assert_eq!(compute_result(5, 10, 85, 100, 2_000, 5_000), ProposalResult::ThresholdNotMet);
+        assert_eq!(compute_result(0, 0, 100, 100, 2_000, 5_000), ProposalResult::ThresholdNotMet);
+        assert_eq!(compute_result(5, 0, 10, 100, 2_000, 5_000), ProposalResult::QuorumNotMet);
+    }
+
+    #[test]
+    fn compute_result_with_no_power_meets_quorum_but_needs_a_yes() {
+        assert_eq!(compute_result(0, 0, 0, 0, 2_000, 5_000), ProposalResult::ThresholdNotMet);
+        assert_eq!(compute_result(1, 0, 0, 0, 2_000, 5_000), ProposalResult::Passed);
+    }
+
+    #[test]
+    fn compute_result_handles_u64_sized_tallies() {
+        let half = u64::MAX / 2;
+        assert_eq!(compute_result(half + 1, half, 0, u64::MAX, 5_000, 5_000), ProposalResult::Passed);
+        assert_eq!(
+            compute_result(half, half + 1, 0, u64::MAX, 5_000, 5_000),
+            ProposalResult::ThresholdNotMet
+        );
+        assert_eq!(compute_result(half, 0, 0, u64::MAX, 5_000, 0), ProposalResult::QuorumNotMet);
+        assert_eq!(compute_result(u64::MAX, 0, 0, u64::MAX, bps::MAX_BPS, 9_999), ProposalResult::Passed);
+    }
+}
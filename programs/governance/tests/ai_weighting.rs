@@ -0,0 +1,56 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, VoteRecord};
+use membership::{MemberType, RegistryField};
+
+#[test]
+fn multiplier_scales_only_ai_vote_weight() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let human = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let agent = add_member(&mut rt, &dao.registry, MemberType::AI, 100);
+    update_config(&mut rt, &dao.registry, RegistryField::AiPowerMultiplierBps, 5_000);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &human, true).unwrap();
+    vote(&mut rt, &dao, proposal, &agent, false).unwrap();
+
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&proposal, &human.account)).weight, 100);
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&proposal, &agent.account)).weight, 50);
+    assert_eq!(member(&rt, &agent).voting_power, 100);
+}
+
+#[test]
+fn default_multiplier_leaves_ai_weight_unscaled() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let agent = add_member(&mut rt, &dao.registry, MemberType::AI, 80);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &agent, true).unwrap();
+
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&proposal, &agent.account)).weight, 80);
+}
+
+#[test]
+fn multiplier_scales_power_delegated_by_ai_members() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let human = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let agent = add_member(&mut rt, &dao.registry, MemberType::AI, 40);
+    set_delegate(&mut rt, &dao.registry, &agent, &human);
+    update_config(&mut rt, &dao.registry, RegistryField::AiPowerMultiplierBps, 2_500);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    let mut ix = vote_ix(&dao, proposal, &human, true);
+    ix.accounts.push(AccountMeta::new_readonly(agent.account, false));
+    rt.send(ix, &[human.key]).unwrap();
+
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&proposal, &human.account)).weight, 110);
+}
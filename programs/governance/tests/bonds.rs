@@ -0,0 +1,86 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{BondState, ErrorCode, ExecutionPayload};
+use membership::MemberType;
+
+fn settle_accounts(dao: &TestDao, proposal: Pubkey) -> governance::accounts::SettleBond {
+    governance::accounts::SettleBond {
+        dao: dao.key,
+        proposal,
+        proposer: dao.authority,
+        treasury: dao.treasury,
+    }
+}
+
+fn refund_bond(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> std::result::Result<(), TxError> {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            settle_accounts(dao, proposal),
+            governance::instruction::RefundBond {},
+        ),
+        &[payer],
+    )
+}
+
+fn forfeit_bond(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> std::result::Result<(), TxError> {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            settle_accounts(dao, proposal),
+            governance::instruction::ForfeitBond {},
+        ),
+        &[payer],
+    )
+}
+
+#[test]
+fn passed_proposal_refunds_its_bond() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    set_dao_field(&mut rt, &dao, |state| state.proposal_bond = SOL);
+    let before = rt.lamports(&dao.authority);
+
+    let proposal = pass_proposal(&mut rt, &dao, &voter, ExecutionPayload::None);
+    // The proposer paid the bond on top of the proposal account's rent.
+    assert!(rt.lamports(&dao.authority) <= before - SOL);
+    let state = dao::proposal(&rt, &proposal);
+    assert_eq!(state.bond, SOL);
+    assert!(state.bond_state == BondState::Held);
+    assert_error(forfeit_bond(&mut rt, &dao, proposal), ErrorCode::BondRefundable);
+
+    let posted = rt.lamports(&dao.authority);
+    refund_bond(&mut rt, &dao, proposal).unwrap();
+
+    assert_eq!(rt.lamports(&dao.authority), posted + SOL);
+    assert!(dao::proposal(&rt, &proposal).bond_state == BondState::Refunded);
+    assert_error(refund_bond(&mut rt, &dao, proposal), ErrorCode::BondNotHeld);
+}
+
+#[test]
+fn proposal_without_quorum_forfeits_its_bond() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    set_dao_field(&mut rt, &dao, |state| state.proposal_bond = SOL);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_error(forfeit_bond(&mut rt, &dao, proposal), ErrorCode::ProposalNotFinished);
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert_error(refund_bond(&mut rt, &dao, proposal), ErrorCode::BondForfeited);
+    let treasury = rt.lamports(&dao.treasury);
+
+    forfeit_bond(&mut rt, &dao, proposal).unwrap();
+
+    assert_eq!(rt.lamports(&dao.treasury), treasury + SOL);
+    assert!(dao::proposal(&rt, &proposal).bond_state == BondState::Forfeited);
+}
@@ -0,0 +1,129 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use std::cell::RefCell;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, ProposalResultCallback, ProposalStatus};
+use membership::MemberType;
+
+thread_local! {
+    static FAILING: RefCell<bool> = const { RefCell::new(false) };
+    static RECEIVED: RefCell<Vec<ProposalResultCallback>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Stands in for a callback program: records each result it is told, or
+/// fails while `FAILING` is set.
+fn callback_target(_: &Pubkey, _: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    if FAILING.with(|failing| *failing.borrow()) {
+        return Err(ProgramError::Custom(1));
+    }
+    let args = ProposalResultCallback::try_from_slice(&data[8..])?;
+    RECEIVED.with(|received| received.borrow_mut().push(args));
+    Ok(())
+}
+
+fn set_callback_program(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey, callback_program: Pubkey) {
+    let proposal_id = dao::proposal(rt, &proposal).id;
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::SetCallbackProgram {
+                proposal,
+                proposer: dao.authority,
+            },
+            governance::instruction::SetCallbackProgram {
+                proposal_id,
+                callback_program: Some(callback_program),
+            },
+        ),
+        &[dao.authority],
+    )
+    .unwrap();
+}
+
+fn notify_callback(rt: &mut Runtime, proposal: Pubkey, callback_program: Pubkey) -> std::result::Result<(), TxError> {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::NotifyCallback {
+                proposal,
+                callback_program,
+            },
+            governance::instruction::NotifyCallback {},
+        ),
+        &[payer],
+    )
+}
+
+#[test]
+fn failed_callback_does_not_block_the_close_and_can_be_retried() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let callback_program = Pubkey::new_unique();
+    rt.add_program(callback_program, callback_target);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    set_callback_program(&mut rt, &dao, proposal, callback_program);
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    FAILING.with(|failing| *failing.borrow_mut() = true);
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert!(state.callback_pending);
+
+    assert!(notify_callback(&mut rt, proposal, callback_program).is_err());
+    assert!(dao::proposal(&rt, &proposal).callback_pending);
+    assert!(RECEIVED.with(|received| received.borrow().is_empty()));
+
+    FAILING.with(|failing| *failing.borrow_mut() = false);
+    notify_callback(&mut rt, proposal, callback_program).unwrap();
+    assert!(!dao::proposal(&rt, &proposal).callback_pending);
+    RECEIVED.with(|received| {
+        let received = received.borrow();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].dao, dao.key);
+        assert_eq!(received[0].proposal_id, state.id);
+        assert!(received[0].result == ProposalStatus::Passed);
+    });
+
+    assert_error(notify_callback(&mut rt, proposal, callback_program), ErrorCode::NoCallbackPending);
+}
+
+#[test]
+fn callback_is_only_delivered_to_the_proposals_program() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let callback_program = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    rt.add_program(callback_program, callback_target);
+    rt.add_program(other, callback_target);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    set_callback_program(&mut rt, &dao, proposal, callback_program);
+
+    assert_error(notify_callback(&mut rt, proposal, callback_program), ErrorCode::NoCallbackPending);
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+
+    assert_error(notify_callback(&mut rt, proposal, other), ErrorCode::CallbackProgramMismatch);
+    assert!(dao::proposal(&rt, &proposal).callback_pending);
+}
+
+#[test]
+fn proposal_without_a_callback_program_has_nothing_pending() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+
+    assert!(!dao::proposal(&rt, &proposal).callback_pending);
+}
@@ -0,0 +1,93 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{BondState, ErrorCode, ExecutionPayload, ProposalClosed, ProposalStatus};
+use membership::MemberType;
+
+fn cancel_proposal(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> std::result::Result<(), TxError> {
+    let proposal_id = dao::proposal(rt, &proposal).id;
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::CancelProposal {
+                dao: dao.key,
+                proposal,
+                proposer,
+            },
+            governance::instruction::CancelProposal { proposal_id },
+        ),
+        &[proposer],
+    )
+}
+
+#[test]
+fn proposer_cancels_a_proposal_without_votes() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.proposal_bond = SOL);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 1);
+    let before = rt.lamports(&dao.authority);
+
+    cancel_proposal(&mut rt, &dao, proposal, dao.authority).unwrap();
+
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Cancelled);
+    assert!(state.bond_state == BondState::Refunded);
+    assert_eq!(rt.lamports(&dao.authority), before + SOL);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 0);
+    let closed = rt.events::<ProposalClosed>();
+    assert_eq!(closed.len(), 1);
+    assert!(closed[0].status == ProposalStatus::Cancelled);
+    assert_error(cancel_proposal(&mut rt, &dao, proposal, dao.authority), ErrorCode::ProposalNotActive);
+}
+
+#[test]
+fn proposal_with_votes_cannot_be_cancelled() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, proposal, &voter, false).unwrap();
+
+    assert_error(cancel_proposal(&mut rt, &dao, proposal, dao.authority), ErrorCode::ProposalHasVotes);
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Active);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 1);
+}
+
+#[test]
+fn only_the_proposer_may_cancel() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let stranger = rt.wallet(SOL);
+
+    assert_error(
+        cancel_proposal(&mut rt, &dao, proposal, stranger),
+        anchor_lang::error::ErrorCode::ConstraintHasOne,
+    );
+}
+
+#[test]
+fn cancel_refuses_to_underflow_the_open_count() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    set_dao_field(&mut rt, &dao, |state| state.open_proposal_count = 0);
+
+    assert_error(
+        cancel_proposal(&mut rt, &dao, proposal, dao.authority),
+        ErrorCode::OpenProposalCountMismatch,
+    );
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Active);
+}
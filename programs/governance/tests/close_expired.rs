@@ -0,0 +1,120 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalStatus};
+use membership::MemberType;
+
+const REWARD: u64 = SOL / 1_000;
+
+fn forfeit_bond(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) {
+    let payer = rt.wallet(SOL);
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::SettleBond {
+            dao: dao.key,
+            proposal,
+            proposer: dao.authority,
+            treasury: dao.treasury,
+        },
+        governance::instruction::ForfeitBond {},
+    );
+    rt.send(ix, &[payer]).unwrap();
+}
+
+/// Opens `count` proposals and lets their voting period run out.
+fn expired_proposals(rt: &mut Runtime, dao: &TestDao, count: usize) -> Vec<Pubkey> {
+    let proposals = (0..count)
+        .map(|_| create_proposal(rt, dao, ExecutionPayload::None))
+        .collect();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    proposals
+}
+
+#[test]
+fn closes_expired_proposals_and_skips_open_ones() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let passing = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let unvoted = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, passing, &member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    let open = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    let keeper = rt.wallet(SOL);
+    close_expired(&mut rt, &dao, keeper, &[passing, unvoted, open]);
+
+    assert!(dao::proposal(&rt, &passing).status == ProposalStatus::Passed);
+    assert!(dao::proposal(&rt, &unvoted).status == ProposalStatus::Rejected);
+    assert!(dao::proposal(&rt, &open).status == ProposalStatus::Active);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 1);
+}
+
+#[test]
+fn keeper_is_rewarded_per_proposal_closed() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| {
+        state.keeper_reward = REWARD;
+        state.keeper_pool = SOL;
+    });
+    let proposals = expired_proposals(&mut rt, &dao, 2);
+    let open = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let keeper = rt.wallet(SOL);
+    let treasury = rt.lamports(&dao.treasury);
+
+    close_expired(&mut rt, &dao, keeper, &[proposals[0], proposals[1], open]);
+    assert_eq!(rt.lamports(&keeper), SOL + 2 * REWARD);
+    assert_eq!(rt.lamports(&dao.treasury), treasury - 2 * REWARD);
+    assert_eq!(dao.dao(&rt).keeper_pool, SOL - 2 * REWARD);
+
+    // Proposals already closed earn nothing.
+    close_expired(&mut rt, &dao, keeper, &proposals);
+    assert_eq!(rt.lamports(&keeper), SOL + 2 * REWARD);
+}
+
+#[test]
+fn reward_per_call_is_capped() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| {
+        state.keeper_reward = REWARD;
+        state.max_keeper_reward = 2 * REWARD;
+        state.keeper_pool = SOL;
+    });
+    let proposals = expired_proposals(&mut rt, &dao, 3);
+    let keeper = rt.wallet(SOL);
+
+    close_expired(&mut rt, &dao, keeper, &proposals);
+    assert_eq!(rt.lamports(&keeper), SOL + 2 * REWARD);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 0);
+}
+
+#[test]
+fn rewards_are_paid_only_out_of_forfeited_bonds() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    set_dao_field(&mut rt, &dao, |state| {
+        state.keeper_reward = REWARD;
+        state.proposal_bond = REWARD;
+    });
+    let proposals = expired_proposals(&mut rt, &dao, 3);
+    let keeper = rt.wallet(SOL);
+    let treasury = rt.lamports(&dao.treasury);
+
+    close_expired(&mut rt, &dao, keeper, &proposals[..1]);
+    assert_eq!(rt.lamports(&keeper), SOL);
+    assert_eq!(rt.lamports(&dao.treasury), treasury);
+
+    forfeit_bond(&mut rt, &dao, proposals[0]);
+    assert_eq!(dao.dao(&rt).keeper_pool, REWARD);
+    close_expired(&mut rt, &dao, keeper, &proposals[1..]);
+    assert_eq!(rt.lamports(&keeper), SOL + REWARD);
+    assert_eq!(rt.lamports(&dao.treasury), treasury);
+    assert_eq!(dao.dao(&rt).keeper_pool, 0);
+}
@@ -0,0 +1,80 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{Comment, ErrorCode, ExecutionPayload, MAX_COMMENT_LEN};
+use membership::MemberType;
+
+fn comment_address(proposal: &Pubkey, index: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"comment", proposal.as_ref(), &index.to_le_bytes()], &governance::ID).0
+}
+
+fn add_comment(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    author: &TestMember,
+    text: &str,
+) -> std::result::Result<(), TxError> {
+    let state = dao::proposal(rt, &proposal);
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::AddComment {
+            dao: dao.key,
+            registry: dao.registry.key,
+            proposal,
+            member: author.account,
+            comment: comment_address(&proposal, state.comment_count),
+            author: author.key,
+            system_program: anchor_lang::system_program::ID,
+        },
+        governance::instruction::AddComment {
+            proposal_id: state.id,
+            text: text.to_string(),
+        },
+    );
+    rt.send(ix, &[author.key])
+}
+
+#[test]
+fn comments_read_back_in_posting_order() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let alice = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let bob = add_member(&mut rt, &dao.registry, MemberType::AI, 10);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let thread = [(&alice, "I support this."), (&bob, "Costs look high."), (&alice, "They are one-off.")];
+
+    for (author, text) in thread {
+        add_comment(&mut rt, &dao, proposal, author, text).unwrap();
+        rt.advance_time(60);
+    }
+
+    assert_eq!(dao::proposal(&rt, &proposal).comment_count, 3);
+    for (index, (author, text)) in thread.iter().enumerate() {
+        let comment: Comment = rt.get(&comment_address(&proposal, index as u64));
+        assert_eq!(comment.proposal, proposal);
+        assert_eq!(comment.index, index as u64);
+        assert_eq!(comment.author, author.key);
+        assert_eq!(comment.text, *text);
+        assert_eq!(comment.posted_at, 1_700_000_000 + 60 * index as i64);
+    }
+}
+
+#[test]
+fn overlong_comment_is_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        add_comment(&mut rt, &dao, proposal, &member, &"x".repeat(MAX_COMMENT_LEN + 1)),
+        ErrorCode::CommentTooLong,
+    );
+    add_comment(&mut rt, &dao, proposal, &member, &"x".repeat(MAX_COMMENT_LEN)).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).comment_count, 1);
+}
@@ -0,0 +1,203 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{vote_commitment, ConfigField, ErrorCode, ExecutionPayload, ProposalStatus};
+use membership::MemberType;
+
+const REVEAL_SECS: i64 = 3_600;
+
+fn commitment_address(proposal: &Pubkey, member: &TestMember) -> Pubkey {
+    Pubkey::find_program_address(&[b"commit", proposal.as_ref(), member.account.as_ref()], &governance::ID).0
+}
+
+fn commit_vote(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    support: bool,
+    salt: [u8; 32],
+) {
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::CommitVote {
+            dao: dao.key,
+            registry: dao.registry.key,
+            proposal,
+            member: member.account,
+            commitment: commitment_address(&proposal, member),
+            voter: member.key,
+            system_program: anchor_lang::system_program::ID,
+        },
+        governance::instruction::CommitVote {
+            proposal_id: dao::proposal(rt, &proposal).id,
+            commitment: vote_commitment(support, &salt),
+        },
+    );
+    rt.send(ix, &[member.key]).unwrap();
+}
+
+/// Opens the reveal window as an arbitrary keeper rather than the authority.
+fn begin_reveal_phase(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> std::result::Result<(), TxError> {
+    let keeper = rt.wallet(SOL);
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::BeginRevealPhase { dao: dao.key, proposal },
+        governance::instruction::BeginRevealPhase {
+            proposal_id: dao::proposal(rt, &proposal).id,
+        },
+    );
+    rt.send(ix, &[keeper])
+}
+
+fn reveal_vote(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    support: bool,
+    salt: [u8; 32],
+) -> std::result::Result<(), TxError> {
+    let mut accounts = vote_accounts(dao, proposal, member);
+    accounts.commitment = Some(commitment_address(&proposal, member));
+    let ix = instruction(
+        governance::ID,
+        accounts,
+        governance::instruction::RevealVote {
+            proposal_id: dao::proposal(rt, &proposal).id,
+            support,
+            salt,
+        },
+    );
+    rt.send(ix, &[member.key])
+}
+
+#[test]
+fn only_revealed_votes_are_tallied() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.reveal_secs = REVEAL_SECS);
+    let backer = add_member(&mut rt, &dao.registry, MemberType::Human, 60);
+    let critic = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let silent = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(vote(&mut rt, &dao, proposal, &backer, true), ErrorCode::CommitRevealRequired);
+    commit_vote(&mut rt, &dao, proposal, &backer, true, [1; 32]);
+    commit_vote(&mut rt, &dao, proposal, &critic, false, [2; 32]);
+    commit_vote(&mut rt, &dao, proposal, &silent, true, [3; 32]);
+    assert_error(
+        reveal_vote(&mut rt, &dao, proposal, &backer, true, [1; 32]),
+        ErrorCode::RevealNotOpen,
+    );
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    begin_reveal_phase(&mut rt, &dao, proposal).unwrap();
+    reveal_vote(&mut rt, &dao, proposal, &backer, true, [1; 32]).unwrap();
+    reveal_vote(&mut rt, &dao, proposal, &critic, false, [2; 32]).unwrap();
+
+    rt.advance_time(REVEAL_SECS);
+    assert_error(
+        reveal_vote(&mut rt, &dao, proposal, &silent, true, [3; 32]),
+        ErrorCode::RevealNotOpen,
+    );
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert_eq!(state.votes_for, 60);
+    assert_eq!(state.votes_against, 30);
+    assert!(state.status == ProposalStatus::Passed);
+}
+
+#[test]
+fn reveal_must_match_the_commitment() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.reveal_secs = REVEAL_SECS);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    commit_vote(&mut rt, &dao, proposal, &member, false, [7; 32]);
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    begin_reveal_phase(&mut rt, &dao, proposal).unwrap();
+
+    assert_error(
+        reveal_vote(&mut rt, &dao, proposal, &member, true, [7; 32]),
+        ErrorCode::InvalidReveal,
+    );
+    assert_error(
+        reveal_vote(&mut rt, &dao, proposal, &member, false, [8; 32]),
+        ErrorCode::InvalidReveal,
+    );
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 0);
+
+    reveal_vote(&mut rt, &dao, proposal, &member, false, [7; 32]).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).votes_against, 100);
+}
+
+#[test]
+fn keeper_opens_the_reveal_window_once_voting_ends() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.reveal_secs = REVEAL_SECS);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let voting_ends_at = dao::proposal(&rt, &proposal).voting_ends_at;
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD - 1);
+    assert_error(begin_reveal_phase(&mut rt, &dao, proposal), ErrorCode::VotingNotEnded);
+    assert_eq!(dao::proposal(&rt, &proposal).reveal_ends_at, 0);
+
+    rt.advance_time(1);
+    assert_eq!(rt.clock().unix_timestamp, voting_ends_at);
+    begin_reveal_phase(&mut rt, &dao, proposal).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).reveal_ends_at, voting_ends_at + REVEAL_SECS);
+
+    rt.advance_time(10);
+    assert_error(begin_reveal_phase(&mut rt, &dao, proposal), ErrorCode::RevealPhaseStarted);
+    assert_eq!(dao::proposal(&rt, &proposal).reveal_ends_at, voting_ends_at + REVEAL_SECS);
+}
+
+#[test]
+fn open_voting_proposal_has_no_reveal_window() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+
+    assert_error(begin_reveal_phase(&mut rt, &dao, proposal), ErrorCode::NotCommitReveal);
+}
+
+#[test]
+fn reveal_window_cannot_be_set_past_the_cap() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let set_reveal = |value| ExecutionPayload::SetConfig {
+        field: ConfigField::RevealSecs,
+        value,
+    };
+
+    assert_error(
+        try_create_proposal(
+            &mut rt,
+            &dao,
+            dao.authority,
+            proposal_args(set_reveal(governance::MAX_REVEAL_SECS as u64 + 1)),
+        ),
+        ErrorCode::InvalidConfigValue,
+    );
+    create_proposal(&mut rt, &dao, set_reveal(governance::MAX_REVEAL_SECS as u64));
+}
+
+#[test]
+fn reveal_window_past_the_largest_timestamp_is_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.reveal_secs = i64::MAX);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+
+    assert_error(begin_reveal_phase(&mut rt, &dao, proposal), ErrorCode::RevealWindowOverflow);
+    assert_eq!(dao::proposal(&rt, &proposal).reveal_ends_at, 0);
+}
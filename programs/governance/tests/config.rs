@@ -0,0 +1,44 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use membership::RegistryField;
+
+fn get_config(rt: &mut Runtime, dao: &TestDao) -> governance::Config {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::GetConfig {
+                dao: dao.key,
+                registry: dao.registry.key,
+            },
+            governance::instruction::GetConfig {},
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+#[test]
+fn get_config_reports_registry_and_dao_settings() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::TreasuryMinBalance, 5_000);
+    update_config(&mut rt, &dao.registry, RegistryField::DuesAmount, 1_000);
+    update_config(&mut rt, &dao.registry, RegistryField::DecayGraceSlots, 300);
+    update_config(&mut rt, &dao.registry, RegistryField::AiPowerMultiplierBps, 2_500);
+    update_config(&mut rt, &dao.registry, RegistryField::NormalizedTotal, 1_000_000);
+    set_dao_field(&mut rt, &dao, |state| state.max_execution_attempts = 7);
+
+    let config = get_config(&mut rt, &dao);
+    assert_eq!(config.treasury_min_balance, 5_000);
+    assert_eq!(config.dues_amount, 1_000);
+    assert_eq!(config.decay.grace_slots, 300);
+    assert_eq!(config.ai_power_multiplier_bps, 2_500);
+    assert_eq!(config.normalized_total, Some(1_000_000));
+    assert_eq!(config.max_execution_attempts, 7);
+}
@@ -0,0 +1,67 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload};
+use membership::MemberType;
+
+fn cosponsor(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+) -> std::result::Result<(), TxError> {
+    let proposal_id = dao::proposal(rt, &proposal).id;
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::CosponsorProposal {
+                dao: dao.key,
+                registry: dao.registry.key,
+                proposal,
+                member: member.account,
+                cosponsor: member.key,
+            },
+            governance::instruction::CosponsorProposal { proposal_id },
+        ),
+        &[member.key],
+    )
+}
+
+#[test]
+fn votes_wait_for_the_cosponsor_minimum() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let second = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    set_dao_field(&mut rt, &dao, |state| state.min_cosponsors = 2);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    cosponsor(&mut rt, &dao, proposal, &first).unwrap();
+    assert_error(
+        vote(&mut rt, &dao, proposal, &voter, true),
+        ErrorCode::InsufficientCosponsors,
+    );
+    assert_error(
+        cosponsor(&mut rt, &dao, proposal, &first),
+        ErrorCode::AlreadyCosponsored,
+    );
+
+    cosponsor(&mut rt, &dao, proposal, &second).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).cosponsors, vec![first.key, second.key]);
+    vote(&mut rt, &dao, proposal, &voter, true).unwrap();
+}
+
+#[test]
+fn votes_need_no_cosponsors_by_default() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &voter, true).unwrap();
+}
@@ -0,0 +1,376 @@
+//! Governance fixtures on top of the shared runtime: a DAO linked to a
+//! registry it governs, and helpers for the proposal lifecycle.
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use governance::{ExecutionPayload, VoterEligibility};
+use membership::SpendingCategory;
+
+use crate::common::*;
+
+/// A DAO created by `create_dao`, governing `registry`.
+#[derive(Clone, Copy)]
+pub struct TestDao {
+    pub key: Pubkey,
+    pub authority: Pubkey,
+    pub registry: Registry,
+    pub treasury: Pubkey,
+    pub governance_signer: Pubkey,
+}
+
+impl TestDao {
+    pub fn dao(&self, rt: &Runtime) -> governance::Dao {
+        rt.get(&self.key)
+    }
+}
+
+/// A runtime running both programs.
+pub fn runtime() -> Runtime {
+    let mut rt = Runtime::new();
+    rt.add_program(governance::ID, governance::entry);
+    rt
+}
+
+pub fn governance_signer(dao: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"governance", dao.as_ref()], &governance::ID).0
+}
+
+pub fn treasury(dao: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"treasury", dao.as_ref()], &governance::ID).0
+}
+
+pub fn vote_record(proposal: &Pubkey, member: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vote", proposal.as_ref(), member.as_ref()], &governance::ID).0
+}
+
+/// Creates a registry and a DAO sharing its authority, links them, and
+/// records the DAO's governance PDA on the registry.
+pub fn create_dao(rt: &mut Runtime) -> TestDao {
+    let registry = create_registry(rt);
+    let key = Pubkey::new_unique();
+    let authority = registry.authority;
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::Initialize {
+                dao: key,
+                authority,
+                system_program: system_program::ID,
+            },
+            governance::instruction::Initialize {
+                legal_name: "MicroAI DAO LLC".to_string(),
+                registered_agent_address: "30 N Gould St, Sheridan WY".to_string(),
+                principal_place_of_business: "Cheyenne WY".to_string(),
+                legal_entity_id: "2024-001234567".to_string(),
+                jurisdiction: "Wyoming".to_string(),
+            },
+        ),
+        &[key, authority],
+    )
+    .unwrap();
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::LinkRegistry {
+                dao: key,
+                registry: registry.key,
+                authority,
+            },
+            governance::instruction::LinkRegistry {},
+        ),
+        &[authority],
+    )
+    .unwrap();
+
+    let governance_signer = governance_signer(&key);
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetGovernance {
+                registry: registry.key,
+                audit_log: registry.audit_log,
+                authority,
+            },
+            membership::instruction::SetGovernance {
+                governance: governance_signer,
+            },
+        ),
+        &[authority],
+    )
+    .unwrap();
+
+    let treasury = treasury(&key);
+    rt.airdrop(&treasury, SOL);
+    TestDao {
+        key,
+        authority,
+        registry,
+        treasury,
+        governance_signer,
+    }
+}
+
+/// Sets a DAO config field directly, as a passed proposal would.
+pub fn set_dao_field(rt: &mut Runtime, dao: &TestDao, edit: impl FnOnce(&mut governance::Dao)) {
+    rt.update(&dao.key, edit);
+}
+
+pub fn proposal_args(payload: ExecutionPayload) -> governance::instruction::CreateProposal {
+    governance::instruction::CreateProposal {
+        title: "Proposal".to_string(),
+        description: "Test proposal".to_string(),
+        amount: 0,
+        payload,
+        quorum_bps_override: None,
+        category: SpendingCategory::Operational,
+        eligibility: VoterEligibility::All,
+    }
+}
+
+pub fn create_proposal_ix(
+    dao: &TestDao,
+    proposer: Pubkey,
+    args: governance::instruction::CreateProposal,
+) -> (Instruction, Pubkey) {
+    let proposal = Pubkey::new_unique();
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::CreateProposal {
+            dao: dao.key,
+            proposal,
+            proposer,
+            system_program: system_program::ID,
+            registry: Some(dao.registry.key),
+        },
+        args,
+    );
+    (ix, proposal)
+}
+
+pub fn try_create_proposal(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposer: Pubkey,
+    args: governance::instruction::CreateProposal,
+) -> std::result::Result<Pubkey, TxError> {
+    let (ix, proposal) = create_proposal_ix(dao, proposer, args);
+    rt.send(ix, &[proposal, proposer])?;
+    Ok(proposal)
+}
+
+/// Opens a proposal with `payload`, proposed by the DAO authority.
+pub fn create_proposal(rt: &mut Runtime, dao: &TestDao, payload: ExecutionPayload) -> Pubkey {
+    try_create_proposal(rt, dao, dao.authority, proposal_args(payload)).unwrap()
+}
+
+pub fn proposal(rt: &Runtime, proposal: &Pubkey) -> governance::Proposal {
+    rt.get(proposal)
+}
+
+pub fn vote_accounts(dao: &TestDao, proposal: Pubkey, member: &TestMember) -> governance::accounts::Vote {
+    governance::accounts::Vote {
+        dao: dao.key,
+        registry: dao.registry.key,
+        proposal,
+        member: member.account,
+        vote_record: vote_record(&proposal, &member.account),
+        voter: member.key,
+        governance_signer: dao.governance_signer,
+        membership_program: membership::ID,
+        system_program: system_program::ID,
+        sponsor: None,
+        epoch_snapshot: None,
+        commitment: None,
+        instructions: None,
+    }
+}
+
+pub fn vote_ix(dao: &TestDao, proposal: Pubkey, member: &TestMember, support: bool) -> Instruction {
+    instruction(
+        governance::ID,
+        vote_accounts(dao, proposal, member),
+        governance::instruction::Vote {
+            support,
+            rationale: String::new(),
+        },
+    )
+}
+
+pub fn vote(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    support: bool,
+) -> std::result::Result<(), TxError> {
+    rt.send(vote_ix(dao, proposal, member, support), &[member.key])
+}
+
+pub fn close_proposal_ix(dao: &TestDao, proposal: Pubkey) -> Instruction {
+    instruction(
+        governance::ID,
+        governance::accounts::CloseProposal {
+            dao: dao.key,
+            registry: dao.registry.key,
+            proposal,
+            closer: dao.authority,
+        },
+        governance::instruction::CloseProposal {},
+    )
+}
+
+/// Closes a proposal as the DAO authority.
+pub fn close_proposal(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> std::result::Result<(), TxError> {
+    rt.send(close_proposal_ix(dao, proposal), &[dao.authority])
+}
+
+/// Closes whichever of `proposals` have expired, as `keeper`.
+pub fn close_expired(rt: &mut Runtime, dao: &TestDao, keeper: Pubkey, proposals: &[Pubkey]) {
+    let mut ix = instruction(
+        governance::ID,
+        governance::accounts::CloseExpiredProposals {
+            dao: dao.key,
+            registry: dao.registry.key,
+            treasury: dao.treasury,
+            keeper,
+            system_program: system_program::ID,
+        },
+        governance::instruction::CloseExpiredProposals {},
+    );
+    ix.accounts
+        .extend(proposals.iter().map(|proposal| AccountMeta::new(*proposal, false)));
+    rt.send(ix, &[keeper]).unwrap();
+}
+
+pub fn execute_accounts(
+    rt: &Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    destination: Option<Pubkey>,
+) -> governance::accounts::ExecuteProposal {
+    governance::accounts::ExecuteProposal {
+        dao: dao.key,
+        proposal,
+        registry: dao.registry.key,
+        audit_log: current_audit_log(rt, &dao.registry),
+        governance_signer: dao.governance_signer,
+        membership_program: membership::ID,
+        treasury: dao.treasury,
+        destination,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn execute_proposal(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    destination: Option<Pubkey>,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        governance::ID,
+        execute_accounts(rt, dao, proposal, destination),
+        governance::instruction::ExecuteProposal {},
+    );
+    let payer = rt.wallet(SOL);
+    rt.send(ix, &[payer])
+}
+
+/// Votes `member` for `payload`, then closes the proposal once voting
+/// ends and waits out the timelock.
+pub fn pass_proposal(rt: &mut Runtime, dao: &TestDao, member: &TestMember, payload: ExecutionPayload) -> Pubkey {
+    let proposal = create_proposal(rt, dao, payload);
+    vote(rt, dao, proposal, member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(rt, dao, proposal).unwrap();
+    rt.advance_time(governance::DEFAULT_TIMELOCK);
+    proposal
+}
+
+pub fn create_multi_choice_ix(
+    dao: &TestDao,
+    proposer: Pubkey,
+    options: &[&str],
+    eligibility: VoterEligibility,
+) -> (Instruction, Pubkey) {
+    let proposal = Pubkey::new_unique();
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::CreateMultiChoiceProposal {
+            dao: dao.key,
+            proposal,
+            proposer,
+            system_program: system_program::ID,
+            registry: Some(dao.registry.key),
+        },
+        governance::instruction::CreateMultiChoiceProposal {
+            title: "Choice".to_string(),
+            description: "Test multi-choice proposal".to_string(),
+            options: options.iter().map(|option| option.to_string()).collect(),
+            eligibility,
+        },
+    );
+    (ix, proposal)
+}
+
+/// Opens a multi-choice proposal open to every member, proposed by the
+/// DAO authority.
+pub fn create_multi_choice(rt: &mut Runtime, dao: &TestDao, options: &[&str]) -> Pubkey {
+    let (ix, proposal) = create_multi_choice_ix(dao, dao.authority, options, VoterEligibility::All);
+    rt.send(ix, &[proposal, dao.authority]).unwrap();
+    proposal
+}
+
+pub fn multi_choice(rt: &Runtime, proposal: &Pubkey) -> governance::MultiChoiceProposal {
+    rt.get(proposal)
+}
+
+pub fn cast_multi_vote(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    option_index: u8,
+) -> std::result::Result<(), TxError> {
+    let proposal_id = multi_choice(rt, &proposal).id;
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::CastMultiVote {
+            dao: dao.key,
+            registry: dao.registry.key,
+            proposal,
+            member: member.account,
+            vote_record: vote_record(&proposal, &member.account),
+            voter: member.key,
+            governance_signer: dao.governance_signer,
+            membership_program: membership::ID,
+            system_program: system_program::ID,
+            sponsor: None,
+            epoch_snapshot: None,
+        },
+        governance::instruction::CastMultiVote {
+            proposal_id,
+            option_index,
+        },
+    );
+    rt.send(ix, &[member.key])
+}
+
+/// Closes a multi-choice proposal as the DAO authority.
+pub fn close_multi_choice(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> std::result::Result<(), TxError> {
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::CloseMultiChoiceProposal {
+                dao: dao.key,
+                registry: dao.registry.key,
+                proposal,
+                closer: dao.authority,
+            },
+            governance::instruction::CloseMultiChoiceProposal {},
+        ),
+        &[dao.authority],
+    )
+}
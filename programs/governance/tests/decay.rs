@@ -0,0 +1,52 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, VoteRecord};
+use membership::{MemberType, RegistryField};
+
+fn enable_decay(rt: &mut Runtime, dao: &TestDao) {
+    update_config(rt, &dao.registry, RegistryField::DecayGraceSlots, 100);
+    update_config(rt, &dao.registry, RegistryField::DecayPeriodSlots, 100);
+    update_config(rt, &dao.registry, RegistryField::DecayBpsPerPeriod, 1_000);
+}
+
+#[test]
+fn recently_active_member_votes_with_full_power() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    enable_decay(&mut rt, &dao);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    rt.advance_slots(100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &voter, true).unwrap();
+
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &voter.account));
+    assert_eq!(record.weight, 100);
+    assert_eq!(member(&rt, &voter).last_active_slot, rt.clock().slot);
+}
+
+#[test]
+fn long_inactive_member_votes_with_decayed_power() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    enable_decay(&mut rt, &dao);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    // 350 slots past the grace period: three full periods at 10% each.
+    rt.advance_slots(450);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &voter, true).unwrap();
+
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &voter.account));
+    assert_eq!(record.weight, 70);
+    assert_eq!(member(&rt, &voter).voting_power, 100);
+
+    // Voting counts as activity, so the next proposal sees full power again.
+    let next = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, next, &voter, true).unwrap();
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&next, &voter.account)).weight, 100);
+}
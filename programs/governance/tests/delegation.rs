@@ -0,0 +1,100 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, VoteRecord};
+use membership::{MemberType, MembershipError, RegistryField};
+
+/// Votes for `proposal` as `voter`, passing `delegators` as the members
+/// whose power it carries.
+fn vote_with(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    voter: &TestMember,
+    delegators: &[TestMember],
+) -> std::result::Result<(), TxError> {
+    let mut ix = vote_ix(dao, proposal, voter, true);
+    ix.accounts
+        .extend(delegators.iter().map(|m| AccountMeta::new_readonly(m.account, false)));
+    rt.send(ix, &[voter.key])
+}
+
+#[test]
+fn two_hop_chain_is_counted_within_max_depth() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::MaxDelegationDepth, 2);
+    let a = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let b = add_member(&mut rt, &dao.registry, MemberType::Human, 20);
+    let c = add_member(&mut rt, &dao.registry, MemberType::Human, 3);
+    set_delegate(&mut rt, &dao.registry, &b, &a);
+    set_delegate(&mut rt, &dao.registry, &c, &b);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote_with(&mut rt, &dao, proposal, &a, &[b, c]).unwrap();
+
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &a.account));
+    assert_eq!(record.weight, 123);
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 123);
+}
+
+#[test]
+fn flat_delegation_is_the_default() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let a = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let b = add_member(&mut rt, &dao.registry, MemberType::Human, 20);
+    let c = add_member(&mut rt, &dao.registry, MemberType::Human, 3);
+    set_delegate(&mut rt, &dao.registry, &b, &a);
+    set_delegate(&mut rt, &dao.registry, &c, &b);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        vote_with(&mut rt, &dao, proposal, &a, &[b, c]),
+        MembershipError::DelegationTooDeep,
+    );
+    vote_with(&mut rt, &dao, proposal, &a, &[b]).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 120);
+}
+
+#[test]
+fn chain_longer_than_max_depth_is_rejected() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::MaxDelegationDepth, 2);
+    let a = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let chain: Vec<_> = (0..3)
+        .map(|_| add_member(&mut rt, &dao.registry, MemberType::Human, 10))
+        .collect();
+    set_delegate(&mut rt, &dao.registry, &chain[0], &a);
+    set_delegate(&mut rt, &dao.registry, &chain[1], &chain[0]);
+    set_delegate(&mut rt, &dao.registry, &chain[2], &chain[1]);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        vote_with(&mut rt, &dao, proposal, &a, &chain),
+        MembershipError::DelegationTooDeep,
+    );
+}
+
+#[test]
+fn delegation_cycle_is_rejected() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::MaxDelegationDepth, 4);
+    let a = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let b = add_member(&mut rt, &dao.registry, MemberType::Human, 20);
+    let c = add_member(&mut rt, &dao.registry, MemberType::Human, 3);
+    set_delegate(&mut rt, &dao.registry, &b, &c);
+    set_delegate(&mut rt, &dao.registry, &c, &b);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        vote_with(&mut rt, &dao, proposal, &a, &[b, c]),
+        MembershipError::DelegationCycle,
+    );
+}
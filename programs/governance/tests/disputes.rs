@@ -0,0 +1,47 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload};
+use membership::{MemberRegistry, MemberType};
+
+#[test]
+fn disputed_member_votes_again_once_the_dispute_is_dismissed() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    flag_dispute(&mut rt, &dao.registry, &member);
+    assert!(common::member(&rt, &member).under_dispute);
+    assert_error(
+        vote(&mut rt, &dao, proposal, &member, true),
+        ErrorCode::MemberUnderDispute,
+    );
+
+    resolve_dispute(&mut rt, &dao.registry, &member, false);
+    let state = common::member(&rt, &member);
+    assert!(!state.under_dispute && state.is_active);
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+}
+
+#[test]
+fn upheld_dispute_suspends_the_member() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 50);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    flag_dispute(&mut rt, &dao.registry, &member);
+    resolve_dispute(&mut rt, &dao.registry, &member, true);
+
+    let state = common::member(&rt, &member);
+    assert!(!state.under_dispute && !state.is_active);
+    let registry: MemberRegistry = rt.get(&dao.registry.key);
+    assert_eq!(registry.total_voting_power, 50);
+    assert_eq!(registry.active_member_count, 1);
+    assert_error(vote(&mut rt, &dao, proposal, &member, true), ErrorCode::InvalidMember);
+}
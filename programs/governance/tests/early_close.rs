@@ -0,0 +1,60 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, ProposalClosed, ProposalStatus};
+use membership::MemberType;
+
+#[test]
+fn decisive_vote_closes_the_proposal_early() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.early_close = true);
+    let majority = add_member(&mut rt, &dao.registry, MemberType::Human, 200);
+    let minority = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &majority, true).unwrap();
+
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert_eq!(state.closed_at, rt.clock().unix_timestamp);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 0);
+    let closed = rt.events::<ProposalClosed>();
+    assert_eq!(closed.len(), 1);
+    assert_eq!(closed[0].proposal_id, state.id);
+    assert!(closed[0].status == ProposalStatus::Passed);
+    assert_error(vote(&mut rt, &dao, proposal, &minority, false), ErrorCode::ProposalNotActive);
+}
+
+#[test]
+fn vote_that_could_still_be_overturned_leaves_the_proposal_open() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.early_close = true);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &first, true).unwrap();
+
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Active);
+    assert!(rt.events::<ProposalClosed>().is_empty());
+}
+
+#[test]
+fn decisive_vote_leaves_the_proposal_open_without_early_close() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let majority = add_member(&mut rt, &dao.registry, MemberType::Human, 200);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &majority, true).unwrap();
+
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Active);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 1);
+}
@@ -0,0 +1,62 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalStatus, VoterEligibility};
+use membership::{MemberType, MembershipError};
+
+fn create_restricted(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    eligibility: VoterEligibility,
+    listed: &[&TestMember],
+) -> Pubkey {
+    let mut args = proposal_args(ExecutionPayload::None);
+    args.eligibility = eligibility;
+    let (mut ix, proposal) = create_proposal_ix(dao, dao.authority, args);
+    ix.accounts
+        .extend(listed.iter().map(|member| AccountMeta::new_readonly(member.account, false)));
+    rt.send(ix, &[proposal, dao.authority]).unwrap();
+    proposal
+}
+
+#[test]
+fn allowlisted_voter_decides_against_the_allowlists_power() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.quorum_bps = 5_000);
+    let founder = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let outsider = add_member(&mut rt, &dao.registry, MemberType::Human, 70);
+    let allowlist = VoterEligibility::Allowlist(vec![founder.key]);
+    let proposal = create_restricted(&mut rt, &dao, allowlist, &[&founder]);
+    assert_eq!(dao::proposal(&rt, &proposal).eligible_power, 30);
+
+    assert_error(
+        vote(&mut rt, &dao, proposal, &outsider, false),
+        MembershipError::NotEligibleForProposal,
+    );
+    vote(&mut rt, &dao, proposal, &founder, true).unwrap();
+
+    // 30 of the whole DAO's 100 would miss a 50% quorum; 30 of 30 meets it.
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.quorum_met);
+    assert!(state.status == ProposalStatus::Passed);
+}
+
+#[test]
+fn member_type_restriction_admits_only_that_type() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let human = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let ai = add_member(&mut rt, &dao.registry, MemberType::AI, 10);
+    let proposal = create_restricted(&mut rt, &dao, VoterEligibility::MemberType(MemberType::AI), &[]);
+
+    assert_error(vote(&mut rt, &dao, proposal, &human, true), MembershipError::NotEligibleForProposal);
+    vote(&mut rt, &dao, proposal, &ai, true).unwrap();
+}
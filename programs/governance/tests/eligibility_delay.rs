@@ -0,0 +1,38 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::ExecutionPayload;
+use membership::{MemberType, MembershipError, RegistryField};
+
+#[test]
+fn member_votes_only_on_proposals_created_after_the_delay() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::VotingEligibilityDelaySecs, 1_000);
+    let veteran = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    rt.advance_time(1_000);
+    let newcomer = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &veteran, true).unwrap();
+    assert_error(vote(&mut rt, &dao, proposal, &newcomer, true), MembershipError::NotYetEligible);
+
+    // Waiting does not help on the old proposal, only on newer ones.
+    rt.advance_time(1_000);
+    assert_error(vote(&mut rt, &dao, proposal, &newcomer, true), MembershipError::NotYetEligible);
+    let later = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, later, &newcomer, true).unwrap();
+}
+
+#[test]
+fn zero_delay_lets_new_members_vote_at_once() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let newcomer = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+
+    vote(&mut rt, &dao, proposal, &newcomer, true).unwrap();
+}
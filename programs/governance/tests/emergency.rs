@@ -0,0 +1,75 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{EmergencyExecuted, ErrorCode, ExecutionPayload, ProposalStatus};
+use membership::MemberType;
+
+fn emergency_execute(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    signers: &[Pubkey],
+) -> std::result::Result<(), TxError> {
+    let mut ix = instruction(
+        governance::ID,
+        execute_accounts(rt, dao, proposal, None),
+        governance::instruction::EmergencyExecute {},
+    );
+    ix.accounts
+        .extend(signers.iter().map(|key| AccountMeta::new_readonly(*key, true)));
+    rt.send(ix, signers)
+}
+
+#[test]
+fn emergency_execute_needs_the_multisig_threshold() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::SetMultisig {
+                dao: dao.key,
+                authority: dao.authority,
+            },
+            governance::instruction::SetMultisig {
+                signers: signers.clone(),
+                threshold: 2,
+            },
+        ),
+        &[dao.authority],
+    )
+    .unwrap();
+
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, None),
+        ErrorCode::TimelockActive,
+    );
+
+    assert_error(
+        emergency_execute(&mut rt, &dao, proposal, &signers[..1]),
+        ErrorCode::MultisigThresholdNotMet,
+    );
+    let outsiders = [signers[0], Pubkey::new_unique()];
+    assert_error(
+        emergency_execute(&mut rt, &dao, proposal, &outsiders),
+        ErrorCode::MultisigThresholdNotMet,
+    );
+
+    emergency_execute(&mut rt, &dao, proposal, &signers[1..]).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Executed);
+    assert!(state.emergency);
+    let events = rt.events::<EmergencyExecuted>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].signers, signers[1..]);
+}
@@ -0,0 +1,116 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, VoteRecord};
+use membership::MemberType;
+
+fn advance_epoch(rt: &mut Runtime, dao: &TestDao, members: &[&TestMember]) -> Pubkey {
+    let snapshot = Pubkey::new_unique();
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::SnapshotMembers {
+            registry: dao.registry.key,
+            audit_log: current_audit_log(rt, &dao.registry),
+            snapshot,
+            authority: dao.registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::SnapshotMembers {},
+    );
+    ix.accounts
+        .extend(members.iter().map(|m| AccountMeta::new_readonly(m.account, false)));
+    rt.send(ix, &[snapshot, dao.registry.authority]).unwrap();
+
+    let ix = instruction(
+        governance::ID,
+        governance::accounts::AdvanceEpoch {
+            dao: dao.key,
+            registry: dao.registry.key,
+            snapshot,
+            authority: dao.authority,
+        },
+        governance::instruction::AdvanceEpoch {},
+    );
+    rt.send(ix, &[dao.authority]).unwrap();
+    snapshot
+}
+
+fn vote_in_epoch(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    snapshot: Pubkey,
+) -> std::result::Result<(), TxError> {
+    let mut accounts = vote_accounts(dao, proposal, member);
+    accounts.epoch_snapshot = Some(snapshot);
+    let ix = instruction(
+        governance::ID,
+        accounts,
+        governance::instruction::Vote {
+            support: true,
+            rationale: String::new(),
+        },
+    );
+    rt.send(ix, &[member.key])
+}
+
+fn adjust_power(rt: &mut Runtime, dao: &TestDao, member: &TestMember, delta: i64) {
+    let ix = adjust_voting_power_ix(rt, &dao.registry, member, None, delta);
+    rt.send(ix, &[dao.registry.authority]).unwrap();
+}
+
+#[test]
+fn proposal_keeps_its_creation_epochs_weights() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let whale = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let minnow = add_member(&mut rt, &dao.registry, MemberType::Human, 50);
+    let first = advance_epoch(&mut rt, &dao, &[&whale, &minnow]);
+    let first_root = dao.dao(&rt).epoch_root;
+    let early = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    adjust_power(&mut rt, &dao, &whale, 300);
+    let second = advance_epoch(&mut rt, &dao, &[&whale, &minnow]);
+    let late = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    let state = dao.dao(&rt);
+    assert_eq!(state.current_epoch, 2);
+    assert_eq!(state.epoch_snapshot, second);
+    assert_ne!(state.epoch_root, first_root);
+
+    let early_state = dao::proposal(&rt, &early);
+    assert_eq!(early_state.epoch, 1);
+    assert_eq!(early_state.epoch_total_power, 150);
+    assert_eq!(dao::proposal(&rt, &late).epoch_total_power, 450);
+
+    // The earlier proposal cannot be weighed against the newer epoch.
+    assert_error(
+        vote_in_epoch(&mut rt, &dao, early, &whale, second),
+        ErrorCode::EpochSnapshotMismatch,
+    );
+    vote_in_epoch(&mut rt, &dao, early, &whale, first).unwrap();
+    vote_in_epoch(&mut rt, &dao, late, &whale, second).unwrap();
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&early, &whale.account)).weight, 100);
+    assert_eq!(rt.get::<VoteRecord>(&vote_record(&late, &whale.account)).weight, 400);
+}
+
+#[test]
+fn member_added_after_the_epoch_cannot_vote_in_it() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let founder = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let snapshot = advance_epoch(&mut rt, &dao, &[&founder]);
+    let newcomer = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        vote_in_epoch(&mut rt, &dao, proposal, &newcomer, snapshot),
+        ErrorCode::NotInEpochSnapshot,
+    );
+}
@@ -0,0 +1,140 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{keccak, secp256k1_program, sysvar};
+use common::*;
+use dao::*;
+use governance::ExecutionPayload;
+use libsecp256k1::curve::Scalar;
+use libsecp256k1::{Message, PublicKey, SecretKey, Signature, ECMULT_GEN_CONTEXT};
+use membership::{MemberType, MembershipError};
+
+fn eth_address(key: &SecretKey) -> [u8; 20] {
+    let public_key = PublicKey::from_secret_key(key).serialize();
+    keccak::hash(&public_key[1..]).to_bytes()[12..].try_into().unwrap()
+}
+
+/// A Secp256k1 program instruction, at index 0 of its transaction, checking
+/// `key`'s signature over `message`.
+fn secp256k1_ix(key: &SecretKey, message: &[u8]) -> Instruction {
+    let digest = Message::parse(&keccak::hash(message).to_bytes());
+    // A fixed nonce keeps the signature deterministic; fine for tests only.
+    let mut nonce = Scalar::default();
+    let _ = nonce.set_b32(&[0x42; 32]);
+    let (r, s, recovery_id) = ECMULT_GEN_CONTEXT
+        .sign_raw(&(*key).into(), &digest.0, &nonce)
+        .unwrap();
+
+    // The count, one set of offsets, then the address, signature and message.
+    let (address_at, signature_at, message_at) = (12u16, 32u16, 97u16);
+    let mut data = vec![1];
+    data.extend_from_slice(&signature_at.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&address_at.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&message_at.to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&eth_address(key));
+    data.extend_from_slice(&Signature { r, s }.serialize());
+    data.push(recovery_id);
+    data.extend_from_slice(message);
+    Instruction {
+        program_id: secp256k1_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+fn set_eth_address(rt: &mut Runtime, registry: &Registry, member: &TestMember, eth_address: [u8; 20]) {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetEthAddress {
+            registry: registry.key,
+            member: member.account,
+            owner: member.key,
+        },
+        membership::instruction::SetEthAddress { eth_address },
+    );
+    rt.send(ix, &[member.key]).unwrap();
+}
+
+/// Votes for `member` from a relayer wallet, authorized by whatever
+/// instructions go before the vote.
+fn relay_vote(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    support: bool,
+    before: Vec<Instruction>,
+) -> std::result::Result<(), TxError> {
+    let relayer = rt.wallet(SOL);
+    let mut accounts = vote_accounts(dao, proposal, member);
+    accounts.voter = relayer;
+    accounts.instructions = Some(sysvar::instructions::ID);
+    let vote = instruction(
+        governance::ID,
+        accounts,
+        governance::instruction::Vote {
+            support,
+            rationale: String::new(),
+        },
+    );
+    let mut instructions = before;
+    instructions.push(vote);
+    rt.send_all(&instructions, &[relayer])
+}
+
+#[test]
+fn valid_ethereum_signature_casts_the_vote() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let key = SecretKey::parse(&[7; 32]).unwrap();
+    set_eth_address(&mut rt, &dao.registry, &member, eth_address(&key));
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    let message = governance::eth_vote_message(&proposal, &member.account, true);
+    relay_vote(&mut rt, &dao, proposal, &member, true, vec![secp256k1_ix(&key, &message)]).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 10);
+}
+
+#[test]
+fn invalid_ethereum_signatures_are_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let key = SecretKey::parse(&[7; 32]).unwrap();
+    let other_key = SecretKey::parse(&[9; 32]).unwrap();
+    set_eth_address(&mut rt, &dao.registry, &member, eth_address(&key));
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let message = governance::eth_vote_message(&proposal, &member.account, true);
+
+    // Signed by another key: the precompile passes, the address does not match.
+    assert_error(
+        relay_vote(&mut rt, &dao, proposal, &member, true, vec![secp256k1_ix(&other_key, &message)]),
+        MembershipError::InvalidEthSignature,
+    );
+    // Signed for the other choice.
+    assert_error(
+        relay_vote(&mut rt, &dao, proposal, &member, false, vec![secp256k1_ix(&key, &message)]),
+        MembershipError::InvalidEthSignature,
+    );
+    // No signature at all.
+    assert_error(
+        relay_vote(&mut rt, &dao, proposal, &member, true, vec![]),
+        MembershipError::InvalidEthSignature,
+    );
+    // A signature the precompile itself rejects.
+    let mut forged = secp256k1_ix(&key, &message);
+    forged.data[40] ^= 1;
+    assert!(matches!(
+        relay_vote(&mut rt, &dao, proposal, &member, true, vec![forged]),
+        Err(TxError::Runtime { index: 0, .. })
+    ));
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 0);
+}
@@ -0,0 +1,58 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalExecuted, TreasuryDeposit, TreasuryWithdraw};
+use membership::MemberType;
+
+fn deposit(rt: &mut Runtime, dao: &TestDao, amount: u64) -> u64 {
+    let depositor = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::Deposit {
+                dao: dao.key,
+                registry: dao.registry.key,
+                treasury: dao.treasury,
+                depositor,
+                system_program: anchor_lang::system_program::ID,
+            },
+            governance::instruction::Deposit { amount },
+        ),
+        &[depositor],
+    )
+    .unwrap();
+    rt.events::<TreasuryDeposit>()[0].sequence
+}
+
+#[test]
+fn dao_events_are_numbered_consecutively_across_instructions() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+
+    assert_eq!(deposit(&mut rt, &dao, SOL / 10), 0);
+    assert_eq!(deposit(&mut rt, &dao, SOL / 10), 1);
+
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: SOL / 4,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+    let next = dao.dao(&rt).event_seq;
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+
+    // One instruction emitting two events numbers them in turn.
+    let withdraw = rt.events::<TreasuryWithdraw>()[0].sequence;
+    let executed = rt.events::<ProposalExecuted>()[0].sequence;
+    let mut sequences = [withdraw, executed];
+    sequences.sort();
+    assert_eq!(sequences, [next, next + 1]);
+    assert_eq!(dao.dao(&rt).event_seq, next + 2);
+
+    assert_eq!(deposit(&mut rt, &dao, SOL / 10), next + 2);
+}
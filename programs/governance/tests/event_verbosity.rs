@@ -0,0 +1,28 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalClosed};
+use membership::{EventVerbosity, MemberType};
+
+fn closed_events_at(verbosity: EventVerbosity) -> usize {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.event_verbosity = verbosity);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    rt.events::<ProposalClosed>().len()
+}
+
+#[test]
+fn proposal_closed_is_emitted_only_at_full_verbosity() {
+    assert_eq!(closed_events_at(EventVerbosity::All), 1);
+    assert_eq!(closed_events_at(EventVerbosity::Errors), 0);
+    assert_eq!(closed_events_at(EventVerbosity::Off), 0);
+}
@@ -0,0 +1,207 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use std::cell::RefCell;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use common::*;
+use dao::*;
+use governance::{ConfigField, ErrorCode, ExecutionPayload, ProposalStatus};
+use membership::{AuditLog, InstructionKind, MemberRegistry, MemberType};
+
+thread_local! {
+    static REENTRY: RefCell<Option<Instruction>> = const { RefCell::new(None) };
+}
+
+/// Stands in for the membership program and, when a payload invokes it,
+/// tries to execute the proposal again.
+fn reentering_target(_: &Pubkey, accounts: &[AccountInfo], _: &[u8]) -> ProgramResult {
+    let ix = REENTRY.with(|reentry| reentry.borrow().clone()).unwrap();
+    invoke(&ix, accounts)
+}
+
+#[test]
+fn passed_set_config_proposal_updates_the_registry_as_governance() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let payload = ExecutionPayload::SetConfig {
+        field: ConfigField::MaxMembers,
+        value: 7,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+
+    execute_proposal(&mut rt, &dao, proposal, None).unwrap();
+
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Executed);
+    assert_eq!(rt.get::<MemberRegistry>(&dao.registry.key).max_members, 7);
+    let log: AuditLog = rt.get(&dao.registry.audit_log);
+    let entry = &log.entries[log.next_offset as usize - 1];
+    assert!(entry.kind == InstructionKind::UpdateConfig);
+    assert_eq!(entry.actor, dao.governance_signer);
+}
+
+#[test]
+fn set_config_waits_for_the_registry_to_accept_governance() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let payload = ExecutionPayload::SetConfig {
+        field: ConfigField::MaxMembers,
+        value: 7,
+    };
+    let proposal = create_proposal(&mut rt, &dao, payload);
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, None),
+        ErrorCode::TimelockActive,
+    );
+    rt.advance_time(governance::DEFAULT_TIMELOCK);
+
+    // Without the governance PDA on the registry the CPI cannot be signed,
+    // so the attempt is counted instead of failing the transaction.
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetGovernance {
+                registry: dao.registry.key,
+                audit_log: dao.registry.audit_log,
+                authority: dao.authority,
+            },
+            membership::instruction::SetGovernance {
+                governance: anchor_lang::prelude::Pubkey::default(),
+            },
+        ),
+        &[dao.authority],
+    )
+    .unwrap();
+    execute_proposal(&mut rt, &dao, proposal, None).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert_eq!(state.execution_attempts, 1);
+    assert_eq!(rt.get::<MemberRegistry>(&dao.registry.key).max_members, 0);
+}
+
+#[test]
+fn target_cannot_reenter_execute_proposal() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let payload = ExecutionPayload::SetConfig {
+        field: ConfigField::MaxMembers,
+        value: 7,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+    let reentry = instruction(
+        governance::ID,
+        execute_accounts(&rt, &dao, proposal, None),
+        governance::instruction::ExecuteProposal {},
+    );
+    REENTRY.with(|slot| *slot.borrow_mut() = Some(reentry));
+    rt.add_program(membership::ID, reentering_target);
+
+    match execute_proposal(&mut rt, &dao, proposal, None) {
+        Err(TxError::Runtime { reason, .. }) => assert!(reason.starts_with("reentrant call")),
+        other => panic!("expected the re-entry to be refused, got {other:?}"),
+    }
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert_eq!(state.executed_at_slot, 0);
+}
+
+#[test]
+fn proposal_executes_once_per_transaction() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: 1_000_000,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+    let execute = instruction(
+        governance::ID,
+        execute_accounts(&rt, &dao, proposal, Some(destination)),
+        governance::instruction::ExecuteProposal {},
+    );
+    let payer = rt.wallet(SOL);
+
+    let result = rt.send_all(&[execute.clone(), execute], &[payer]);
+    assert_eq!(
+        result,
+        Err(TxError::Instruction {
+            index: 1,
+            error: ProgramError::Custom(ErrorCode::ProposalNotPassed.into()),
+        })
+    );
+    assert_eq!(rt.lamports(&destination), 0);
+
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, Some(destination)),
+        ErrorCode::ProposalNotPassed,
+    );
+    assert_eq!(rt.lamports(&destination), 1_000_000);
+    assert_ne!(dao::proposal(&rt, &proposal).executed_at_slot, 0);
+}
+
+#[test]
+fn failed_transfer_is_retried_once_the_treasury_can_cover_it() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.max_execution_attempts = 3);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: 2 * SOL,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert_eq!(state.execution_attempts, 1);
+    assert_eq!(rt.lamports(&destination), 0);
+
+    rt.airdrop(&dao.treasury, 2 * SOL);
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Executed);
+    assert_eq!(state.execution_attempts, 1);
+    assert_eq!(rt.lamports(&destination), 2 * SOL);
+}
+
+#[test]
+fn execution_fails_for_good_after_the_last_attempt() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.max_execution_attempts = 2);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: 2 * SOL,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::ExecutionFailed);
+    assert_eq!(state.execution_attempts, 2);
+
+    rt.airdrop(&dao.treasury, 2 * SOL);
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, Some(destination)),
+        ErrorCode::ProposalNotPassed,
+    );
+    assert_eq!(rt.lamports(&destination), 0);
+}
@@ -0,0 +1,35 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::ExecutionPayload;
+use membership::{MemberRegistry, MemberType};
+
+#[test]
+fn member_who_leaves_can_no_longer_vote() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let leaver = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let delegator = add_member(&mut rt, &dao.registry, MemberType::Human, 20);
+    set_delegate(&mut rt, &dao.registry, &delegator, &leaver);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let rent = rt.lamports(&leaver.account);
+    let balance = rt.lamports(&leaver.key);
+
+    let ix = leave_membership_ix(&rt, &dao.registry, &leaver, None, &[&delegator]);
+    rt.send(ix, &[leaver.key]).unwrap();
+
+    assert_eq!(rt.lamports(&leaver.account), 0);
+    assert_eq!(rt.lamports(&leaver.key), balance + rent);
+    assert_eq!(member(&rt, &delegator).delegate, None);
+    let registry: MemberRegistry = rt.get(&dao.registry.key);
+    assert_eq!(registry.member_count, 1);
+    assert_eq!(registry.active_member_count, 1);
+    assert_eq!(registry.total_voting_power, 20);
+    assert_error(
+        vote(&mut rt, &dao, proposal, &leaver, true),
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+}
@@ -0,0 +1,82 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, LegalInfo, ProposalStatus, MAX_LEGAL_ENTITY_ID_LEN};
+use membership::MemberType;
+
+fn get_legal_info(rt: &mut Runtime, dao: &TestDao) -> LegalInfo {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::GetLegalInfo { dao: dao.key },
+            governance::instruction::GetLegalInfo {},
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+fn set_legal_info(legal_entity_id: &str, jurisdiction: &str) -> ExecutionPayload {
+    ExecutionPayload::SetLegalInfo {
+        legal_entity_id: legal_entity_id.to_string(),
+        jurisdiction: jurisdiction.to_string(),
+    }
+}
+
+#[test]
+fn legal_info_is_set_at_creation_and_changed_by_a_passed_proposal() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+
+    let info = get_legal_info(&mut rt, &dao);
+    assert_eq!(info.legal_entity_id, "2024-001234567");
+    assert_eq!(info.jurisdiction, "Wyoming");
+
+    let proposal = pass_proposal(&mut rt, &dao, &member, set_legal_info("2025-007654321", "Wyoming, USA"));
+    // Passing is not enough; the change lands only on execution.
+    assert_eq!(get_legal_info(&mut rt, &dao).legal_entity_id, "2024-001234567");
+    execute_proposal(&mut rt, &dao, proposal, None).unwrap();
+
+    let info = get_legal_info(&mut rt, &dao);
+    assert_eq!(info.legal_entity_id, "2025-007654321");
+    assert_eq!(info.jurisdiction, "Wyoming, USA");
+}
+
+#[test]
+fn rejected_proposal_leaves_legal_info_alone() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, set_legal_info("2025-007654321", "Delaware"));
+
+    vote(&mut rt, &dao, proposal, &member, false).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Rejected);
+    rt.advance_time(governance::DEFAULT_TIMELOCK);
+    assert!(execute_proposal(&mut rt, &dao, proposal, None).is_err());
+
+    assert_eq!(get_legal_info(&mut rt, &dao).jurisdiction, "Wyoming");
+}
+
+#[test]
+fn invalid_legal_info_cannot_be_proposed() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+
+    for payload in [
+        set_legal_info("2025-007654321", ""),
+        set_legal_info(&"9".repeat(MAX_LEGAL_ENTITY_ID_LEN + 1), "Wyoming"),
+    ] {
+        assert_error(
+            try_create_proposal(&mut rt, &dao, dao.authority, proposal_args(payload)),
+            ErrorCode::InvalidLegalInfo,
+        );
+    }
+}
@@ -0,0 +1,63 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use membership::{MembershipError, RegistryField};
+
+const MIN_DEPOSIT: u64 = SOL / 100;
+
+fn deposit(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    depositor: Pubkey,
+    amount: u64,
+) -> std::result::Result<(), TxError> {
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::Deposit {
+                dao: dao.key,
+                registry: dao.registry.key,
+                treasury: dao.treasury,
+                depositor,
+                system_program: anchor_lang::system_program::ID,
+            },
+            governance::instruction::Deposit { amount },
+        ),
+        &[depositor],
+    )
+}
+
+#[test]
+fn deposits_below_the_minimum_are_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::MinDeposit, MIN_DEPOSIT);
+    let depositor = rt.wallet(SOL);
+    let before = rt.lamports(&dao.treasury);
+
+    assert_error(deposit(&mut rt, &dao, depositor, 1), MembershipError::DepositTooSmall);
+    assert_error(
+        deposit(&mut rt, &dao, depositor, MIN_DEPOSIT - 1),
+        MembershipError::DepositTooSmall,
+    );
+    assert_eq!(rt.lamports(&dao.treasury), before);
+
+    deposit(&mut rt, &dao, depositor, MIN_DEPOSIT).unwrap();
+    deposit(&mut rt, &dao, depositor, MIN_DEPOSIT + 1).unwrap();
+    assert_eq!(rt.lamports(&dao.treasury), before + 2 * MIN_DEPOSIT + 1);
+}
+
+#[test]
+fn any_amount_is_accepted_without_a_minimum() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let depositor = rt.wallet(SOL);
+    let before = rt.lamports(&dao.treasury);
+
+    deposit(&mut rt, &dao, depositor, 1).unwrap();
+    assert_eq!(rt.lamports(&dao.treasury), before + 1);
+}
@@ -0,0 +1,160 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{BondState, ErrorCode, ProposalStatus, VoterEligibility};
+use membership::{MemberType, MembershipError};
+
+const OPTIONS: [&str; 3] = ["Alpha", "Beta", "Gamma"];
+
+/// Runs a three-option vote among members of power 50, 30 and 30, the
+/// last two voting for `second` and `third`, and returns the winner.
+fn winner(second: u8, third: u8) -> Option<u8> {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let large = add_member(&mut rt, &dao.registry, MemberType::Human, 50);
+    let small = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let other = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let proposal = create_multi_choice(&mut rt, &dao, &OPTIONS);
+
+    cast_multi_vote(&mut rt, &dao, proposal, &large, 0).unwrap();
+    cast_multi_vote(&mut rt, &dao, proposal, &small, second).unwrap();
+    cast_multi_vote(&mut rt, &dao, proposal, &other, third).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_multi_choice(&mut rt, &dao, proposal).unwrap();
+
+    let state = multi_choice(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 0);
+    state.winning_option
+}
+
+#[test]
+fn heaviest_option_wins() {
+    assert_eq!(winner(1, 2), Some(0));
+    assert_eq!(winner(1, 1), Some(1));
+}
+
+#[test]
+fn multi_choice_proposal_takes_and_refunds_the_bond() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    set_dao_field(&mut rt, &dao, |state| state.proposal_bond = SOL);
+    let before = rt.lamports(&dao.authority);
+    let proposal = create_multi_choice(&mut rt, &dao, &OPTIONS);
+    let state = multi_choice(&rt, &proposal);
+    assert_eq!(state.bond, SOL);
+    assert!(state.bond_state == BondState::Held);
+    let rent = rt.lamports(&proposal) - SOL;
+    assert_eq!(rt.lamports(&dao.authority), before - rent - SOL);
+
+    cast_multi_vote(&mut rt, &dao, proposal, &member, 2).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_multi_choice(&mut rt, &dao, proposal).unwrap();
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::SettleMultiChoiceBond {
+                dao: dao.key,
+                proposal,
+                proposer: dao.authority,
+                treasury: dao.treasury,
+            },
+            governance::instruction::RefundMultiChoiceBond {},
+        ),
+        &[dao.authority],
+    )
+    .unwrap();
+
+    assert!(multi_choice(&rt, &proposal).bond_state == BondState::Refunded);
+    assert_eq!(rt.lamports(&proposal), rent);
+    assert_eq!(rt.lamports(&dao.authority), before - rent);
+}
+
+#[test]
+fn multi_choice_votes_need_the_cosponsor_minimum() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let cosponsor = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    set_dao_field(&mut rt, &dao, |state| state.min_cosponsors = 1);
+    let proposal = create_multi_choice(&mut rt, &dao, &OPTIONS);
+
+    assert_error(
+        cast_multi_vote(&mut rt, &dao, proposal, &member, 0),
+        ErrorCode::InsufficientCosponsors,
+    );
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::CosponsorMultiChoiceProposal {
+                dao: dao.key,
+                registry: dao.registry.key,
+                proposal,
+                member: cosponsor.account,
+                cosponsor: cosponsor.key,
+            },
+            governance::instruction::CosponsorMultiChoiceProposal { proposal_id: 0 },
+        ),
+        &[cosponsor.key],
+    )
+    .unwrap();
+    cast_multi_vote(&mut rt, &dao, proposal, &member, 0).unwrap();
+}
+
+#[test]
+fn multi_choice_proposal_keeps_its_electorate_and_rules() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let listed = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let unlisted = add_member(&mut rt, &dao.registry, MemberType::Human, 70);
+    set_dao_field(&mut rt, &dao, |state| {
+        state.quorum_bps = 1_000;
+        state.quadratic_voting = true;
+    });
+    let (mut ix, proposal) = create_multi_choice_ix(
+        &dao,
+        dao.authority,
+        &OPTIONS,
+        VoterEligibility::Allowlist(vec![listed.key]),
+    );
+    ix.accounts.push(AccountMeta::new_readonly(listed.account, false));
+    rt.send(ix, &[proposal, dao.authority]).unwrap();
+    let state = multi_choice(&rt, &proposal);
+    assert_eq!(state.eligible_power, 30);
+    assert_eq!(state.quorum_bps, 1_000);
+
+    // Later changes to the DAO do not reach the open proposal.
+    set_dao_field(&mut rt, &dao, |state| {
+        state.quorum_bps = 10_000;
+        state.quadratic_voting = false;
+    });
+    assert_error(
+        cast_multi_vote(&mut rt, &dao, proposal, &unlisted, 0),
+        MembershipError::NotEligibleForProposal,
+    );
+    cast_multi_vote(&mut rt, &dao, proposal, &listed, 1).unwrap();
+    // sqrt(30), a sixth of the allowlist's power: over the old quorum only.
+    assert_eq!(multi_choice(&rt, &proposal).tallies, vec![0, 5, 0]);
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_multi_choice(&mut rt, &dao, proposal).unwrap();
+    let state = multi_choice(&rt, &proposal);
+    assert!(state.quorum_met);
+    assert_eq!(state.winning_option, Some(1));
+}
+
+#[test]
+fn multi_choice_proposals_count_against_the_open_limit() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.max_open_proposals = 1);
+    create_multi_choice(&mut rt, &dao, &OPTIONS);
+
+    let (ix, proposal) = create_multi_choice_ix(&dao, dao.authority, &OPTIONS, VoterEligibility::All);
+    assert_error(rt.send(ix, &[proposal, dao.authority]), ErrorCode::TooManyOpenProposals);
+}
@@ -0,0 +1,67 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::MultiChoiceLeader;
+use membership::MemberType;
+
+const OPTIONS: [&str; 3] = ["Alpha", "Beta", "Gamma"];
+
+fn leader(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> MultiChoiceLeader {
+    let proposal_id = multi_choice(rt, &proposal).id;
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::GetMultiChoiceLeader { dao: dao.key, proposal },
+            governance::instruction::GetMultiChoiceLeader { proposal_id },
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+#[test]
+fn leader_is_the_option_with_the_most_weight() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let big = add_member(&mut rt, &dao.registry, MemberType::Human, 60);
+    let small = add_member(&mut rt, &dao.registry, MemberType::Human, 40);
+    let proposal = create_multi_choice(&mut rt, &dao, &OPTIONS);
+
+    let empty = leader(&mut rt, &dao, proposal);
+    assert_eq!(empty.option_index, None);
+    assert_eq!(empty.weight, 0);
+    assert!(!empty.tied);
+
+    cast_multi_vote(&mut rt, &dao, proposal, &small, 0).unwrap();
+    cast_multi_vote(&mut rt, &dao, proposal, &big, 2).unwrap();
+
+    let current = leader(&mut rt, &dao, proposal);
+    assert_eq!(current.option_index, Some(2));
+    assert_eq!(current.weight, 60);
+    assert!(!current.tied);
+    // The preview is read-only.
+    assert_eq!(multi_choice(&rt, &proposal).tallies, vec![40, 0, 60]);
+}
+
+#[test]
+fn tie_reports_the_lowest_tied_option() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, 50);
+    let second = add_member(&mut rt, &dao.registry, MemberType::Human, 50);
+    let proposal = create_multi_choice(&mut rt, &dao, &OPTIONS);
+
+    cast_multi_vote(&mut rt, &dao, proposal, &first, 2).unwrap();
+    cast_multi_vote(&mut rt, &dao, proposal, &second, 1).unwrap();
+
+    let tied = leader(&mut rt, &dao, proposal);
+    assert_eq!(tied.option_index, Some(1));
+    assert_eq!(tied.weight, 50);
+    assert!(tied.tied);
+}
@@ -0,0 +1,116 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use dao::*;
+use governance::ExecutionPayload;
+use membership::{MemberType, MembershipError};
+
+fn try_convert(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::ConvertToMultisigMember {
+            registry: registry.key,
+            member: member.account,
+            owner: member.key,
+        },
+        membership::instruction::ConvertToMultisigMember { signers, threshold },
+    );
+    rt.send(ix, &[member.key])
+}
+
+/// Votes for `member` as `voter`, with `cosigners` signing alongside.
+fn vote_as(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    voter: Pubkey,
+    cosigners: &[Pubkey],
+) -> std::result::Result<(), TxError> {
+    let mut accounts = vote_accounts(dao, proposal, member);
+    accounts.voter = voter;
+    let mut ix = instruction(
+        governance::ID,
+        accounts,
+        governance::instruction::Vote {
+            support: true,
+            rationale: String::new(),
+        },
+    );
+    ix.accounts
+        .extend(cosigners.iter().map(|key| AccountMeta::new_readonly(*key, true)));
+    let signers: Vec<_> = std::iter::once(voter).chain(cosigners.iter().copied()).collect();
+    rt.send(ix, &signers)
+}
+
+#[test]
+fn single_key_member_votes_with_its_own_key() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let stranger = rt.wallet(SOL);
+
+    assert!(vote_as(&mut rt, &dao, proposal, &member, stranger, &[]).is_err());
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 10);
+}
+
+#[test]
+fn converted_member_votes_with_a_threshold_of_its_signers() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let signers: Vec<_> = (0..3).map(|_| rt.wallet(SOL)).collect();
+    try_convert(&mut rt, &dao.registry, &member, signers.clone(), 2).unwrap();
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    // The member's own key no longer votes for it.
+    assert!(vote(&mut rt, &dao, proposal, &member, true).is_err());
+    assert_error(
+        vote_as(&mut rt, &dao, proposal, &member, signers[0], &[]),
+        MembershipError::MemberThresholdNotMet,
+    );
+    assert_error(
+        vote_as(&mut rt, &dao, proposal, &member, signers[0], &[signers[0]]),
+        MembershipError::MemberThresholdNotMet,
+    );
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 0);
+
+    vote_as(&mut rt, &dao, proposal, &member, signers[0], &[signers[2]]).unwrap();
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 10);
+}
+
+#[test]
+fn invalid_signer_sets_are_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let a = Pubkey::new_unique();
+    let b = Pubkey::new_unique();
+    let too_many: Vec<_> = (0..=membership::MAX_MEMBER_SIGNERS).map(|_| Pubkey::new_unique()).collect();
+
+    for (signers, threshold) in [(vec![a, b], 0), (vec![a, b], 3), (vec![a, a], 1), (too_many, 1)] {
+        assert_error(
+            try_convert(&mut rt, &dao.registry, &member, signers, threshold),
+            MembershipError::InvalidMemberSigners,
+        );
+    }
+
+    // The conversion is one-way.
+    try_convert(&mut rt, &dao.registry, &member, vec![a, b], 1).unwrap();
+    assert_error(
+        try_convert(&mut rt, &dao.registry, &member, vec![a], 1),
+        MembershipError::InvalidMemberAccount,
+    );
+}
@@ -0,0 +1,31 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload};
+
+#[test]
+fn closing_a_proposal_frees_its_slot_once() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.max_open_proposals = 2);
+    let first = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 2);
+
+    assert_error(
+        try_create_proposal(&mut rt, &dao, dao.authority, proposal_args(ExecutionPayload::None)),
+        ErrorCode::TooManyOpenProposals,
+    );
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, first).unwrap();
+    assert_eq!(dao.dao(&rt).open_proposal_count, 1);
+    assert_error(close_proposal(&mut rt, &dao, first), ErrorCode::ProposalNotActive);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 1);
+
+    create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_eq!(dao.dao(&rt).open_proposal_count, 2);
+}
@@ -0,0 +1,66 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::ExecutionPayload;
+use membership::MemberType;
+
+fn participation(rt: &mut Runtime, voter: &TestMember) -> u64 {
+    let id = member(rt, voter).id;
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::GetParticipation { member: voter.account },
+            membership::instruction::GetParticipation { member_id: id },
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+#[test]
+fn member_who_skips_a_proposal_has_partial_participation() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposals: Vec<_> = (0..3)
+        .map(|_| create_proposal(&mut rt, &dao, ExecutionPayload::None))
+        .collect();
+
+    vote(&mut rt, &dao, proposals[0], &voter, true).unwrap();
+    vote(&mut rt, &dao, proposals[2], &voter, false).unwrap();
+
+    let state = member(&rt, &voter);
+    assert_eq!(state.votes_cast, 2);
+    assert_eq!(state.proposals_eligible, 3);
+    assert_eq!(participation(&mut rt, &voter), 6_666);
+}
+
+#[test]
+fn member_who_never_voted_has_no_participation() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let idle = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_eq!(member(&rt, &idle).votes_cast, 0);
+    assert_eq!(participation(&mut rt, &idle), 0);
+}
+
+#[test]
+fn eligibility_starts_at_the_first_proposal_voted_on() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let second = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, second, &voter, true).unwrap();
+
+    assert_eq!(member(&rt, &voter).proposals_eligible, 1);
+    assert_eq!(participation(&mut rt, &voter), 10_000);
+}
@@ -0,0 +1,70 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, VoteRecord};
+use membership::{MemberType, MembershipError, PowerTier};
+
+fn set_power_tiers(rt: &mut Runtime, dao: &TestDao, tiers: Vec<PowerTier>) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetPowerTiers {
+            registry: dao.registry.key,
+            audit_log: current_audit_log(rt, &dao.registry),
+            authority: dao.registry.authority,
+        },
+        membership::instruction::SetPowerTiers { tiers },
+    );
+    rt.send(ix, &[dao.registry.authority])
+}
+
+/// First 1,000 in full, the next 9,000 at half, anything beyond at a quarter.
+fn anti_whale_tiers() -> Vec<PowerTier> {
+    vec![
+        PowerTier { from: 1_000, bps: 5_000 },
+        PowerTier { from: 10_000, bps: 2_500 },
+    ]
+}
+
+#[test]
+fn votes_count_progressively_less_across_tier_boundaries() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_power_tiers(&mut rt, &dao, anti_whale_tiers()).unwrap();
+    let cases = [
+        (999, 999),
+        (1_000, 1_000),
+        (1_001, 1_000),
+        (1_002, 1_001),
+        (10_000, 5_500),
+        (10_004, 5_501),
+        (20_000, 8_000),
+    ];
+    let voters: Vec<_> = cases
+        .iter()
+        .map(|&(raw, _)| add_member(&mut rt, &dao.registry, MemberType::Human, raw))
+        .collect();
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    for (voter, (raw, effective)) in voters.iter().zip(cases) {
+        vote(&mut rt, &dao, proposal, voter, true).unwrap();
+        let record: VoteRecord = rt.get(&vote_record(&proposal, &voter.account));
+        assert_eq!(record.weight, effective, "raw power {raw}");
+    }
+}
+
+#[test]
+fn tiers_must_start_at_increasing_levels() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+
+    let mut unordered = anti_whale_tiers();
+    unordered.reverse();
+    assert_error(set_power_tiers(&mut rt, &dao, unordered), MembershipError::InvalidConfigValue);
+    let from_zero = vec![PowerTier { from: 0, bps: 5_000 }];
+    assert_error(set_power_tiers(&mut rt, &dao, from_zero), MembershipError::InvalidConfigValue);
+
+    set_power_tiers(&mut rt, &dao, Vec::new()).unwrap();
+}
@@ -0,0 +1,117 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, ProposalStatus};
+use membership::MemberType;
+
+/// Reserves `count` IDs, creating a placeholder for each of `placeholders`.
+fn preallocate(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    count: u64,
+    placeholders: &[Pubkey],
+) -> std::result::Result<(), TxError> {
+    let mut ix = instruction(
+        governance::ID,
+        governance::accounts::PreallocateProposals {
+            dao: dao.key,
+            authority: dao.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        governance::instruction::PreallocateProposals { count },
+    );
+    ix.accounts
+        .extend(placeholders.iter().map(|key| AccountMeta::new(*key, true)));
+    let signers: Vec<_> = std::iter::once(dao.authority).chain(placeholders.iter().copied()).collect();
+    rt.send(ix, &signers)
+}
+
+fn fill(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    proposal_id: u64,
+) -> std::result::Result<(), TxError> {
+    let args = proposal_args(ExecutionPayload::None);
+    let mut ix = instruction(
+        governance::ID,
+        governance::accounts::FillProposal {
+            dao: dao.key,
+            proposal,
+            authority: dao.authority,
+            system_program: anchor_lang::system_program::ID,
+            registry: Some(dao.registry.key),
+        },
+        governance::instruction::FillProposal {
+            proposal_id,
+            title: args.title,
+            description: args.description,
+            amount: args.amount,
+            payload: args.payload,
+            quorum_bps_override: args.quorum_bps_override,
+            category: args.category,
+            eligibility: args.eligibility,
+        },
+    );
+    // A proposal account without a placeholder is created, so it signs.
+    ix.accounts[1].is_signer = true;
+    rt.send(ix, &[dao.authority, proposal])
+}
+
+#[test]
+fn reserved_ids_are_skipped_by_new_proposals_and_filled_later() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let before = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let placeholder = Pubkey::new_unique();
+
+    preallocate(&mut rt, &dao, 3, &[placeholder]).unwrap();
+    let state = dao::proposal(&rt, &placeholder);
+    assert!(state.status == ProposalStatus::Pending);
+    assert_eq!(state.id, 1);
+    assert_error(vote(&mut rt, &dao, placeholder, &member, true), ErrorCode::ProposalNotActive);
+
+    // Proposals created meanwhile are numbered after the reserved range.
+    let after = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_eq!(dao::proposal(&rt, &before).id, 0);
+    assert_eq!(dao::proposal(&rt, &after).id, 4);
+
+    fill(&mut rt, &dao, placeholder, 1).unwrap();
+    let state = dao::proposal(&rt, &placeholder);
+    assert!(state.status == ProposalStatus::Active);
+    assert_eq!(state.id, 1);
+    vote(&mut rt, &dao, placeholder, &member, true).unwrap();
+    assert_eq!(dao::proposal(&rt, &placeholder).votes_for, 10);
+
+    // A reserved ID without a placeholder takes a fresh account.
+    let fresh = Pubkey::new_unique();
+    fill(&mut rt, &dao, fresh, 3).unwrap();
+    assert_eq!(dao::proposal(&rt, &fresh).id, 3);
+}
+
+#[test]
+fn ids_outside_the_reservation_or_already_taken_are_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let placeholder = Pubkey::new_unique();
+    preallocate(&mut rt, &dao, 2, &[placeholder]).unwrap();
+
+    assert_error(fill(&mut rt, &dao, Pubkey::new_unique(), 0), ErrorCode::ProposalIdNotReserved);
+    assert_error(fill(&mut rt, &dao, Pubkey::new_unique(), 2), ErrorCode::ProposalIdNotReserved);
+    fill(&mut rt, &dao, placeholder, 0).unwrap();
+    assert!(fill(&mut rt, &dao, placeholder, 0).is_err());
+
+    assert_error(preallocate(&mut rt, &dao, 0, &[]), ErrorCode::InvalidReservation);
+    assert_error(
+        preallocate(&mut rt, &dao, governance::MAX_RESERVED_PROPOSALS + 1, &[]),
+        ErrorCode::InvalidReservation,
+    );
+    let extra = [Pubkey::new_unique(), Pubkey::new_unique()];
+    assert_error(preallocate(&mut rt, &dao, 1, &extra), ErrorCode::InvalidReservation);
+}
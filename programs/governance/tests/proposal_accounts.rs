@@ -0,0 +1,73 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload};
+use membership::MemberType;
+
+fn close_proposal_account(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    destination: Pubkey,
+) -> std::result::Result<(), TxError> {
+    let proposal_id = dao::proposal(rt, &proposal).id;
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::CloseProposalAccount {
+                dao: dao.key,
+                proposal,
+                destination,
+                authority: dao.authority,
+            },
+            governance::instruction::CloseProposalAccount { proposal_id },
+        ),
+        &[dao.authority],
+    )
+}
+
+#[test]
+fn executed_proposal_is_reclaimed_after_retention() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = pass_proposal(&mut rt, &dao, &member, ExecutionPayload::None);
+    execute_proposal(&mut rt, &dao, proposal, None).unwrap();
+    let destination = rt.wallet(SOL);
+    let rent = rt.lamports(&proposal);
+
+    assert_error(
+        close_proposal_account(&mut rt, &dao, proposal, destination),
+        ErrorCode::RetentionPeriodActive,
+    );
+    rt.advance_time(governance::DEFAULT_RETENTION_PERIOD);
+    close_proposal_account(&mut rt, &dao, proposal, destination).unwrap();
+
+    assert!(rt.account(&proposal).is_none());
+    assert_eq!(rt.lamports(&destination), SOL + rent);
+}
+
+#[test]
+fn open_or_timelocked_proposals_cannot_be_reclaimed() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = rt.wallet(SOL);
+
+    let active = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_error(
+        close_proposal_account(&mut rt, &dao, active, destination),
+        ErrorCode::ProposalNotFinished,
+    );
+
+    let passed = pass_proposal(&mut rt, &dao, &member, ExecutionPayload::None);
+    rt.advance_time(governance::DEFAULT_RETENTION_PERIOD);
+    assert_error(
+        close_proposal_account(&mut rt, &dao, passed, destination),
+        ErrorCode::ProposalNotFinished,
+    );
+}
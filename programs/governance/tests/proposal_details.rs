@@ -0,0 +1,94 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, ProposalDetails, ProposalStatus};
+use membership::MemberType;
+
+fn try_get_proposal_details(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    proposal_id: u64,
+) -> std::result::Result<(), TxError> {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::GetProposalDetails {
+                dao: dao.key,
+                registry: dao.registry.key,
+                proposal,
+            },
+            governance::instruction::GetProposalDetails { proposal_id },
+        ),
+        &[payer],
+    )
+}
+
+fn get_proposal_details(rt: &mut Runtime, dao: &TestDao, proposal: Pubkey) -> ProposalDetails {
+    let id = dao::proposal(rt, &proposal).id;
+    try_get_proposal_details(rt, dao, proposal, id).unwrap();
+    rt.returned()
+}
+
+#[test]
+fn partially_voted_proposal_reports_its_state_and_current_result() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let small = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let backer = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let critic = add_member(&mut rt, &dao.registry, MemberType::Human, 20);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 40);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let state = dao::proposal(&rt, &proposal);
+
+    // 10 of 100 misses the 20% quorum.
+    vote(&mut rt, &dao, proposal, &small, true).unwrap();
+    let details = get_proposal_details(&mut rt, &dao, proposal);
+    assert_eq!(details.id, state.id);
+    assert!(details.status == ProposalStatus::Active);
+    assert_eq!(details.proposer, dao.authority);
+    assert_eq!(details.quorum_bps, governance::DEFAULT_QUORUM_BPS);
+    assert_eq!(details.threshold_bps, governance::DEFAULT_THRESHOLD_BPS);
+    assert_eq!(details.quorum_base, 100);
+    assert_eq!(details.created_at, state.created_at);
+    assert_eq!(details.voting_ends_at, state.voting_ends_at);
+    assert_eq!(details.closed_at, 0);
+    assert!(!details.quorum_met);
+    assert!(details.threshold_met);
+    assert!(details.current_result == ProposalStatus::Rejected);
+
+    vote(&mut rt, &dao, proposal, &backer, true).unwrap();
+    vote(&mut rt, &dao, proposal, &critic, false).unwrap();
+    let details = get_proposal_details(&mut rt, &dao, proposal);
+    assert_eq!((details.votes_for, details.votes_against), (40, 20));
+    assert!(details.quorum_met);
+    assert!(details.current_result == ProposalStatus::Passed);
+    assert!(details.status == ProposalStatus::Active);
+}
+
+#[test]
+fn closed_proposal_reports_its_final_status() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    vote(&mut rt, &dao, proposal, &member, false).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+
+    let details = get_proposal_details(&mut rt, &dao, proposal);
+    assert!(details.status == ProposalStatus::Rejected);
+    assert!(details.current_result == ProposalStatus::Rejected);
+    assert_eq!(details.closed_at, rt.clock().unix_timestamp);
+
+    let id = details.id;
+    assert_error(
+        try_get_proposal_details(&mut rt, &dao, proposal, id + 1),
+        ErrorCode::ProposalMismatch,
+    );
+}
@@ -0,0 +1,56 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{quadratic_weight, ExecutionPayload, QuadRounding, VoteRecord};
+use membership::MemberType;
+
+fn weights(power: u64) -> [u64; 3] {
+    [
+        quadratic_weight(power, &QuadRounding::Floor),
+        quadratic_weight(power, &QuadRounding::Round),
+        quadratic_weight(power, &QuadRounding::Ceil),
+    ]
+}
+
+#[test]
+fn small_powers_round_each_way() {
+    assert_eq!(weights(0), [0, 0, 0]);
+    assert_eq!(weights(1), [1, 1, 1]);
+    // sqrt(2) = 1.41 and sqrt(3) = 1.73 sit either side of the midpoint.
+    assert_eq!(weights(2), [1, 1, 2]);
+    assert_eq!(weights(3), [1, 2, 2]);
+    assert_eq!(weights(4), [2, 2, 2]);
+}
+
+#[test]
+fn large_powers_round_each_way() {
+    assert_eq!(weights(1_000_000), [1_000, 1_000, 1_000]);
+    assert_eq!(weights(999_999), [999, 1_000, 1_000]);
+    assert_eq!(weights(1_000_500), [1_000, 1_000, 1_001]);
+    // 1000.5^2 = 1_001_000.25, so 1_001_000 is the last power rounding down.
+    assert_eq!(weights(1_001_000), [1_000, 1_000, 1_001]);
+    assert_eq!(weights(1_001_001), [1_000, 1_001, 1_001]);
+    let root = u32::MAX as u64;
+    assert_eq!(weights(root * root), [root, root, root]);
+    assert_eq!(weights(u64::MAX), [root, root + 1, root + 1]);
+}
+
+#[test]
+fn quadratic_vote_uses_the_dao_rounding() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| {
+        state.quadratic_voting = true;
+        state.quad_rounding = QuadRounding::Ceil;
+    });
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &member.account));
+    assert_eq!(record.weight, 4);
+}
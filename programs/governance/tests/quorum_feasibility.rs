@@ -0,0 +1,72 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use dao::*;
+use membership::MemberType;
+
+fn can_reach_quorum(rt: &mut Runtime, dao: &TestDao, roster: &[&TestMember], quorum_bps_override: Option<u16>) -> bool {
+    let mut ix = instruction(
+        governance::ID,
+        governance::accounts::CanReachQuorum {
+            dao: dao.key,
+            registry: dao.registry.key,
+        },
+        governance::instruction::CanReachQuorum { quorum_bps_override },
+    );
+    ix.accounts
+        .extend(roster.iter().map(|member| AccountMeta::new_readonly(member.account, false)));
+    let payer = rt.wallet(SOL);
+    rt.send(ix, &[payer]).unwrap();
+    rt.returned()
+}
+
+#[test]
+fn quorum_is_reachable_while_enough_members_can_vote() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let second = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let third = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let roster = [&first, &second, &third];
+
+    assert!(can_reach_quorum(&mut rt, &dao, &roster, None));
+    assert!(can_reach_quorum(&mut rt, &dao, &roster, Some(10_000)));
+}
+
+#[test]
+fn quorum_is_unreachable_once_most_members_are_suspended() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let second = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let third = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    flag_dispute(&mut rt, &dao.registry, &second);
+    flag_dispute(&mut rt, &dao.registry, &third);
+    let roster = [&first, &second, &third];
+
+    assert!(can_reach_quorum(&mut rt, &dao, &roster, Some(3_000)));
+    assert!(!can_reach_quorum(&mut rt, &dao, &roster, Some(5_000)));
+}
+
+#[test]
+fn partial_roster_is_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+
+    let mut ix = instruction(
+        governance::ID,
+        governance::accounts::CanReachQuorum {
+            dao: dao.key,
+            registry: dao.registry.key,
+        },
+        governance::instruction::CanReachQuorum { quorum_bps_override: None },
+    );
+    ix.accounts.push(AccountMeta::new_readonly(first.account, false));
+    let payer = rt.wallet(SOL);
+    assert_error(rt.send(ix, &[payer]), governance::ErrorCode::IncompleteMemberSet);
+}
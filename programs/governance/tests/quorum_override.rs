@@ -0,0 +1,47 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, ProposalStatus, DEFAULT_QUORUM_BPS};
+use membership::MemberType;
+
+#[test]
+fn raised_quorum_decides_the_close() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 70);
+    let mut args = proposal_args(ExecutionPayload::None);
+    args.quorum_bps_override = Some(5_000);
+    let raised = try_create_proposal(&mut rt, &dao, dao.authority, args).unwrap();
+    let default = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert_eq!(dao::proposal(&rt, &raised).quorum_bps, 5_000);
+
+    // 30% turnout clears the default 20% quorum but not the raised one.
+    vote(&mut rt, &dao, raised, &voter, true).unwrap();
+    vote(&mut rt, &dao, default, &voter, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, raised).unwrap();
+    close_proposal(&mut rt, &dao, default).unwrap();
+
+    let state = dao::proposal(&rt, &raised);
+    assert!(state.status == ProposalStatus::Rejected);
+    assert!(!state.quorum_met);
+    assert!(dao::proposal(&rt, &default).status == ProposalStatus::Passed);
+}
+
+#[test]
+fn override_cannot_lower_the_quorum() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let mut args = proposal_args(ExecutionPayload::None);
+    args.quorum_bps_override = Some(DEFAULT_QUORUM_BPS - 1);
+
+    assert_error(
+        try_create_proposal(&mut rt, &dao, dao.authority, args),
+        ErrorCode::InvalidQuorumOverride,
+    );
+    assert_eq!(dao.dao(&rt).proposal_count, 0);
+}
@@ -0,0 +1,57 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, VoteRecord, MAX_RATIONALE_LEN};
+use membership::MemberType;
+
+fn vote_with_rationale(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    rationale: String,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        governance::ID,
+        vote_accounts(dao, proposal, member),
+        governance::instruction::Vote { support: true, rationale },
+    );
+    rt.send(ix, &[member.key])
+}
+
+#[test]
+fn vote_record_keeps_the_rationale() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let rationale = "x".repeat(MAX_RATIONALE_LEN);
+
+    vote_with_rationale(&mut rt, &dao, proposal, &member, rationale.clone()).unwrap();
+
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &member.account));
+    assert_eq!(record.rationale, rationale);
+    assert_eq!(record.weight, 100);
+}
+
+#[test]
+fn over_length_rationale_is_rejected() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        vote_with_rationale(&mut rt, &dao, proposal, &member, "x".repeat(MAX_RATIONALE_LEN + 1)),
+        ErrorCode::RationaleTooLong,
+    );
+    assert_eq!(dao::proposal(&rt, &proposal).votes_for, 0);
+
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &member.account));
+    assert!(record.rationale.is_empty());
+}
@@ -0,0 +1,60 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{proposal_reference, ErrorCode, ExecutionPayload};
+
+/// 2024-01-01T00:00:00Z.
+const NEW_YEAR_2024: i64 = 1_704_067_200;
+
+fn set_proposal_prefix(rt: &mut Runtime, dao: &TestDao, prefix: &str) -> std::result::Result<(), TxError> {
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::SetProposalPrefix {
+                dao: dao.key,
+                authority: dao.authority,
+            },
+            governance::instruction::SetProposalPrefix { prefix: prefix.to_string() },
+        ),
+        &[dao.authority],
+    )
+}
+
+#[test]
+fn reference_uses_the_utc_year_and_padded_id() {
+    assert_eq!(proposal_reference("PROP", NEW_YEAR_2024 - 1, 1), "PROP-2023-0001");
+    assert_eq!(proposal_reference("PROP", NEW_YEAR_2024, 1), "PROP-2024-0001");
+    assert_eq!(proposal_reference("LLC", NEW_YEAR_2024, 12_345), "LLC-2024-12345");
+}
+
+#[test]
+fn proposal_stores_its_reference_at_creation() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let now = rt.clock().unix_timestamp;
+    rt.advance_time(NEW_YEAR_2024 - now);
+
+    let first = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let state = dao::proposal(&rt, &first);
+    assert_eq!(state.reference, format!("PROP-2024-{:04}", state.id));
+
+    set_proposal_prefix(&mut rt, &dao, "LLC").unwrap();
+    let second = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let second_state = dao::proposal(&rt, &second);
+    assert_eq!(second_state.reference, format!("LLC-2024-{:04}", second_state.id));
+    assert_eq!(dao::proposal(&rt, &first).reference, state.reference);
+}
+
+#[test]
+fn prefix_must_be_short_and_alphanumeric() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+
+    for prefix in ["", "PROP-X", &"P".repeat(governance::MAX_PROPOSAL_PREFIX_LEN + 1)] {
+        assert_error(set_proposal_prefix(&mut rt, &dao, prefix), ErrorCode::InvalidProposalPrefix);
+    }
+    assert_eq!(dao.dao(&rt).proposal_prefix, governance::DEFAULT_PROPOSAL_PREFIX);
+}
@@ -0,0 +1,64 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalStatus};
+use membership::MemberType;
+
+fn proposal_status(rt: &mut Runtime, proposal: Pubkey) -> ProposalStatus {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::GetProposalStatus { proposal },
+            governance::instruction::GetProposalStatus {},
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+fn verify_membership(rt: &mut Runtime, registry: &Registry, member: &TestMember, pubkey: Pubkey) -> bool {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::VerifyMembership {
+                registry: registry.key,
+                member: member.account,
+            },
+            membership::instruction::VerifyMembership { pubkey },
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+#[test]
+fn proposal_status_is_returned() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    assert!(proposal_status(&mut rt, proposal) == ProposalStatus::Active);
+
+    vote(&mut rt, &dao, proposal, &member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert!(proposal_status(&mut rt, proposal) == ProposalStatus::Passed);
+}
+
+#[test]
+fn membership_verification_is_returned() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+
+    assert!(verify_membership(&mut rt, &dao.registry, &member, member.key));
+    assert!(!verify_membership(&mut rt, &dao.registry, &member, Pubkey::new_unique()));
+}
@@ -0,0 +1,71 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalStatus};
+use membership::MemberType;
+
+const OPTIONS: [&str; 3] = ["Alpha", "Beta", "Gamma"];
+
+#[test]
+fn rules_raised_mid_vote_do_not_apply_to_open_proposals() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let backer = add_member(&mut rt, &dao.registry, MemberType::Human, 30);
+    let critic = add_member(&mut rt, &dao.registry, MemberType::Human, 20);
+    add_member(&mut rt, &dao.registry, MemberType::Human, 50);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    set_dao_field(&mut rt, &dao, |state| {
+        state.quorum_bps = 9_000;
+        state.threshold_bps = 9_000;
+    });
+    vote(&mut rt, &dao, proposal, &backer, true).unwrap();
+    vote(&mut rt, &dao, proposal, &critic, false).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+
+    // Half the power voted and 60% of it in favour: enough under the
+    // rules at creation, not under the new ones.
+    let state = dao::proposal(&rt, &proposal);
+    assert_eq!(state.quorum_bps, governance::DEFAULT_QUORUM_BPS);
+    assert_eq!(state.threshold_bps, governance::DEFAULT_THRESHOLD_BPS);
+    assert!(state.quorum_met);
+    assert!(state.status == ProposalStatus::Passed);
+}
+
+/// Closes a multi-choice vote where options 0 and 1 get `leader` and
+/// `runner_up` power, under a DAO threshold of `threshold_bps` at creation
+/// that is reset to the default straight after.
+fn close_two_way_race(threshold_bps: u16, leader: u64, runner_up: u64) -> governance::MultiChoiceProposal {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let first = add_member(&mut rt, &dao.registry, MemberType::Human, leader);
+    let second = add_member(&mut rt, &dao.registry, MemberType::Human, runner_up);
+    set_dao_field(&mut rt, &dao, |state| state.threshold_bps = threshold_bps);
+    let proposal = create_multi_choice(&mut rt, &dao, &OPTIONS);
+    set_dao_field(&mut rt, &dao, |state| state.threshold_bps = governance::DEFAULT_THRESHOLD_BPS);
+
+    cast_multi_vote(&mut rt, &dao, proposal, &first, 0).unwrap();
+    cast_multi_vote(&mut rt, &dao, proposal, &second, 1).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_multi_choice(&mut rt, &dao, proposal).unwrap();
+    multi_choice(&rt, &proposal)
+}
+
+#[test]
+fn multi_choice_leader_needs_its_snapshotted_threshold() {
+    let narrow = close_two_way_race(6_000, 55, 45);
+    assert_eq!(narrow.threshold_bps, 6_000);
+    assert!(narrow.status == ProposalStatus::Rejected);
+    assert_eq!(narrow.winning_option, None);
+
+    let clear = close_two_way_race(6_000, 70, 30);
+    assert!(clear.status == ProposalStatus::Passed);
+    assert_eq!(clear.winning_option, Some(0));
+
+    let tied = close_two_way_race(governance::DEFAULT_THRESHOLD_BPS, 50, 50);
+    assert!(tied.status == ProposalStatus::Rejected);
+}
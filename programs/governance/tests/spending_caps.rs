@@ -0,0 +1,70 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalStatus};
+use membership::{MemberType, MembershipError, RegistryField, SpendingCategory};
+
+/// Passes a proposal in `category` that sends `amount` to `destination`.
+fn pass_spend(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    member: &TestMember,
+    category: SpendingCategory,
+    destination: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let mut args = proposal_args(ExecutionPayload::Transfer { destination, amount });
+    args.category = category;
+    let proposal = try_create_proposal(rt, dao, dao.authority, args).unwrap();
+    vote(rt, dao, proposal, member, true).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(rt, dao, proposal).unwrap();
+    rt.advance_time(governance::DEFAULT_TIMELOCK);
+    proposal
+}
+
+#[test]
+fn spend_within_its_category_cap_executes() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::OperationalCap, SOL);
+    update_config(&mut rt, &dao.registry, RegistryField::FinancialCap, 5 * SOL);
+    rt.airdrop(&dao.treasury, 10 * SOL);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+
+    let routine = pass_spend(&mut rt, &dao, &member, SpendingCategory::Operational, destination, SOL);
+    execute_proposal(&mut rt, &dao, routine, Some(destination)).unwrap();
+    let large = pass_spend(&mut rt, &dao, &member, SpendingCategory::Financial, destination, 3 * SOL);
+    execute_proposal(&mut rt, &dao, large, Some(destination)).unwrap();
+
+    assert!(dao::proposal(&rt, &large).status == ProposalStatus::Executed);
+    assert_eq!(rt.lamports(&destination), 4 * SOL);
+}
+
+#[test]
+fn spend_over_its_category_cap_is_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::OperationalCap, SOL);
+    rt.airdrop(&dao.treasury, 10 * SOL);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+
+    let proposal = pass_spend(&mut rt, &dao, &member, SpendingCategory::Operational, destination, 2 * SOL);
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, Some(destination)),
+        MembershipError::SpendingCapExceeded,
+    );
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Passed);
+    assert_eq!(rt.lamports(&destination), 0);
+
+    // The financial cap is unset, so the same amount goes through there.
+    let proposal = pass_spend(&mut rt, &dao, &member, SpendingCategory::Financial, destination, 2 * SOL);
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+    assert_eq!(rt.lamports(&destination), 2 * SOL);
+}
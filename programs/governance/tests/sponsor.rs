@@ -0,0 +1,84 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, VoteRecord};
+use membership::{MemberType, MembershipError, RegistryField};
+
+fn set_sponsor(rt: &mut Runtime, dao: &TestDao, member: &TestMember, sponsor: Pubkey) {
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetSponsor {
+                registry: dao.registry.key,
+                audit_log: current_audit_log(rt, &dao.registry),
+                member: member.account,
+                authority: dao.authority,
+            },
+            membership::instruction::SetSponsor { sponsor: Some(sponsor) },
+        ),
+        &[dao.authority],
+    )
+    .unwrap();
+}
+
+fn vote_cosigned(
+    rt: &mut Runtime,
+    dao: &TestDao,
+    proposal: Pubkey,
+    member: &TestMember,
+    sponsor: Option<Pubkey>,
+) -> std::result::Result<(), TxError> {
+    let mut accounts = vote_accounts(dao, proposal, member);
+    accounts.sponsor = sponsor;
+    let ix = instruction(
+        governance::ID,
+        accounts,
+        governance::instruction::Vote {
+            support: true,
+            rationale: String::new(),
+        },
+    );
+    let mut signers = vec![member.key];
+    signers.extend(sponsor);
+    rt.send(ix, &signers)
+}
+
+#[test]
+fn ai_vote_needs_its_sponsor_to_cosign() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::RequireAiSponsor, 1);
+    let agent = add_member(&mut rt, &dao.registry, MemberType::AI, 100);
+    let sponsor = rt.wallet(SOL);
+    set_sponsor(&mut rt, &dao, &agent, sponsor);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        vote_cosigned(&mut rt, &dao, proposal, &agent, None),
+        MembershipError::SponsorSignatureRequired,
+    );
+    let stranger = rt.wallet(SOL);
+    assert_error(
+        vote_cosigned(&mut rt, &dao, proposal, &agent, Some(stranger)),
+        MembershipError::SponsorSignatureRequired,
+    );
+
+    vote_cosigned(&mut rt, &dao, proposal, &agent, Some(sponsor)).unwrap();
+    let record: VoteRecord = rt.get(&vote_record(&proposal, &agent.account));
+    assert_eq!(record.weight, 100);
+}
+
+#[test]
+fn human_vote_needs_no_sponsor() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::RequireAiSponsor, 1);
+    let human = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    vote_cosigned(&mut rt, &dao, proposal, &human, None).unwrap();
+}
@@ -0,0 +1,64 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload, TimeRemaining};
+
+fn try_get_time_remaining(
+    rt: &mut Runtime,
+    proposal: Pubkey,
+    proposal_id: u64,
+) -> std::result::Result<(), TxError> {
+    let payer = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::GetTimeRemaining { proposal },
+            governance::instruction::GetTimeRemaining { proposal_id },
+        ),
+        &[payer],
+    )
+}
+
+fn get_time_remaining(rt: &mut Runtime, proposal: Pubkey, proposal_id: u64) -> TimeRemaining {
+    try_get_time_remaining(rt, proposal, proposal_id).unwrap();
+    rt.returned()
+}
+
+#[test]
+fn open_proposal_counts_down_to_its_deadline() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let id = dao::proposal(&rt, &proposal).id;
+
+    let remaining = get_time_remaining(&mut rt, proposal, id);
+    assert_eq!(remaining.seconds, governance::DEFAULT_VOTING_PERIOD as u64);
+    assert!(remaining.open);
+
+    rt.advance_time(100);
+    let remaining = get_time_remaining(&mut rt, proposal, id);
+    assert_eq!(remaining.seconds, governance::DEFAULT_VOTING_PERIOD as u64 - 100);
+    assert!(remaining.open);
+
+    assert_error(try_get_time_remaining(&mut rt, proposal, id + 1), ErrorCode::ProposalMismatch);
+}
+
+#[test]
+fn expired_proposal_reports_no_time_left() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let id = dao::proposal(&rt, &proposal).id;
+
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD + 1);
+    let remaining = get_time_remaining(&mut rt, proposal, id);
+    assert_eq!(remaining.seconds, 0);
+    assert!(!remaining.open);
+
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert!(!get_time_remaining(&mut rt, proposal, id).open);
+}
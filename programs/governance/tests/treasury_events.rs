@@ -0,0 +1,64 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, TreasuryDeposit, TreasuryWithdraw};
+use membership::MemberType;
+
+#[test]
+fn deposit_event_matches_the_balance_change() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let depositor = rt.wallet(10 * SOL);
+    let before = rt.lamports(&dao.treasury);
+
+    rt.send(
+        instruction(
+            governance::ID,
+            governance::accounts::Deposit {
+                dao: dao.key,
+                registry: dao.registry.key,
+                treasury: dao.treasury,
+                depositor,
+                system_program: anchor_lang::system_program::ID,
+            },
+            governance::instruction::Deposit { amount: 3 * SOL },
+        ),
+        &[depositor],
+    )
+    .unwrap();
+
+    let events = rt.events::<TreasuryDeposit>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].dao, dao.key);
+    assert_eq!(events[0].amount, 3 * SOL);
+    assert_eq!(events[0].mint, None);
+    assert_eq!(events[0].balance_after, rt.lamports(&dao.treasury));
+    assert_eq!(events[0].balance_after, before + 3 * SOL);
+}
+
+#[test]
+fn withdraw_event_matches_the_balance_change() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: SOL / 4,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+    let before = rt.lamports(&dao.treasury);
+
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+
+    let events = rt.events::<TreasuryWithdraw>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].amount, SOL / 4);
+    assert_eq!(events[0].mint, None);
+    assert_eq!(events[0].balance_after, rt.lamports(&dao.treasury));
+    assert_eq!(events[0].balance_after, before - SOL / 4);
+}
@@ -0,0 +1,60 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, ProposalStatus};
+use membership::{MemberType, MembershipError, RegistryField};
+
+/// Tops the treasury up to exactly `spendable` above its rent-exempt
+/// minimum plus a one-SOL `treasury_min_balance`.
+fn fund_treasury(rt: &mut Runtime, dao: &TestDao, spendable: u64) {
+    update_config(rt, &dao.registry, RegistryField::TreasuryMinBalance, SOL);
+    let target = Rent::default().minimum_balance(0) + SOL + spendable;
+    let balance = rt.lamports(&dao.treasury);
+    rt.airdrop(&dao.treasury, target - balance);
+}
+
+#[test]
+fn spend_that_leaves_the_minimum_executes() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    fund_treasury(&mut rt, &dao, 2 * SOL);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: 2 * SOL,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Executed);
+    assert_eq!(rt.lamports(&dao.treasury), Rent::default().minimum_balance(0) + SOL);
+}
+
+#[test]
+fn spend_that_breaches_the_minimum_is_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    fund_treasury(&mut rt, &dao, 2 * SOL);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let payload = ExecutionPayload::Transfer {
+        destination,
+        amount: 2 * SOL + 1,
+    };
+    let proposal = pass_proposal(&mut rt, &dao, &member, payload);
+
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, Some(destination)),
+        MembershipError::TreasuryMinimumBreached,
+    );
+    let state = dao::proposal(&rt, &proposal);
+    assert!(state.status == ProposalStatus::Passed);
+    assert_eq!(state.execution_attempts, 0);
+    assert_eq!(rt.lamports(&destination), 0);
+}
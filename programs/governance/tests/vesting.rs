@@ -0,0 +1,71 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ExecutionPayload, VoteRecord};
+use membership::MemberType;
+
+fn set_vesting(rt: &mut Runtime, dao: &TestDao, member: &TestMember, start: i64, duration: i64, total: u64) {
+    let member_id = common::member(rt, member).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetVesting {
+            registry: dao.registry.key,
+            audit_log: current_audit_log(rt, &dao.registry),
+            member: member.account,
+            authority: dao.registry.authority,
+        },
+        membership::instruction::SetVesting {
+            member_id,
+            start,
+            duration,
+            total,
+        },
+    );
+    rt.send(ix, &[dao.registry.authority]).unwrap();
+}
+
+/// The weight `member` votes with on a proposal opened now.
+fn weight_now(rt: &mut Runtime, dao: &TestDao, member: &TestMember) -> u64 {
+    let proposal = create_proposal(rt, dao, ExecutionPayload::None);
+    vote(rt, dao, proposal, member, true).unwrap();
+    rt.get::<VoteRecord>(&vote_record(&proposal, &member.account)).weight
+}
+
+#[test]
+fn vested_power_grows_linearly_from_start_to_end() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 1_000);
+    let start = rt.clock().unix_timestamp + 100;
+    set_vesting(&mut rt, &dao, &member, start, 1_000, 800);
+
+    // Before the start only the unvested remainder counts.
+    assert_eq!(weight_now(&mut rt, &dao, &member), 200);
+
+    rt.advance_time(100);
+    assert_eq!(weight_now(&mut rt, &dao, &member), 200);
+
+    rt.advance_time(500);
+    assert_eq!(weight_now(&mut rt, &dao, &member), 600);
+
+    rt.advance_time(500);
+    assert_eq!(weight_now(&mut rt, &dao, &member), 1_000);
+    assert_eq!(common::member(&rt, &member).voting_power, 1_000);
+}
+
+#[test]
+fn vested_power_rounds_down_between_whole_units() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let start = rt.clock().unix_timestamp;
+    set_vesting(&mut rt, &dao, &member, start, 3, 10);
+
+    rt.advance_time(1);
+    assert_eq!(weight_now(&mut rt, &dao, &member), 3);
+    rt.advance_time(1);
+    assert_eq!(weight_now(&mut rt, &dao, &member), 6);
+}
@@ -0,0 +1,51 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::ExecutionPayload;
+use membership::{MemberType, MembershipError, RegistryField};
+
+const WINDOW_SLOTS: u64 = 100;
+
+#[test]
+fn votes_beyond_the_window_limit_wait_for_the_window_to_pass() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    update_config(&mut rt, &dao.registry, RegistryField::MaxVotesPerWindow, 2);
+    update_config(&mut rt, &dao.registry, RegistryField::VoteWindowSlots, WINDOW_SLOTS);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let other = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+    let proposals: Vec<_> = (0..3)
+        .map(|_| create_proposal(&mut rt, &dao, ExecutionPayload::None))
+        .collect();
+
+    vote(&mut rt, &dao, proposals[0], &voter, true).unwrap();
+    rt.advance_slots(10);
+    vote(&mut rt, &dao, proposals[1], &voter, true).unwrap();
+    assert_error(
+        vote(&mut rt, &dao, proposals[2], &voter, true),
+        MembershipError::VoteRateLimited,
+    );
+    // The limit is per member.
+    vote(&mut rt, &dao, proposals[2], &other, true).unwrap();
+
+    // The first vote leaves the window; the second is still in it.
+    rt.advance_slots(WINDOW_SLOTS - 10);
+    vote(&mut rt, &dao, proposals[2], &voter, true).unwrap();
+    assert_eq!(member(&rt, &voter).recent_vote_slots.len(), 2);
+}
+
+#[test]
+fn no_limit_when_switched_off() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let voter = add_member(&mut rt, &dao.registry, MemberType::Human, 10);
+
+    for _ in 0..5 {
+        let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+        vote(&mut rt, &dao, proposal, &voter, true).unwrap();
+    }
+    assert!(member(&rt, &voter).recent_vote_slots.is_empty());
+}
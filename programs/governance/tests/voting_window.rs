@@ -0,0 +1,78 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use common::*;
+use dao::*;
+use governance::{ConfigField, ErrorCode, ExecutionPayload, ProposalStatus};
+
+#[test]
+fn voting_lasts_at_least_the_minimum() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.voting_period = 10);
+    let now = rt.clock().unix_timestamp;
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_eq!(
+        dao::proposal(&rt, &proposal).voting_ends_at,
+        now + governance::DEFAULT_MIN_VOTING_SECS
+    );
+}
+
+#[test]
+fn authority_cannot_close_inside_the_minimum_window() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+
+    assert_error(
+        close_proposal(&mut rt, &dao, proposal),
+        ErrorCode::MinimumVotingPeriodActive,
+    );
+    rt.advance_time(governance::DEFAULT_MIN_VOTING_SECS - 1);
+    assert_error(
+        close_proposal(&mut rt, &dao, proposal),
+        ErrorCode::MinimumVotingPeriodActive,
+    );
+
+    rt.advance_time(1);
+    close_proposal(&mut rt, &dao, proposal).unwrap();
+    assert!(dao::proposal(&rt, &proposal).status == ProposalStatus::Rejected);
+}
+
+#[test]
+fn others_wait_for_voting_to_end() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    let keeper = rt.wallet(SOL);
+    let mut ix = close_proposal_ix(&dao, proposal);
+    ix.accounts[3].pubkey = keeper;
+
+    rt.advance_time(governance::DEFAULT_MIN_VOTING_SECS);
+    assert_error(rt.send(ix.clone(), &[keeper]), ErrorCode::VotingNotEnded);
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    rt.send(ix, &[keeper]).unwrap();
+}
+
+#[test]
+fn minimum_window_cannot_be_set_past_the_cap() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let set_min = |value| ExecutionPayload::SetConfig {
+        field: ConfigField::MinVotingSecs,
+        value,
+    };
+
+    assert_error(
+        try_create_proposal(
+            &mut rt,
+            &dao,
+            dao.authority,
+            proposal_args(set_min(governance::MAX_MIN_VOTING_SECS as u64 + 1)),
+        ),
+        ErrorCode::InvalidConfigValue,
+    );
+    create_proposal(&mut rt, &dao, set_min(governance::MAX_MIN_VOTING_SECS as u64));
+}
@@ -0,0 +1,94 @@
+#[path = "../../membership/tests/common/mod.rs"]
+mod common;
+mod dao;
+
+use anchor_lang::prelude::*;
+use common::*;
+use dao::*;
+use governance::{ErrorCode, ExecutionPayload};
+use membership::MemberType;
+
+fn transfer(destination: Pubkey) -> ExecutionPayload {
+    ExecutionPayload::Transfer {
+        destination,
+        amount: SOL / 4,
+    }
+}
+
+#[test]
+fn passed_proposal_withdraws_exactly_its_payload() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let proposal = pass_proposal(&mut rt, &dao, &member, transfer(destination));
+    let before = rt.lamports(&dao.treasury);
+
+    execute_proposal(&mut rt, &dao, proposal, Some(destination)).unwrap();
+
+    assert_eq!(rt.lamports(&dao.treasury), before - SOL / 4);
+    assert_eq!(rt.lamports(&destination), SOL / 4);
+}
+
+#[test]
+fn withdrawal_to_another_destination_is_refused() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let proposal = pass_proposal(&mut rt, &dao, &member, transfer(destination));
+    let before = rt.lamports(&dao.treasury);
+
+    let thief = Pubkey::new_unique();
+    assert_error(
+        execute_proposal(&mut rt, &dao, proposal, Some(thief)),
+        ErrorCode::InvalidTransfer,
+    );
+    assert_error(execute_proposal(&mut rt, &dao, proposal, None), ErrorCode::InvalidTransfer);
+    assert_eq!(rt.lamports(&dao.treasury), before);
+    assert_eq!(rt.lamports(&thief), 0);
+}
+
+#[test]
+fn proposal_that_has_not_passed_withdraws_nothing() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    let member = add_member(&mut rt, &dao.registry, MemberType::Human, 100);
+    let destination = Pubkey::new_unique();
+    let before = rt.lamports(&dao.treasury);
+
+    let active = create_proposal(&mut rt, &dao, transfer(destination));
+    assert_error(
+        execute_proposal(&mut rt, &dao, active, Some(destination)),
+        ErrorCode::ProposalNotPassed,
+    );
+
+    let rejected = create_proposal(&mut rt, &dao, transfer(destination));
+    vote(&mut rt, &dao, rejected, &member, false).unwrap();
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    close_proposal(&mut rt, &dao, rejected).unwrap();
+    rt.advance_time(governance::DEFAULT_TIMELOCK);
+    assert_error(
+        execute_proposal(&mut rt, &dao, rejected, Some(destination)),
+        ErrorCode::ProposalNotPassed,
+    );
+
+    assert_eq!(rt.lamports(&dao.treasury), before);
+    assert_eq!(rt.lamports(&destination), 0);
+}
+
+#[test]
+fn keeper_rewards_leave_dao_funds_alone() {
+    let mut rt = runtime();
+    let dao = create_dao(&mut rt);
+    set_dao_field(&mut rt, &dao, |state| state.keeper_reward = SOL / 4);
+    let proposal = create_proposal(&mut rt, &dao, ExecutionPayload::None);
+    rt.advance_time(governance::DEFAULT_VOTING_PERIOD);
+    let before = rt.lamports(&dao.treasury);
+
+    let keeper = rt.wallet(SOL);
+    close_expired(&mut rt, &dao, keeper, &[proposal]);
+
+    assert_eq!(rt.lamports(&dao.treasury), before);
+    assert_eq!(rt.lamports(&keeper), SOL);
+}
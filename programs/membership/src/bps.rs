@@ -0,0 +1,73 @@
+//! Basis-point arithmetic. Products are taken in `u128`, so no `u64` input
+//! can overflow an intermediate step.
+
+/// Basis points in a whole: 100%.
+pub const MAX_BPS: u16 = 10_000;
+
+/// `value * bps / 10_000`, rounded down. `None` only if the result exceeds
+/// `u64`, which can happen only when `bps` is above `MAX_BPS`.
+pub fn apply_bps(value: u64, bps: u16) -> Option<u64> {
+    u64::try_from(value as u128 * bps as u128 / MAX_BPS as u128).ok()
+}
+
+/// Whether `part` is at least `bps` of `whole`.
+pub fn meets_bps(part: u64, whole: u64, bps: u16) -> bool {
+    part as u128 * MAX_BPS as u128 >= bps as u128 * whole as u128
+}
+
+/// Whether `part` is strictly more than `bps` of `whole`.
+pub fn exceeds_bps(part: u64, whole: u64, bps: u16) -> bool {
+    part as u128 * MAX_BPS as u128 > bps as u128 * whole as u128
+}
+
+/// `part` as a share of `whole` in basis points, rounded down. `None` when
+/// `whole` is zero.
+pub fn ratio_bps(part: u64, whole: u64) -> Option<u64> {
+    if whole == 0 {
+        return None;
+    }
+    u64::try_from(part as u128 * MAX_BPS as u128 / whole as u128).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_bps_rounds_down_and_holds_at_u64_max() {
+        assert_eq!(apply_bps(999, 5_000), Some(499));
+        assert_eq!(apply_bps(u64::MAX, MAX_BPS), Some(u64::MAX));
+        assert_eq!(apply_bps(u64::MAX, 5_000), Some(u64::MAX / 2));
+        assert_eq!(apply_bps(u64::MAX, 0), Some(0));
+        assert_eq!(apply_bps(u64::MAX, MAX_BPS + 1), None);
+    }
+
+    #[test]
+    fn meets_bps_includes_the_boundary() {
+        assert!(meets_bps(50, 100, 5_000));
+        assert!(!meets_bps(49, 100, 5_000));
+        assert!(meets_bps(0, 0, 5_000));
+        assert!(meets_bps(u64::MAX, u64::MAX, MAX_BPS));
+        assert!(!meets_bps(u64::MAX - 1, u64::MAX, MAX_BPS));
+        assert!(meets_bps(u64::MAX / 2 + 1, u64::MAX, 5_000));
+    }
+
+    #[test]
+    fn exceeds_bps_excludes_the_boundary() {
+        assert!(!exceeds_bps(50, 100, 5_000));
+        assert!(exceeds_bps(51, 100, 5_000));
+        assert!(!exceeds_bps(0, 0, 0));
+        assert!(!exceeds_bps(u64::MAX, u64::MAX, MAX_BPS));
+        assert!(exceeds_bps(u64::MAX, u64::MAX, MAX_BPS - 1));
+        assert!(exceeds_bps(u64::MAX / 2 + 1, u64::MAX, 5_000));
+    }
+
+    #[test]
+    fn ratio_bps_rounds_down_and_refuses_an_empty_whole() {
+        assert_eq!(ratio_bps(1, 3), Some(3_333));
+        assert_eq!(ratio_bps(1, 0), None);
+        assert_eq!(ratio_bps(u64::MAX, u64::MAX), Some(MAX_BPS as u64));
+        assert_eq!(ratio_bps(u64::MAX / 2, u64::MAX), Some(4_999));
+        assert_eq!(ratio_bps(u64::MAX, 1), None);
+    }
+}
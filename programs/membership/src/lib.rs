@@ -1,18 +1,486 @@
+// The IDL instructions generated by `#[program]` still call `AccountInfo::realloc`.
+#![allow(deprecated)]
+// Registration instructions take each member field as its own argument.
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::log::sol_log_data;
+use anchor_lang::solana_program::program::{set_return_data, MAX_RETURN_DATA};
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::solana_program::system_instruction::MAX_PERMITTED_DATA_LENGTH;
+use anchor_lang::system_program::{self, CreateAccount, Transfer};
 
 declare_id!("FotEuL6PaHRDYuDmtqNrbbS52AwVX49MQSBjNwCWqRA4");
 
+pub mod bps;
+
+/// Number of entries an audit log holds before it must be rotated.
+pub const AUDIT_LOG_CAPACITY: usize = 64;
+/// Longest delegation chain a registry may be configured to follow.
+pub const MAX_DELEGATION_DEPTH: u8 = 8;
+/// Removed member IDs remembered for reuse; further removals retire their ID.
+pub const MAX_FREE_MEMBER_IDS: usize = 32;
+/// Longest legal name that fits a member account, after its length prefix.
+pub const MAX_LEGAL_NAME_LEN: usize = 256 - 4;
+/// Largest instruction data, discriminator included, `process_instruction`
+/// will pass on to Anchor.
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 1024;
+/// Most members a single snapshot account can record.
+pub const MAX_SNAPSHOT_MEMBERS: usize = 128;
+/// Longest model identifier an AI member may disclose.
+pub const MAX_MODEL_ID_LEN: usize = 64;
+/// Layout version written at the front of every `StateExport`.
+pub const STATE_EXPORT_VERSION: u8 = 1;
+/// Most pubkeys a single role can be granted to.
+pub const MAX_ROLE_MEMBERS: usize = 5;
+/// Longest profile metadata URI a member may record.
+pub const MAX_METADATA_URI_LEN: usize = 200;
+/// Most progressive discount tiers a registry can configure.
+pub const MAX_POWER_TIERS: usize = 4;
+/// Highest per-member vote rate limit a registry can configure.
+pub const MAX_VOTES_PER_WINDOW: usize = 16;
+/// Most keys a multisig-controlled member can list.
+pub const MAX_MEMBER_SIGNERS: usize = 5;
+
+/// The program's entrypoint. Rejects instruction data longer than
+/// `MAX_INSTRUCTION_DATA_LEN` with `InvalidInstruction` before Anchor
+/// dispatches or deserializes any of it, so oversized names, batches and
+/// URIs cost almost no compute. Everything else goes to Anchor's `entry`.
+pub fn process_instruction<'info>(
+    program_id: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    if data.len() > MAX_INSTRUCTION_DATA_LEN {
+        return Err(error!(MembershipError::InvalidInstruction).into());
+    }
+    entry(program_id, accounts, data)
+}
+
+// Anchor's generated entrypoint is compiled out by `custom-entrypoint`,
+// which implies `no-entrypoint`; this one replaces it on chain.
+#[cfg(all(feature = "custom-entrypoint", target_os = "solana"))]
+anchor_lang::solana_program::entrypoint!(process_instruction);
+
 #[program]
 pub mod membership {
     use super::*;
 
+    /// Creates the registry and its first audit log. Rent exemption comes from
+    /// `Rent::get()`, so no caller-supplied rent sysvar account is trusted.
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         let registry = &mut ctx.accounts.registry;
         registry.authority = ctx.accounts.authority.key();
         registry.member_count = 0;
+        registry.max_members = 0;
+        registry.governance = Pubkey::default();
+        registry.total_voting_power = 0;
+        registry.ai_voting_power = 0;
+        registry.human_voting_power = 0;
+        registry.active_member_count = 0;
+        registry.max_delegation_depth = 1;
+        registry.next_member_id = 0;
+        registry.reuse_ids = false;
+        registry.free_member_ids = Vec::new();
+        registry.treasury = Pubkey::default();
+        registry.registration_fee = 0;
+        registry.decay = DecayConfig::default();
+        registry.self_registration = false;
+        registry.default_voting_power = 0;
+        registry.roster_sealed = false;
+        registry.require_ai_sponsor = false;
+        registry.normalized_total = None;
+        registry.ai_power_multiplier_bps = bps::MAX_BPS;
+        registry.rent_buffer_lamports = 0;
+        registry.registration_nonce = 0;
+        registry.max_member_power = 0;
+        registry.require_model_id = false;
+        registry.voting_eligibility_delay_secs = 0;
+        registry.require_contact_hash = false;
+        registry.registration_admins = Vec::new();
+        registry.treasury_admins = Vec::new();
+        registry.config_admins = Vec::new();
+        registry.operational_cap = 0;
+        registry.financial_cap = 0;
+        registry.treasury_min_balance = 0;
+        registry.power_tiers = Vec::new();
+        registry.ai_member_count = 0;
+        registry.human_member_count = 0;
+        registry.require_human_majority_count = false;
+        registry.renounced_capabilities = 0;
+        registry.event_verbosity = EventVerbosity::All;
+        registry.dues_amount = 0;
+        registry.dues_period_secs = 0;
+        registry.unique_names = false;
+        registry.max_votes_per_window = 0;
+        registry.vote_window_slots = 0;
+        registry.agreement_hash = [0; 32];
+        registry.successor = Pubkey::default();
+        registry.authority_last_active_slot = Clock::get()?.slot;
+        registry.succession_timeout_slots = 0;
+        registry.min_deposit = 0;
+        registry.event_seq = 0;
+        registry.audit_log = ctx.accounts.audit_log.key();
+
+        let audit_log = &mut ctx.accounts.audit_log;
+        audit_log.registry = registry.key();
+        audit_log.previous = Pubkey::default();
+        audit_log.next_offset = 0;
+        audit_log.append(InstructionKind::Initialize, registry.authority, registry.key())?;
+        Ok(())
+    }
+
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::ConfigAdmin))]
+    pub fn rotate_audit_log(ctx: Context<RotateAuditLog>) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        let new_audit_log = &mut ctx.accounts.new_audit_log;
+
+        new_audit_log.registry = registry.key();
+        new_audit_log.previous = registry.audit_log;
+        new_audit_log.next_offset = 0;
+        new_audit_log.append(
+            InstructionKind::RotateAuditLog,
+            ctx.accounts.authority.key(),
+            registry.audit_log,
+        )?;
+
+        registry.audit_log = new_audit_log.key();
+        Ok(())
+    }
+
+    /// Records the governance program signer allowed to change registry config
+    /// when a passed proposal is executed.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::ConfigAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::SetGovernance)
+    )]
+    pub fn set_governance(ctx: Context<SetGovernance>, governance: Pubkey) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        registry.governance = governance;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetGovernance,
+            ctx.accounts.authority.key(),
+            governance,
+        )?;
+        Ok(())
+    }
+
+    /// Permanently closes the roster: no member can be added, applied or
+    /// approved afterwards. There is deliberately no way to unseal it.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::ConfigAdmin))]
+    pub fn seal_roster(ctx: Context<SealRoster>) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        registry.roster_sealed = true;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SealRoster,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Records the treasury that registration fees are paid into.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::TreasuryAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::SetTreasury)
+    )]
+    pub fn set_treasury(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        registry.treasury = treasury;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetTreasury,
+            ctx.accounts.authority.key(),
+            treasury,
+        )?;
+        Ok(())
+    }
+
+    /// Records the hash of the operating agreement every new member must
+    /// acknowledge. All zero stops requiring an acknowledgement; members
+    /// already registered keep the hash they acknowledged.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::ConfigAdmin))]
+    pub fn set_agreement_hash(ctx: Context<SetAgreementHash>, agreement_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        registry.agreement_hash = agreement_hash;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetAgreementHash,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Hands the registry to a new operator in one step: the authority moves
+    /// to `new_authority`, the treasury role to `new_treasury_admin` alone,
+    /// and the previous authority loses any registration or config admin
+    /// entry it held. Only the current authority may do this. The
+    /// governance program keeps its own `Dao.authority`, which this does
+    /// not change.
+    #[access_control(
+        assert_authority(&ctx.accounts.authority, &ctx.accounts.registry)
+        assert_capability(&ctx.accounts.registry, Capability::ManageRoles)
+    )]
+    pub fn reassign_control(
+        ctx: Context<ReassignControl>,
+        new_authority: Pubkey,
+        new_treasury_admin: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        require!(
+            new_authority != Pubkey::default() && new_treasury_admin != Pubkey::default(),
+            MembershipError::InvalidConfigValue
+        );
+        let registry = &mut ctx.accounts.registry;
+        let previous_authority = registry.authority;
+        registry.authority = new_authority;
+        registry.authority_last_active_slot = Clock::get()?.slot;
+        registry.treasury_admins = vec![new_treasury_admin];
+        registry.registration_admins.retain(|admin| *admin != previous_authority);
+        registry.config_admins.retain(|admin| *admin != previous_authority);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::ReassignControl,
+            previous_authority,
+            new_authority,
+        )?;
+        if registry.logs(EventVerbosity::All) {
+            emit!(ControlReassigned {
+                registry: registry.key(),
+                previous_authority,
+                new_authority,
+                treasury_admin: new_treasury_admin,
+                sequence: registry.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Names the key that may take over as authority once the authority has
+    /// been inactive for `succession_timeout_slots`, or clears it with the
+    /// default pubkey. Only the authority may do this.
+    #[access_control(assert_authority(&ctx.accounts.authority, &ctx.accounts.registry))]
+    pub fn set_successor(ctx: Context<SetSuccessor>, successor: Pubkey) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        registry.successor = successor;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetSuccessor,
+            ctx.accounts.authority.key(),
+            successor,
+        )?;
+        Ok(())
+    }
+
+    /// Makes the successor the authority once the authority has taken no
+    /// action for `succession_timeout_slots`. The succession is then spent:
+    /// the new authority names its own successor if it wants one. Roles
+    /// granted by the old authority are left as they are.
+    pub fn claim_authority(ctx: Context<ClaimAuthority>) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let slot = Clock::get()?.slot;
+        require!(
+            registry.succession_timeout_slots != 0
+                && slot
+                    >= registry
+                        .authority_last_active_slot
+                        .saturating_add(registry.succession_timeout_slots),
+            MembershipError::SuccessionNotDue
+        );
+
+        let previous_authority = registry.authority;
+        registry.authority = registry.successor;
+        registry.successor = Pubkey::default();
+        registry.authority_last_active_slot = slot;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::ClaimAuthority,
+            previous_authority,
+            registry.authority,
+        )?;
+        if registry.logs(EventVerbosity::All) {
+            emit!(AuthorityClaimed {
+                registry: registry.key(),
+                previous_authority,
+                new_authority: registry.authority,
+                sequence: registry.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Permanently gives up `capability`: every instruction that uses it
+    /// fails from now on, whoever signs, governance included. There is no
+    /// way to restore a renounced capability.
+    #[access_control(assert_authority(&ctx.accounts.authority, &ctx.accounts.registry))]
+    pub fn renounce_capability(ctx: Context<RenounceCapability>, capability: Capability) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        registry.renounced_capabilities |= capability.bit();
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::RenounceCapability,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the pubkeys holding `role`. The authority holds every role
+    /// regardless, so an empty list leaves the role to the authority alone.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::ConfigAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::ManageRoles)
+    )]
+    pub fn set_role_members(ctx: Context<SetRoleMembers>, role: Role, pubkeys: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        require!(pubkeys.len() <= MAX_ROLE_MEMBERS, MembershipError::TooManyRoleMembers);
+        let registry = &mut ctx.accounts.registry;
+        *registry.role_members_mut(&role) = pubkeys;
+        debug_assert_fits(&**registry, MemberRegistry::MAX_ACCOUNT_SIZE);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetRoleMembers,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the progressive tiers that discount large holdings; see
+    /// `MemberRegistry::effective_power_tiered`. Tiers must start at strictly
+    /// increasing, non-zero power levels. An empty list turns tiering off.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::ConfigAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::UpdateConfig)
+    )]
+    pub fn set_power_tiers(ctx: Context<SetPowerTiers>, tiers: Vec<PowerTier>) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        require!(tiers.len() <= MAX_POWER_TIERS, MembershipError::InvalidConfigValue);
+        for (i, tier) in tiers.iter().enumerate() {
+            let previous = if i == 0 { 0 } else { tiers[i - 1].from };
+            require!(
+                tier.from > previous && tier.bps <= bps::MAX_BPS,
+                MembershipError::InvalidConfigValue
+            );
+        }
+        let registry = &mut ctx.accounts.registry;
+        registry.power_tiers = tiers;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetPowerTiers,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    #[access_control(
+        assert_config_signer(&ctx.accounts.signer, &ctx.accounts.registry)
+        assert_capability(&ctx.accounts.registry, Capability::UpdateConfig)
+    )]
+    pub fn update_config(ctx: Context<UpdateConfig>, field: RegistryField, value: u64) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.signer.key())?;
+        let registry = &mut ctx.accounts.registry;
+
+        match field {
+            RegistryField::MaxMembers => registry.max_members = value,
+            RegistryField::MaxDelegationDepth => {
+                require!(
+                    (1..=MAX_DELEGATION_DEPTH as u64).contains(&value),
+                    MembershipError::InvalidConfigValue
+                );
+                registry.max_delegation_depth = value as u8;
+            }
+            RegistryField::ReuseIds => registry.reuse_ids = value != 0,
+            RegistryField::RegistrationFee => registry.registration_fee = value,
+            RegistryField::DecayGraceSlots => registry.decay.grace_slots = value,
+            RegistryField::DecayPeriodSlots => registry.decay.period_slots = value,
+            RegistryField::DecayBpsPerPeriod => {
+                require!(value <= bps::MAX_BPS as u64, MembershipError::InvalidConfigValue);
+                registry.decay.bps_per_period = value as u16;
+            }
+            RegistryField::SelfRegistration => registry.self_registration = value != 0,
+            RegistryField::DefaultVotingPower => registry.default_voting_power = value,
+            RegistryField::RequireAiSponsor => registry.require_ai_sponsor = value != 0,
+            RegistryField::RentBufferLamports => registry.rent_buffer_lamports = value,
+            RegistryField::MaxMemberPower => registry.max_member_power = value,
+            RegistryField::RequireModelId => registry.require_model_id = value != 0,
+            RegistryField::RequireContactHash => registry.require_contact_hash = value != 0,
+            RegistryField::OperationalCap => registry.operational_cap = value,
+            RegistryField::FinancialCap => registry.financial_cap = value,
+            RegistryField::TreasuryMinBalance => registry.treasury_min_balance = value,
+            RegistryField::MinDeposit => registry.min_deposit = value,
+            RegistryField::RequireHumanMajorityCount => {
+                registry.require_human_majority_count = value != 0
+            }
+            RegistryField::VotingEligibilityDelaySecs => {
+                require!(value <= i64::MAX as u64, MembershipError::InvalidConfigValue);
+                registry.voting_eligibility_delay_secs = value as i64;
+            }
+            RegistryField::AiPowerMultiplierBps => {
+                require!(value <= bps::MAX_BPS as u64, MembershipError::InvalidConfigValue);
+                registry.ai_power_multiplier_bps = value as u16;
+            }
+            RegistryField::UniqueNames => registry.unique_names = value != 0,
+            RegistryField::SuccessionTimeoutSlots => registry.succession_timeout_slots = value,
+            RegistryField::MaxVotesPerWindow => {
+                require!(
+                    value <= MAX_VOTES_PER_WINDOW as u64,
+                    MembershipError::InvalidConfigValue
+                );
+                registry.max_votes_per_window = value as u8;
+            }
+            RegistryField::VoteWindowSlots => registry.vote_window_slots = value,
+            RegistryField::DuesAmount => registry.dues_amount = value,
+            RegistryField::DuesPeriodSecs => {
+                require!(value <= i64::MAX as u64, MembershipError::InvalidConfigValue);
+                registry.dues_period_secs = value as i64;
+            }
+            RegistryField::EventVerbosity => {
+                registry.event_verbosity = match value {
+                    0 => EventVerbosity::Off,
+                    1 => EventVerbosity::Errors,
+                    2 => EventVerbosity::All,
+                    _ => return err!(MembershipError::InvalidConfigValue),
+                }
+            }
+            RegistryField::NormalizedTotal => {
+                require!(
+                    value == 0 || value >= registry.total_voting_power,
+                    MembershipError::InvalidConfigValue
+                );
+                registry.normalized_total = (value != 0).then_some(value);
+            }
+        }
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::UpdateConfig,
+            ctx.accounts.signer.key(),
+            registry.key(),
+        )?;
         Ok(())
     }
 
+    /// Registers a member. `nonce` must equal the registry's current
+    /// `registration_nonce`, so a signed registration cannot be replayed.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::AddMembers)
+    )]
     pub fn add_member(
         ctx: Context<AddMember>,
         member_type: MemberType,
@@ -20,10 +488,67 @@ pub mod membership {
         legal_name: String,
         address: String,
         tax_id: String,
+        nonce: u64,
+        model_id: String,
+        contact_hash: [u8; 32],
+        acknowledged_hash: [u8; 32],
     ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
         let registry = &mut ctx.accounts.registry;
         let member = &mut ctx.accounts.member;
 
+        let entry = NewMember {
+            pubkey: ctx.accounts.member_pubkey.key(),
+            member_type,
+            voting_power,
+            legal_name,
+            address,
+            tax_id,
+            model_id,
+            contact_hash,
+            acknowledged_hash,
+        };
+        let name_taken = name_taken(registry, &entry.legal_name, ctx.accounts.name_entry.as_deref());
+        registry
+            .check_registration(
+                &entry,
+                Some(nonce),
+                ctx.accounts.authority.lamports(),
+                false,
+                name_taken,
+            )
+            .into_result()?;
+        registry.use_registration_nonce(nonce)?;
+        let NewMember {
+            member_type,
+            legal_name,
+            address,
+            tax_id,
+            model_id,
+            ..
+        } = entry;
+        claim_name(
+            registry,
+            &legal_name,
+            member.key(),
+            ctx.accounts.name_entry.as_deref(),
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
+
+        charge_registration_fee(
+            registry.registration_fee,
+            &ctx.accounts.authority,
+            &ctx.accounts.treasury,
+            &ctx.accounts.system_program,
+        )?;
+        fund_rent_buffer(
+            registry.rent_buffer_lamports,
+            &ctx.accounts.authority,
+            &member.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
         member.pubkey = ctx.accounts.member_pubkey.key();
         member.member_type = member_type;
         member.voting_power = voting_power;
@@ -34,58 +559,3829 @@ pub mod membership {
         member.address = address;
         member.tax_id = tax_id;
         member.kyc_verified = false; // Requires separate verification process
+        member.pending = false;
+        member.created_by = ctx.accounts.authority.key();
+        member.created_at = member.joined_at;
+        member.sponsor = None;
+        member.parent = None;
+        member.sub_agent_power = 0;
+        member.under_dispute = false;
+        member.votes_cast = 0;
+        member.proposals_eligible = 0;
+        member.eligible_from = None;
+        member.model_id = model_id;
+        member.contact_hash = contact_hash;
+        member.agreement_hash = acknowledged_hash;
+        member.vesting_start = 0;
+        member.vesting_duration = 0;
+        member.vesting_total = 0;
+        member.metadata_uri = String::new();
+        member.dues_paid_through = registry.dues_due_from(member.joined_at);
+        member.dues_lapsed = false;
+        member.endorsement_count = 0;
+        member.recent_vote_slots = Vec::new();
+        member.multisig_signers = Vec::new();
+        member.multisig_threshold = 0;
+        member.eth_address = [0; 20];
+        member.registry = registry.key();
+        member.delegate = None;
+        member.last_active_slot = Clock::get()?.slot;
+        member.id = registry.assign_member_id();
 
         registry.member_count += 1;
+        registry.track_active(&member.member_type, voting_power);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::AddMember,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
 
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(init, payer = authority, space = 8 + 32 + 8)]
-    pub registry: Account<'info, MemberRegistry>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+    /// First half of a two-phase registration: hands out the next member ID
+    /// and records it in a fresh reservation account, returning the ID via
+    /// return data. `commit_member` then creates the member at that ID. A
+    /// reservation that is never committed leaves a gap in the roster.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::AddMembers)
+    )]
+    pub fn reserve_member_id(ctx: Context<ReserveMemberId>) -> Result<u64> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        require!(!registry.roster_sealed, MembershipError::RosterSealed);
 
-#[derive(Accounts)]
-pub struct AddMember<'info> {
-    #[account(mut)]
-    pub registry: Account<'info, MemberRegistry>,
-    #[account(init, payer = authority, space = 8 + 32 + 1 + 8 + 8 + 1 + 256 + 512 + 64 + 1)]
-    pub member: Account<'info, Member>,
-    /// CHECK: Member pubkey is validated by the program logic
-    pub member_pubkey: AccountInfo<'info>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
+        let reservation = &mut ctx.accounts.reservation;
+        reservation.registry = registry.key();
+        reservation.id = registry.assign_member_id();
+        reservation.reserved_by = ctx.accounts.authority.key();
 
-#[account]
-pub struct MemberRegistry {
-    pub authority: Pubkey,
-    pub member_count: u64,
-}
+        ctx.accounts.audit_log.append(
+            InstructionKind::ReserveMemberId,
+            ctx.accounts.authority.key(),
+            reservation.key(),
+        )?;
+        Ok(reservation.id)
+    }
 
-#[account]
-pub struct Member {
-    pub pubkey: Pubkey,
-    pub member_type: MemberType,
-    pub voting_power: u64,
-    pub joined_at: i64,
-    pub is_active: bool,
-    // Wyoming DAO LLC Compliance Fields
-    pub legal_name: String,
-    pub address: String,
-    pub tax_id: String, // SSN for individuals, EIN for entities
-    pub kyc_verified: bool,
-}
+    /// Second half of a two-phase registration: creates the member at
+    /// `reserved_id` and closes the reservation, refunding its rent. The
+    /// reservation is single-use, so no registration nonce is needed.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::AddMembers)
+    )]
+    pub fn commit_member(ctx: Context<CommitMember>, reserved_id: u64, entry: NewMember) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        require!(
+            ctx.accounts.reservation.id == reserved_id,
+            MembershipError::InvalidReservation
+        );
+        let name_taken = name_taken(registry, &entry.legal_name, ctx.accounts.name_entry.as_deref());
+        registry
+            .check_registration(&entry, None, ctx.accounts.authority.lamports(), false, name_taken)
+            .into_result()?;
+        claim_name(
+            registry,
+            &entry.legal_name,
+            ctx.accounts.member.key(),
+            ctx.accounts.name_entry.as_deref(),
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub enum MemberType {
-    Human,
-    AI,
-    Organization,
+        charge_registration_fee(
+            registry.registration_fee,
+            &ctx.accounts.authority,
+            &ctx.accounts.treasury,
+            &ctx.accounts.system_program,
+        )?;
+        fund_rent_buffer(
+            registry.rent_buffer_lamports,
+            &ctx.accounts.authority,
+            &ctx.accounts.member.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.member.set_inner(Member {
+            pubkey: entry.pubkey,
+            member_type: entry.member_type,
+            voting_power: entry.voting_power,
+            joined_at: clock.unix_timestamp,
+            is_active: true,
+            legal_name: entry.legal_name,
+            address: entry.address,
+            tax_id: entry.tax_id,
+            kyc_verified: false,
+            registry: registry.key(),
+            delegate: None,
+            id: reserved_id,
+            last_active_slot: clock.slot,
+            pending: false,
+            created_by: ctx.accounts.authority.key(),
+            created_at: clock.unix_timestamp,
+            sponsor: None,
+            parent: None,
+            sub_agent_power: 0,
+            under_dispute: false,
+            votes_cast: 0,
+            proposals_eligible: 0,
+            eligible_from: None,
+            model_id: entry.model_id,
+            contact_hash: entry.contact_hash,
+            agreement_hash: entry.acknowledged_hash,
+            vesting_start: 0,
+            vesting_duration: 0,
+            vesting_total: 0,
+            metadata_uri: String::new(),
+            dues_paid_through: registry.dues_due_from(clock.unix_timestamp),
+            dues_lapsed: false,
+            endorsement_count: 0,
+            recent_vote_slots: Vec::new(),
+            multisig_signers: Vec::new(),
+            multisig_threshold: 0,
+            eth_address: [0; 20],
+        });
+
+        let member = &ctx.accounts.member;
+        registry.member_count += 1;
+        registry.track_active(&member.member_type, member.voting_power);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::AddMember,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Opens an import of a roster manifest whose Merkle root is
+    /// `manifest_root`, as computed by `manifest_leaf` and
+    /// `verify_merkle_proof`. Signing this transaction is the authority's
+    /// endorsement of the manifest. Manifest position `i` becomes member ID
+    /// `i`, so the registry must not have handed out any ID yet; IDs
+    /// 0..entry_count are set aside for the import straight away.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::AddMembers)
+    )]
+    pub fn begin_roster_import(
+        ctx: Context<BeginRosterImport>,
+        manifest_root: [u8; 32],
+        entry_count: u64,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        require!(!registry.roster_sealed, MembershipError::RosterSealed);
+        require!(registry.next_member_id == 0, MembershipError::RegistryNotEmpty);
+        registry.next_member_id = entry_count;
+
+        let import = &mut ctx.accounts.roster_import;
+        import.registry = registry.key();
+        import.manifest_root = manifest_root;
+        import.entry_count = entry_count;
+        import.cursor = 0;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::BeginRosterImport,
+            ctx.accounts.authority.key(),
+            import.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Writes manifest entries `start..start + entries.len()` of an open
+    /// import, each with its Merkle proof. Member accounts are the PDAs
+    /// `[b"member", registry, id]`, passed in the same order in
+    /// `remaining_accounts`. Entries whose account already exists are
+    /// skipped, so a chunk can safely be resubmitted. `start` may not lie
+    /// past the import's cursor, which records how far the manifest has
+    /// been written. While names must be unique, each entry's name entry
+    /// follows all the member accounts, in the same order. Anyone may
+    /// submit; `payer` funds the new accounts.
+    pub fn import_roster<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ImportRoster<'info>>,
+        start: u64,
+        entries: Vec<NewMember>,
+        proofs: Vec<Vec<[u8; 32]>>,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let import = &mut ctx.accounts.roster_import;
+        let name_count = if registry.unique_names { entries.len() } else { 0 };
+        require!(
+            ctx.remaining_accounts.len() == entries.len() + name_count
+                && proofs.len() == entries.len(),
+            MembershipError::InvalidMemberAccount
+        );
+        let (members, name_entries) = ctx.remaining_accounts.split_at(entries.len());
+
+        assert_capability(registry, Capability::AddMembers)?;
+        require!(start <= import.cursor, MembershipError::InvalidManifestEntry);
+        require!(
+            start + entries.len() as u64 <= import.entry_count,
+            MembershipError::InvalidManifestEntry
+        );
+
+        let clock = Clock::get()?;
+        let rent = Rent::get()?
+            .minimum_balance(Member::MAX_ACCOUNT_SIZE)
+            .checked_add(registry.rent_buffer_lamports)
+            .ok_or(MembershipError::InsufficientFunds)?;
+        let registry_key = registry.key();
+        for (i, (((id, account), entry), proof)) in
+            (start..).zip(members).zip(entries).zip(proofs).enumerate()
+        {
+            require!(
+                verify_merkle_proof(&import.manifest_root, manifest_leaf(id, &entry)?, &proof),
+                MembershipError::InvalidManifestEntry
+            );
+            let id_bytes = id.to_le_bytes();
+            let (address, bump) =
+                Pubkey::find_program_address(&[b"member", registry_key.as_ref(), &id_bytes], &crate::ID);
+            require_keys_eq!(account.key(), address, MembershipError::InvalidMemberAccount);
+            if account.owner == &crate::ID && !account.data_is_empty() {
+                continue;
+            }
+
+            // Imported members were paid for in the source registry, so no
+            // registration fee is charged and the funds check always passes.
+            let name_taken = name_taken(registry, &entry.legal_name, name_entries.get(i));
+            registry
+                .check_registration(&entry, None, u64::MAX, false, name_taken)
+                .into_result()?;
+            claim_name(
+                registry,
+                &entry.legal_name,
+                account.key(),
+                name_entries.get(i),
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+            )?;
+
+            system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    CreateAccount {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account.clone(),
+                    },
+                    &[&[b"member", registry_key.as_ref(), &id_bytes, &[bump]]],
+                ),
+                rent,
+                Member::MAX_ACCOUNT_SIZE as u64,
+                &crate::ID,
+            )?;
+
+            let member = Member {
+                pubkey: entry.pubkey,
+                member_type: entry.member_type,
+                voting_power: entry.voting_power,
+                joined_at: clock.unix_timestamp,
+                is_active: true,
+                legal_name: entry.legal_name,
+                address: entry.address,
+                tax_id: entry.tax_id,
+                kyc_verified: false,
+                registry: registry_key,
+                delegate: None,
+                id,
+                last_active_slot: clock.slot,
+                pending: false,
+                created_by: ctx.accounts.payer.key(),
+                created_at: clock.unix_timestamp,
+                sponsor: None,
+                parent: None,
+                sub_agent_power: 0,
+                under_dispute: false,
+                votes_cast: 0,
+                proposals_eligible: 0,
+                eligible_from: None,
+                model_id: entry.model_id,
+                contact_hash: entry.contact_hash,
+                agreement_hash: entry.acknowledged_hash,
+                vesting_start: 0,
+                vesting_duration: 0,
+                vesting_total: 0,
+                metadata_uri: String::new(),
+                dues_paid_through: registry.dues_due_from(clock.unix_timestamp),
+                dues_lapsed: false,
+                endorsement_count: 0,
+                recent_vote_slots: Vec::new(),
+                multisig_signers: Vec::new(),
+                multisig_threshold: 0,
+                eth_address: [0; 20],
+            };
+            member.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+            registry.member_count += 1;
+            registry.track_active(&member.member_type, member.voting_power);
+
+            ctx.accounts.audit_log.append(
+                InstructionKind::AddMember,
+                ctx.accounts.payer.key(),
+                account.key(),
+            )?;
+        }
+
+        import.cursor = import.cursor.max(start + members.len() as u64);
+        Ok(())
+    }
+
+    /// Lets a human member register an AI agent under them. The power of all
+    /// of a parent's sub-agents together may not exceed the parent's own.
+    pub fn register_sub_agent(
+        ctx: Context<RegisterSubAgent>,
+        voting_power: u64,
+        legal_name: String,
+        address: String,
+        tax_id: String,
+        model_id: String,
+        contact_hash: [u8; 32],
+        acknowledged_hash: [u8; 32],
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let parent = &mut ctx.accounts.parent;
+        let member = &mut ctx.accounts.member;
+
+        let entry = NewMember {
+            pubkey: ctx.accounts.member_pubkey.key(),
+            member_type: MemberType::AI,
+            voting_power,
+            legal_name,
+            address,
+            tax_id,
+            model_id,
+            contact_hash,
+            acknowledged_hash,
+        };
+        let name_taken = name_taken(registry, &entry.legal_name, ctx.accounts.name_entry.as_deref());
+        registry
+            .check_registration(&entry, None, ctx.accounts.owner.lamports(), false, name_taken)
+            .into_result()?;
+        claim_name(
+            registry,
+            &entry.legal_name,
+            member.key(),
+            ctx.accounts.name_entry.as_deref(),
+            &ctx.accounts.owner,
+            &ctx.accounts.system_program,
+        )?;
+
+        charge_registration_fee(
+            registry.registration_fee,
+            &ctx.accounts.owner,
+            &ctx.accounts.treasury,
+            &ctx.accounts.system_program,
+        )?;
+        fund_rent_buffer(
+            registry.rent_buffer_lamports,
+            &ctx.accounts.owner,
+            &member.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        let clock = Clock::get()?;
+        member.pubkey = entry.pubkey;
+        member.member_type = entry.member_type;
+        member.voting_power = voting_power;
+        member.joined_at = clock.unix_timestamp;
+        member.is_active = true;
+        member.legal_name = entry.legal_name;
+        member.address = entry.address;
+        member.tax_id = entry.tax_id;
+        member.kyc_verified = false;
+        member.pending = false;
+        member.created_by = ctx.accounts.owner.key();
+        member.created_at = clock.unix_timestamp;
+        member.sponsor = None;
+        member.parent = Some(parent.id);
+        member.sub_agent_power = 0;
+        member.under_dispute = false;
+        member.votes_cast = 0;
+        member.proposals_eligible = 0;
+        member.eligible_from = None;
+        member.model_id = entry.model_id;
+        member.contact_hash = entry.contact_hash;
+        member.agreement_hash = entry.acknowledged_hash;
+        member.vesting_start = 0;
+        member.vesting_duration = 0;
+        member.vesting_total = 0;
+        member.metadata_uri = String::new();
+        member.dues_paid_through = registry.dues_due_from(clock.unix_timestamp);
+        member.dues_lapsed = false;
+        member.endorsement_count = 0;
+        member.recent_vote_slots = Vec::new();
+        member.multisig_signers = Vec::new();
+        member.multisig_threshold = 0;
+        member.eth_address = [0; 20];
+        member.registry = registry.key();
+        member.delegate = None;
+        member.last_active_slot = clock.slot;
+        member.id = registry.assign_member_id();
+
+        rebalance_sub_agent(member, Some(parent), 0, voting_power)?;
+
+        registry.member_count += 1;
+        registry.track_active(&member.member_type, voting_power);
+        Ok(())
+    }
+
+    /// Registers several members in one transaction. Each entry's member
+    /// account is a fresh keypair passed, signed, in `remaining_accounts`
+    /// after the fixed accounts. Accounts are created and given IDs strictly
+    /// in that order, so `entries[i]` always lands in the i-th account and
+    /// receives the i-th ID handed out. While names must be unique, each
+    /// entry's name entry follows all the member accounts, in the same
+    /// order. The whole batch uses one `nonce`.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::AddMembers)
+    )]
+    pub fn add_members_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddMembersBatch<'info>>,
+        entries: Vec<NewMember>,
+        nonce: u64,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        // `process_instruction` has already bounded the encoded batch by
+        // `MAX_INSTRUCTION_DATA_LEN`.
+
+        registry.use_registration_nonce(nonce)?;
+
+        let name_count = if registry.unique_names { entries.len() } else { 0 };
+        require!(
+            ctx.remaining_accounts.len() == entries.len() + name_count,
+            MembershipError::InvalidMemberAccount
+        );
+        let (members, name_entries) = ctx.remaining_accounts.split_at(entries.len());
+        for (i, account) in members.iter().enumerate() {
+            require!(
+                account.key() != registry.key() && account.is_signer && account.is_writable,
+                MembershipError::InvalidMemberAccount
+            );
+            require!(
+                !members[..i].iter().any(|other| other.key() == account.key()),
+                MembershipError::MemberAlreadyExists
+            );
+        }
+        require!(!registry.roster_sealed, MembershipError::RosterSealed);
+        require!(
+            registry.max_members == 0
+                || registry.member_count + entries.len() as u64 <= registry.max_members,
+            MembershipError::MaxMembersReached
+        );
+
+        let total_fee = registry
+            .registration_fee
+            .checked_mul(entries.len() as u64)
+            .ok_or(MembershipError::InsufficientFunds)?;
+        charge_registration_fee(
+            total_fee,
+            &ctx.accounts.authority,
+            &ctx.accounts.treasury,
+            &ctx.accounts.system_program,
+        )?;
+
+        let clock = Clock::get()?;
+        let rent = Rent::get()?
+            .minimum_balance(Member::MAX_ACCOUNT_SIZE)
+            .checked_add(registry.rent_buffer_lamports)
+            .ok_or(MembershipError::InsufficientFunds)?;
+        for (i, (account, entry)) in members.iter().zip(entries).enumerate() {
+            // The batch's fees were charged together above, so the funds
+            // check always passes. Its one nonce was consumed up front.
+            let name_taken = name_taken(registry, &entry.legal_name, name_entries.get(i));
+            registry
+                .check_registration(&entry, None, u64::MAX, account_in_use(account), name_taken)
+                .into_result()?;
+            claim_name(
+                registry,
+                &entry.legal_name,
+                account.key(),
+                name_entries.get(i),
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+            )?;
+            system_program::create_account(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    CreateAccount {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                rent,
+                Member::MAX_ACCOUNT_SIZE as u64,
+                &crate::ID,
+            )?;
+
+            let member = Member {
+                pubkey: entry.pubkey,
+                member_type: entry.member_type,
+                voting_power: entry.voting_power,
+                joined_at: clock.unix_timestamp,
+                is_active: true,
+                legal_name: entry.legal_name,
+                address: entry.address,
+                tax_id: entry.tax_id,
+                kyc_verified: false,
+                registry: registry.key(),
+                delegate: None,
+                id: registry.assign_member_id(),
+                last_active_slot: clock.slot,
+                pending: false,
+                created_by: ctx.accounts.authority.key(),
+                created_at: clock.unix_timestamp,
+                sponsor: None,
+                parent: None,
+                sub_agent_power: 0,
+                under_dispute: false,
+                votes_cast: 0,
+                proposals_eligible: 0,
+                eligible_from: None,
+                model_id: entry.model_id,
+                contact_hash: entry.contact_hash,
+                agreement_hash: entry.acknowledged_hash,
+                vesting_start: 0,
+                vesting_duration: 0,
+                vesting_total: 0,
+                metadata_uri: String::new(),
+                dues_paid_through: registry.dues_due_from(clock.unix_timestamp),
+                dues_lapsed: false,
+                endorsement_count: 0,
+                recent_vote_slots: Vec::new(),
+                multisig_signers: Vec::new(),
+                multisig_threshold: 0,
+                eth_address: [0; 20],
+            };
+            member.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+            registry.member_count += 1;
+            registry.track_active(&member.member_type, member.voting_power);
+
+            ctx.accounts.audit_log.append(
+                InstructionKind::AddMember,
+                ctx.accounts.authority.key(),
+                account.key(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Lets anyone apply for membership while self-registration is enabled.
+    /// The application is inactive and holds no power or ID until the
+    /// authority approves it; rejecting it is a `remove_member`.
+    pub fn apply_for_membership(
+        ctx: Context<ApplyForMembership>,
+        member_type: MemberType,
+        legal_name: String,
+        address: String,
+        tax_id: String,
+        model_id: String,
+        contact_hash: [u8; 32],
+        acknowledged_hash: [u8; 32],
+    ) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        require!(!registry.roster_sealed, MembershipError::RosterSealed);
+        require!(registry.self_registration, MembershipError::SelfRegistrationDisabled);
+        registry.check_model_id(&member_type, &model_id)?;
+        registry.check_contact_hash(&contact_hash)?;
+        registry.check_agreement(&acknowledged_hash)?;
+        claim_name(
+            registry,
+            &legal_name,
+            ctx.accounts.member.key(),
+            ctx.accounts.name_entry.as_deref(),
+            &ctx.accounts.applicant,
+            &ctx.accounts.system_program,
+        )?;
+
+        charge_registration_fee(
+            registry.registration_fee,
+            &ctx.accounts.applicant,
+            &ctx.accounts.treasury,
+            &ctx.accounts.system_program,
+        )?;
+        fund_rent_buffer(
+            registry.rent_buffer_lamports,
+            &ctx.accounts.applicant,
+            &ctx.accounts.member.to_account_info(),
+            &ctx.accounts.system_program,
+        )?;
+
+        let member = &mut ctx.accounts.member;
+        member.pubkey = ctx.accounts.applicant.key();
+        member.member_type = member_type;
+        member.voting_power = 0;
+        member.joined_at = 0;
+        member.is_active = false;
+        member.legal_name = legal_name;
+        member.address = address;
+        member.tax_id = tax_id;
+        member.kyc_verified = false;
+        member.registry = registry.key();
+        member.delegate = None;
+        member.id = 0;
+        member.last_active_slot = 0;
+        member.pending = true;
+        member.created_by = ctx.accounts.applicant.key();
+        member.created_at = Clock::get()?.unix_timestamp;
+        member.sponsor = None;
+        member.parent = None;
+        member.sub_agent_power = 0;
+        member.under_dispute = false;
+        member.votes_cast = 0;
+        member.proposals_eligible = 0;
+        member.eligible_from = None;
+        member.model_id = model_id;
+        member.contact_hash = contact_hash;
+        member.agreement_hash = acknowledged_hash;
+        member.vesting_start = 0;
+        member.vesting_duration = 0;
+        member.vesting_total = 0;
+        member.metadata_uri = String::new();
+        member.dues_paid_through = 0; // set on approval
+        member.dues_lapsed = false;
+        member.endorsement_count = 0;
+        member.recent_vote_slots = Vec::new();
+        member.multisig_signers = Vec::new();
+        member.multisig_threshold = 0;
+        member.eth_address = [0; 20];
+        Ok(())
+    }
+
+    /// Admits a pending applicant with `voting_power`, or the registry's
+    /// `default_voting_power` when none is given.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::AddMembers)
+    )]
+    pub fn approve_membership(
+        ctx: Context<ApproveMembership>,
+        voting_power: Option<u64>,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        let member = &mut ctx.accounts.member;
+
+        require!(!registry.roster_sealed, MembershipError::RosterSealed);
+        require!(
+            registry.max_members == 0 || registry.member_count < registry.max_members,
+            MembershipError::MaxMembersReached
+        );
+
+        let clock = Clock::get()?;
+        member.pending = false;
+        member.is_active = true;
+        member.voting_power = voting_power.unwrap_or(registry.default_voting_power);
+        member.joined_at = clock.unix_timestamp;
+        member.last_active_slot = clock.slot;
+        member.dues_paid_through = registry.dues_due_from(clock.unix_timestamp);
+        member.id = registry.assign_member_id();
+
+        registry.check_human_majority(&member.member_type)?;
+        registry.check_member_power(member.voting_power)?;
+        registry.check_normalized_total(member.voting_power)?;
+        registry.member_count += 1;
+        registry.track_active(&member.member_type, member.voting_power);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::ApproveMembership,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Applies a signed change to a member's voting power, keeping the
+    /// registry aggregates in step.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::UpdateVotingPower)
+    )]
+    pub fn adjust_voting_power(ctx: Context<AdjustVotingPower>, delta: i64) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        let member = &mut ctx.accounts.member;
+
+        let old_power = member.voting_power;
+        let new_power = if delta >= 0 {
+            old_power
+                .checked_add(delta as u64)
+                .ok_or(MembershipError::PowerOverflow)?
+        } else {
+            old_power
+                .checked_sub(delta.unsigned_abs())
+                .ok_or(MembershipError::PowerUnderflow)?
+        };
+        require!(
+            new_power >= member.sub_agent_power,
+            MembershipError::SubAgentPowerExceeded
+        );
+        rebalance_sub_agent(member, ctx.accounts.parent.as_mut(), old_power, new_power)?;
+        registry.check_member_power(new_power)?;
+
+        if member.is_active {
+            let others = registry
+                .total_voting_power
+                .checked_sub(old_power)
+                .ok_or(MembershipError::AggregateMismatch)?;
+            require!(others.checked_add(new_power).is_some(), MembershipError::PowerOverflow);
+            registry.check_normalized_total(new_power.saturating_sub(old_power))?;
+            registry.untrack_active(&member.member_type, old_power);
+            registry.track_active(&member.member_type, new_power);
+        }
+        member.voting_power = new_power;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::AdjustVotingPower,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Moves `amount` of voting power from one active member to another. The
+    /// registry total is unchanged; only the AI/human split can shift. Either
+    /// the `from` member or a registration admin must sign. Sub-agents are
+    /// excluded, since their power is accounted against their parent. Only
+    /// power that has vested can move; the locked share stays with `from`.
+    pub fn transfer_voting_power(
+        ctx: Context<TransferVotingPower>,
+        from_id: u64,
+        to_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let from = &mut ctx.accounts.from;
+        let to = &mut ctx.accounts.to;
+        let signer = ctx.accounts.signer.key();
+
+        require!(
+            from.id == from_id && to.id == to_id && from_id != to_id,
+            MembershipError::InvalidMemberAccount
+        );
+        check_signer_as(
+            &ctx.accounts.signer,
+            registry,
+            signer == from.pubkey || registry.has_role(&signer, &Role::RegistrationAdmin),
+        )?;
+        assert_capability(registry, Capability::UpdateVotingPower)?;
+        require!(
+            from.is_active && to.is_active && from.parent.is_none() && to.parent.is_none(),
+            MembershipError::InvalidMemberAccount
+        );
+        require!(!from.under_dispute, MembershipError::MemberUnderDispute);
+
+        let from_power = from
+            .voting_power
+            .checked_sub(amount)
+            .ok_or(MembershipError::PowerUnderflow)?;
+        let now = Clock::get()?.unix_timestamp;
+        restart_vesting(from, now);
+        require!(amount <= vested_power(from, now), MembershipError::PowerStillVesting);
+        require!(
+            from_power >= from.sub_agent_power,
+            MembershipError::SubAgentPowerExceeded
+        );
+        let to_power = to
+            .voting_power
+            .checked_add(amount)
+            .ok_or(MembershipError::PowerOverflow)?;
+        registry.check_member_power(to_power)?;
+
+        registry.untrack_active(&from.member_type, from.voting_power);
+        registry.track_active(&from.member_type, from_power);
+        registry.untrack_active(&to.member_type, to.voting_power);
+        registry.track_active(&to.member_type, to_power);
+        from.voting_power = from_power;
+        to.voting_power = to_power;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::TransferVotingPower,
+            signer,
+            from.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Names the human who must co-sign an AI member's governance actions
+    /// while `require_ai_sponsor` is on.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin))]
+    pub fn set_sponsor(ctx: Context<SetSponsor>, sponsor: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let member = &mut ctx.accounts.member;
+        member.sponsor = sponsor;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetSponsor,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the model identifier an AI member discloses. Non-AI members
+    /// may only have it cleared.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin))]
+    pub fn set_model_id(ctx: Context<SetModelId>, member_id: u64, model_id: String) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let member = &mut ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+        ctx.accounts.registry.check_model_id(&member.member_type, &model_id)?;
+        member.model_id = model_id;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetModelId,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Replaces the hash of the member's off-chain contact details. Only the
+    /// hash is ever stored; matching it to a person happens off-chain.
+    pub fn set_contact_hash(ctx: Context<SetContactHash>, contact_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.registry.check_contact_hash(&contact_hash)?;
+        let member = &mut ctx.accounts.member;
+        member.contact_hash = contact_hash;
+        Ok(())
+    }
+
+    /// Records the URI of the member's off-chain profile. The member maintains
+    /// it themselves; a registration admin may override it, and only an
+    /// override is written to the audit log.
+    pub fn set_member_metadata(ctx: Context<SetMemberMetadata>, uri: String) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        let signer = ctx.accounts.signer.key();
+        require!(
+            signer == member.pubkey || ctx.accounts.registry.has_role(&signer, &Role::RegistrationAdmin),
+            MembershipError::NotAuthorized
+        );
+        require!(uri.len() <= MAX_METADATA_URI_LEN, MembershipError::MetadataUriTooLong);
+        member.metadata_uri = uri;
+
+        if signer != member.pubkey {
+            ctx.accounts.audit_log.append(InstructionKind::SetMemberMetadata, signer, member.key())?;
+        }
+        Ok(())
+    }
+
+    /// Schedules `total` of the member's voting power to vest linearly over
+    /// `duration` seconds from `start`. A zero `total` removes the schedule.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::UpdateVotingPower)
+    )]
+    pub fn set_vesting(
+        ctx: Context<SetVesting>,
+        member_id: u64,
+        start: i64,
+        duration: i64,
+        total: u64,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let member = &mut ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+        require!(
+            duration >= 0 && total <= member.voting_power,
+            MembershipError::InvalidConfigValue
+        );
+        member.vesting_start = start;
+        member.vesting_duration = duration;
+        member.vesting_total = total;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SetVesting,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Flags a member as under dispute, barring them from voting until the
+    /// dispute is resolved.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin))]
+    pub fn flag_dispute(ctx: Context<FlagDispute>, member_id: u64, reason: String) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let member = &mut ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+        require!(!member.under_dispute, MembershipError::MemberUnderDispute);
+        member.under_dispute = true;
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::FlagDispute,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        if ctx.accounts.registry.logs(EventVerbosity::All) {
+            emit!(DisputeFlagged {
+                registry: ctx.accounts.registry.key(),
+                member_id,
+                reason,
+                sequence: ctx.accounts.registry.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Clears a dispute flag. Upholding the dispute also suspends the member,
+    /// removing their power from the registry aggregates; a member whose dues
+    /// had lapsed stays suspended even once they pay.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin))]
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, member_id: u64, uphold: bool) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        let member = &mut ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+        require!(member.under_dispute, MembershipError::MemberNotUnderDispute);
+
+        if uphold {
+            assert_capability(registry, Capability::RemoveMembers)?;
+        }
+
+        member.under_dispute = false;
+        if uphold {
+            // A suspension is not lifted by paying dues.
+            member.dues_lapsed = false;
+            if member.is_active {
+                member.is_active = false;
+                registry.untrack_active(&member.member_type, member.voting_power);
+            }
+        }
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::ResolveDispute,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        if registry.logs(EventVerbosity::All) {
+            emit!(DisputeResolved {
+                registry: registry.key(),
+                member_id,
+                upheld: uphold,
+                sequence: registry.next_event_seq(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Removes a member and returns their account rent to the authority.
+    /// `close` empties the data and hands the account back to the System
+    /// Program, so refunding it later in the same transaction cannot revive
+    /// it as a `Member`: every load checks owner and discriminator. The
+    /// member's name entry, if any, is closed too, freeing the name. A parent
+    /// whose sub-agents still hold power must have them removed first.
+    #[access_control(
+        assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin)
+        assert_capability(&ctx.accounts.registry, Capability::RemoveMembers)
+    )]
+    pub fn remove_member(ctx: Context<RemoveMember>) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        let member = &ctx.accounts.member;
+        require!(member.sub_agent_power == 0, MembershipError::HasSubAgents);
+
+        release_name(
+            registry,
+            member,
+            ctx.accounts.name_entry.as_deref(),
+            &ctx.accounts.authority,
+        )?;
+        rebalance_sub_agent(member, ctx.accounts.parent.as_mut(), member.voting_power, 0)?;
+        registry.release(member);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::RemoveMember,
+            ctx.accounts.authority.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Lets a member leave on their own. Their account is closed with the rent
+    /// returned to them, and members delegating to them, passed in
+    /// `remaining_accounts`, have that delegation cleared. As with
+    /// `remove_member`, a parent's sub-agents must go first.
+    pub fn leave_membership<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LeaveMembership<'info>>,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let member = &ctx.accounts.member;
+        require!(member.sub_agent_power == 0, MembershipError::HasSubAgents);
+
+        for info in ctx.remaining_accounts.iter() {
+            let mut delegator = Account::<Member>::try_from(info)?;
+            require_keys_eq!(delegator.registry, registry.key(), MembershipError::InvalidDelegation);
+            require!(
+                delegator.delegate == Some(member.key()),
+                MembershipError::InvalidDelegation
+            );
+            delegator.delegate = None;
+            delegator.exit(&crate::ID)?;
+        }
+
+        release_name(
+            registry,
+            member,
+            ctx.accounts.name_entry.as_deref(),
+            &ctx.accounts.owner,
+        )?;
+        rebalance_sub_agent(member, ctx.accounts.parent.as_mut(), member.voting_power, 0)?;
+        registry.release(member);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::LeaveMembership,
+            ctx.accounts.owner.key(),
+            member.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Rebuilds the registry's aggregate counters from the full set of member
+    /// accounts passed in `remaining_accounts`.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin))]
+    pub fn recompute_aggregates<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecomputeAggregates<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+        require!(
+            ctx.remaining_accounts.len() as u64 == registry.member_count,
+            MembershipError::IncompleteMemberSet
+        );
+
+        registry.total_voting_power = 0;
+        registry.ai_voting_power = 0;
+        registry.human_voting_power = 0;
+        registry.active_member_count = 0;
+        registry.ai_member_count = 0;
+        registry.human_member_count = 0;
+
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            require!(!seen.contains(info.key), MembershipError::IncompleteMemberSet);
+            seen.push(info.key());
+
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            require!(!member.pending, MembershipError::InvalidMemberAccount);
+            if member.is_active {
+                registry.track_active(&member.member_type, member.voting_power);
+            }
+        }
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::RecomputeAggregates,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Resets `member_count` to the number of member accounts passed in
+    /// `remaining_accounts`, for when it has drifted from the accounts that
+    /// actually exist. Every live, non-pending member must be passed exactly
+    /// once; nothing checks that none were left out, so run `validate_roster`
+    /// afterwards.
+    #[access_control(assert_authority(&ctx.accounts.authority, &ctx.accounts.registry))]
+    pub fn reconcile_member_count<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReconcileMemberCount<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &mut ctx.accounts.registry;
+
+        let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            require!(!seen.contains(info.key), MembershipError::IncompleteMemberSet);
+            seen.push(info.key());
+
+            // Checks the owner and discriminator, so closed or foreign
+            // accounts are rejected.
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            require!(!member.pending, MembershipError::InvalidMemberAccount);
+        }
+
+        let before = registry.member_count;
+        registry.member_count = seen.len() as u64;
+        if registry.logs(EventVerbosity::All) {
+            msg!("Member count reconciled from {} to {}", before, registry.member_count);
+        }
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::ReconcileMemberCount,
+            ctx.accounts.authority.key(),
+            registry.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Records every member account passed in `remaining_accounts` into a
+    /// fresh `Snapshot` account. No instruction writes to a snapshot after
+    /// this, so it stands as a fixed record of the roster at that slot.
+    #[access_control(assert_role(&ctx.accounts.authority, &ctx.accounts.registry, Role::RegistrationAdmin))]
+    pub fn snapshot_members<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SnapshotMembers<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.registry.note_activity(&ctx.accounts.authority.key())?;
+        let registry = &ctx.accounts.registry;
+        require!(
+            ctx.remaining_accounts.len() as u64 == registry.member_count,
+            MembershipError::IncompleteMemberSet
+        );
+        require!(
+            ctx.remaining_accounts.len() <= MAX_SNAPSHOT_MEMBERS,
+            MembershipError::SnapshotTooLarge
+        );
+
+        let mut entries: Vec<SnapshotEntry> = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            require!(!member.pending, MembershipError::InvalidMemberAccount);
+            require!(
+                !entries.iter().any(|entry| entry.id == member.id),
+                MembershipError::IncompleteMemberSet
+            );
+            entries.push(SnapshotEntry {
+                id: member.id,
+                pubkey: member.pubkey,
+                voting_power: member.voting_power,
+                is_ai: matches!(member.member_type, MemberType::AI),
+            });
+        }
+
+        let clock = Clock::get()?;
+        let snapshot = &mut ctx.accounts.snapshot;
+        snapshot.registry = registry.key();
+        snapshot.slot = clock.slot;
+        snapshot.timestamp = clock.unix_timestamp;
+        snapshot.entries = entries;
+        debug_assert_fits(&**snapshot, Snapshot::MAX_ACCOUNT_SIZE);
+
+        ctx.accounts.audit_log.append(
+            InstructionKind::SnapshotMembers,
+            ctx.accounts.authority.key(),
+            snapshot.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Deactivates every member in `remaining_accounts` whose dues are paid
+    /// only up to a time that has passed, skipping the rest. Anyone may call
+    /// this, since it only enforces deadlines that have already passed.
+    /// Nothing happens while the registry charges no dues.
+    pub fn charge_dues<'info>(ctx: Context<'_, '_, 'info, 'info, ChargeDues<'info>>) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        if registry.dues_period_secs == 0 {
+            return Ok(());
+        }
+        let now = Clock::get()?.unix_timestamp;
+
+        let mut lapsed = 0u32;
+        for info in ctx.remaining_accounts.iter() {
+            let mut member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            if !member.is_active || member.pending || now <= member.dues_paid_through {
+                continue;
+            }
+            member.is_active = false;
+            member.dues_lapsed = true;
+            registry.untrack_active(&member.member_type, member.voting_power);
+            member.exit(&crate::ID)?;
+            lapsed += 1;
+        }
+
+        if lapsed > 0 {
+            ctx.accounts.audit_log.append(
+                InstructionKind::ChargeDues,
+                ctx.accounts.keeper.key(),
+                registry.key(),
+            )?;
+        }
+        if registry.logs(EventVerbosity::All) {
+            msg!("Deactivated {} members with lapsed dues", lapsed);
+        }
+        Ok(())
+    }
+
+    /// Pays `periods` dues periods for a member into the treasury. Dues run
+    /// on from the paid-through time, or from now if that has passed, and a
+    /// member deactivated by `charge_dues` is reactivated. Anyone may pay.
+    pub fn pay_dues(ctx: Context<PayDues>, member_id: u64, periods: u32) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        let member = &mut ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+        require!(
+            registry.dues_period_secs > 0 && periods > 0,
+            MembershipError::InvalidConfigValue
+        );
+
+        let amount = registry
+            .dues_amount
+            .checked_mul(periods as u64)
+            .ok_or(MembershipError::InsufficientFunds)?;
+        charge_registration_fee(
+            amount,
+            &ctx.accounts.payer,
+            &ctx.accounts.treasury,
+            &ctx.accounts.system_program,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        member.dues_paid_through = member
+            .dues_paid_through
+            .max(now)
+            .saturating_add(registry.dues_period_secs.saturating_mul(periods as i64));
+        if member.dues_lapsed {
+            registry.check_human_majority(&member.member_type)?;
+            registry.check_normalized_total(member.voting_power)?;
+            member.dues_lapsed = false;
+            member.is_active = true;
+            registry.track_active(&member.member_type, member.voting_power);
+        }
+        Ok(())
+    }
+
+    /// Hands this member's vote to another member of the same registry.
+    pub fn set_delegate(ctx: Context<SetDelegate>) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        member.delegate = Some(ctx.accounts.delegate_member.key());
+        Ok(())
+    }
+
+    pub fn clear_delegate(ctx: Context<ClearDelegate>) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        member.delegate = None;
+        Ok(())
+    }
+
+    /// Hands the member's vote to a set of keys: from now on a vote needs
+    /// `threshold` of `signers` in the transaction instead of the member's
+    /// own key. Only the member may do this, once.
+    pub fn convert_to_multisig_member(
+        ctx: Context<ConvertToMultisigMember>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            signers.len() <= MAX_MEMBER_SIGNERS
+                && (1..=signers.len()).contains(&(threshold as usize))
+                && signers.iter().enumerate().all(|(i, key)| !signers[..i].contains(key)),
+            MembershipError::InvalidMemberSigners
+        );
+        let member = &mut ctx.accounts.member;
+        member.multisig_signers = signers;
+        member.multisig_threshold = threshold;
+        Ok(())
+    }
+
+    /// Links an Ethereum address to the member, whose votes it may then
+    /// authorize with a secp256k1 signature instead of a Solana signer. A
+    /// zero address removes the link.
+    pub fn set_eth_address(ctx: Context<SetEthAddress>, eth_address: [u8; 20]) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        member.eth_address = eth_address;
+        Ok(())
+    }
+
+    /// Records that the signing member vouches for member `endorsee_id`.
+    /// Each pair gets one `Endorsement` account, so endorsing the same
+    /// member twice fails when that account is created again.
+    pub fn endorse_member(ctx: Context<EndorseMember>, endorsee_id: u64) -> Result<()> {
+        let endorsee = &mut ctx.accounts.endorsee;
+        endorsee.endorsement_count += 1;
+        ctx.accounts.endorsement.set_inner(Endorsement {
+            registry: ctx.accounts.registry.key(),
+            endorser: ctx.accounts.endorser.key(),
+            endorsee: endorsee.key(),
+            endorsee_id,
+            created_at: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Stamps a member as active and counts their vote on `proposal_id`.
+    /// Governance calls this on every vote; it is routine bookkeeping and is
+    /// not written to the audit log. Fails with `VoteRateLimited` if the
+    /// vote would exceed the registry's per-member rate limit.
+    ///
+    /// Eligibility is computed lazily rather than touching every member when
+    /// a proposal is created: a member counts as eligible for every proposal
+    /// from the oldest one they have voted on up to `proposal_count`, the
+    /// number of proposals the DAO has created so far.
+    pub fn record_activity(
+        ctx: Context<RecordActivity>,
+        proposal_id: u64,
+        proposal_count: u64,
+    ) -> Result<()> {
+        let member = &mut ctx.accounts.member;
+        let slot = Clock::get()?.slot;
+        member.record_vote_slot(&ctx.accounts.registry, slot)?;
+        member.last_active_slot = slot;
+
+        let eligible_from = member.eligible_from.map_or(proposal_id, |from| from.min(proposal_id));
+        member.eligible_from = Some(eligible_from);
+        member.votes_cast += 1;
+        member.proposals_eligible = proposal_count.saturating_sub(eligible_from);
+        Ok(())
+    }
+
+    /// Returns the member's participation rate in basis points.
+    pub fn get_participation(ctx: Context<GetParticipation>, member_id: u64) -> Result<u64> {
+        let member = &ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+        Ok(member.participation_bps())
+    }
+
+    /// Returns the member's own power plus everything delegated to them by
+    /// the members passed in `remaining_accounts`.
+    pub fn get_effective_power<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetEffectivePower<'info>>,
+        member_id: u64,
+    ) -> Result<u64> {
+        let member = &ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+
+        let registry = &ctx.accounts.registry;
+        let now = Clock::get()?.unix_timestamp;
+        let delegated = delegated_power(
+            member.key(),
+            member.registry,
+            ctx.remaining_accounts,
+            registry.max_delegation_depth,
+            registry.ai_power_multiplier_bps,
+            i64::MAX,
+        )?;
+        let own = if member.is_active {
+            scaled_power(member, vested_power(member, now), registry.ai_power_multiplier_bps)
+        } else {
+            0
+        };
+        let total = registry.effective_power_tiered(own + delegated);
+
+        if registry.logs(EventVerbosity::All) {
+            msg!("Effective power for member {}: {}", member_id, total);
+        }
+        Ok(total)
+    }
+
+    /// Returns, via return data, the member's delegate, the members passed
+    /// in `remaining_accounts` that delegate to it, the endorsers named by
+    /// the `Endorsement` accounts passed there, and its endorsement count.
+    /// Every account passed must reference the member, and each only once.
+    pub fn get_member_relations<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetMemberRelations<'info>>,
+        member_id: u64,
+    ) -> Result<MemberRelations> {
+        let member = &ctx.accounts.member;
+        require!(member.id == member_id, MembershipError::InvalidMemberAccount);
+
+        let mut relations = MemberRelations {
+            delegated_to: member.delegate,
+            delegated_from: Vec::new(),
+            endorsers: Vec::new(),
+            endorsement_count: member.endorsement_count,
+        };
+        let mut seen = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            require!(!seen.contains(info.key), MembershipError::MemberAlreadyExists);
+            seen.push(info.key());
+            if info.try_borrow_data()?.starts_with(Member::DISCRIMINATOR) {
+                let delegator = Account::<Member>::try_from(info)?;
+                require!(
+                    delegator.registry == member.registry && delegator.delegate == Some(member.key()),
+                    MembershipError::RelationMismatch
+                );
+                relations.delegated_from.push(info.key());
+            } else {
+                let endorsement = Account::<Endorsement>::try_from(info)?;
+                require!(
+                    endorsement.registry == member.registry && endorsement.endorsee == member.key(),
+                    MembershipError::RelationMismatch
+                );
+                relations.endorsers.push(endorsement.endorser);
+            }
+        }
+        Ok(relations)
+    }
+
+    /// Runs `add_member`'s checks for registering `entry` at `member` with
+    /// `nonce` against the current registry without writing anything,
+    /// returning the first one that would fail. `payer` is the account that
+    /// would fund the member account and fee.
+    pub fn simulate_register(
+        ctx: Context<SimulateRegister>,
+        entry: NewMember,
+        nonce: u64,
+    ) -> Result<RegistrationCheck> {
+        let registry = &ctx.accounts.registry;
+        let rent = Rent::get()?.minimum_balance(Member::MAX_ACCOUNT_SIZE);
+        let available = ctx
+            .accounts
+            .payer
+            .lamports()
+            .saturating_sub(rent)
+            .saturating_sub(registry.rent_buffer_lamports);
+        let name_taken = name_taken(registry, &entry.legal_name, ctx.accounts.name_entry.as_deref());
+        Ok(registry.check_registration(
+            &entry,
+            Some(nonce),
+            available,
+            account_in_use(&ctx.accounts.member),
+            name_taken,
+        ))
+    }
+
+    /// Grows a member account created under an older, smaller layout to the
+    /// current `Member::MAX_ACCOUNT_SIZE`, with `payer` covering the extra
+    /// rent. Fields appended since read as zero, i.e. `None`, `0` or `false`.
+    /// Accounts already at the current size are left untouched, so this is
+    /// safe to call repeatedly. Anyone may call it for a member of `registry`.
+    ///
+    /// Accounts from the original layout predate a member's link to its
+    /// registry and read it as the default key. Upgrading one adopts it into
+    /// `registry`, giving it an ID and counting it in the registry's totals,
+    /// so `authority` must sign as a registration admin.
+    pub fn resize_member_account(ctx: Context<ResizeMemberAccount>) -> Result<()> {
+        let info = ctx.accounts.member.to_account_info();
+        require!(
+            info.try_borrow_data()?.starts_with(Member::DISCRIMINATOR),
+            MembershipError::InvalidMemberAccount
+        );
+        if info.data_len() >= Member::MAX_ACCOUNT_SIZE {
+            if ctx.accounts.registry.logs(EventVerbosity::All) {
+                msg!("Member account is already at the current size");
+            }
+            return Ok(());
+        }
+
+        info.resize(Member::MAX_ACCOUNT_SIZE)?;
+        fund_rent_buffer(0, &ctx.accounts.payer, &info, &ctx.accounts.system_program)?;
+
+        let mut member = Member::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        let registry = &mut ctx.accounts.registry;
+        if member.registry == Pubkey::default() {
+            let authority = ctx.accounts.authority.as_ref().ok_or(MembershipError::NotAuthorized)?;
+            assert_role(authority, registry, Role::RegistrationAdmin)?;
+            require!(!registry.roster_sealed, MembershipError::RosterSealed);
+            member.registry = registry.key();
+            member.id = registry.assign_member_id();
+            registry.member_count += 1;
+            if member.is_active {
+                registry.track_active(&member.member_type, member.voting_power);
+            }
+        }
+        require!(
+            member.registry == registry.key(),
+            MembershipError::InvalidMemberAccount
+        );
+        member.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+
+    /// Serializes the registry and the member accounts passed in
+    /// `remaining_accounts` into one Borsh-encoded `StateExport`. It is sent
+    /// as return data when it fits; otherwise it is logged in order as
+    /// `MAX_RETURN_DATA`-byte chunks of program data for the client to join.
+    /// See docs/STATE_MIGRATION.md for loading an export into a new
+    /// deployment.
+    pub fn export_state<'info>(ctx: Context<'_, '_, 'info, 'info, ExportState<'info>>) -> Result<()> {
+        let registry = &ctx.accounts.registry;
+        let mut members = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            members.push(member.into_inner());
+        }
+
+        let export = StateExport {
+            version: STATE_EXPORT_VERSION,
+            registry: registry.key(),
+            state: (**registry).clone(),
+            members,
+        };
+        let data = anchor_lang::prelude::borsh::to_vec(&export)?;
+        if data.len() <= MAX_RETURN_DATA {
+            set_return_data(&data);
+        } else {
+            for chunk in data.chunks(MAX_RETURN_DATA) {
+                sol_log_data(&[chunk]);
+            }
+        }
+        if registry.logs(EventVerbosity::All) {
+            msg!("Exported {} members in {} bytes", export.members.len(), data.len());
+        }
+        Ok(())
+    }
+
+    /// Checks that the member accounts passed as remaining accounts carry
+    /// exactly the IDs 0..member_count, each once. Nothing is written; every
+    /// gap, duplicate and out-of-range ID is logged and the totals come back
+    /// via return data, so off-chain tools can detect drift before relying
+    /// on a contiguous roster.
+    pub fn validate_roster<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ValidateRoster<'info>>,
+    ) -> Result<RosterReport> {
+        let registry = &ctx.accounts.registry;
+        let mut ids = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            require!(!member.pending, MembershipError::InvalidMemberAccount);
+            ids.push(member.id);
+        }
+        ids.sort_unstable();
+
+        let mut report = RosterReport::default();
+        let mut expected = 0u64;
+        for (i, &id) in ids.iter().enumerate() {
+            if i > 0 && ids[i - 1] == id {
+                if registry.logs(EventVerbosity::Errors) {
+                    msg!("Duplicate member ID {}", id);
+                }
+                report.duplicate_count += 1;
+                report.first_duplicate.get_or_insert(id);
+                continue;
+            }
+            if id >= registry.member_count {
+                if registry.logs(EventVerbosity::Errors) {
+                    msg!("Member ID {} is outside 0..{}", id, registry.member_count);
+                }
+                report.out_of_range_count += 1;
+                continue;
+            }
+            if id > expected {
+                if registry.logs(EventVerbosity::Errors) {
+                    msg!("Missing member IDs {}..{}", expected, id);
+                }
+                report.missing_count += id - expected;
+                report.first_missing.get_or_insert(expected);
+            }
+            expected = id + 1;
+        }
+        if expected < registry.member_count {
+            if registry.logs(EventVerbosity::Errors) {
+                msg!("Missing member IDs {}..{}", expected, registry.member_count);
+            }
+            report.missing_count += registry.member_count - expected;
+            report.first_missing.get_or_insert(expected);
+        }
+        Ok(report)
+    }
+
+    /// Returns, via return data, the voting-power-weighted average of
+    /// `attribute` over the active members passed as remaining accounts,
+    /// rounded down, or 0 if they hold no power. Inactive members are
+    /// skipped; each member may be passed once. Nothing is written.
+    pub fn aggregate_attribute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AggregateAttribute<'info>>,
+        attribute: MemberAttribute,
+    ) -> Result<u64> {
+        let registry = &ctx.accounts.registry;
+        let now = Clock::get()?.unix_timestamp;
+        let mut seen = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut weighted_sum = 0u128;
+        let mut total_weight = 0u128;
+        for info in ctx.remaining_accounts.iter() {
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(member.registry, registry.key(), MembershipError::InvalidMemberAccount);
+            require!(!member.pending, MembershipError::InvalidMemberAccount);
+            require!(!seen.contains(info.key), MembershipError::MemberAlreadyExists);
+            seen.push(info.key());
+            if !member.is_active {
+                continue;
+            }
+            weighted_sum += member.voting_power as u128 * attribute.value(&member, now) as u128;
+            total_weight += member.voting_power as u128;
+        }
+
+        // A weighted average never exceeds the largest value, so it fits.
+        let average = weighted_sum.checked_div(total_weight).unwrap_or(0) as u64;
+        if registry.logs(EventVerbosity::All) {
+            msg!("Weighted average over {} members: {}", seen.len(), average);
+        }
+        Ok(average)
+    }
+
+    /// Returns, via return data, whether `pubkey` is an active member of the
+    /// registry through the given member account.
+    pub fn verify_membership(ctx: Context<VerifyMembership>, pubkey: Pubkey) -> Result<bool> {
+        let member = &ctx.accounts.member;
+        Ok(member.pubkey == pubkey
+            && member.registry == ctx.accounts.registry.key()
+            && member.is_active)
+    }
+}
+
+/// Fails with `NotAuthorized` unless `account` signed the transaction.
+pub fn assert_signer(account: &AccountInfo) -> Result<()> {
+    require!(account.is_signer, MembershipError::NotAuthorized);
+    Ok(())
+}
+
+/// Fails with `NotAuthorized` unless `account` is the registry authority and
+/// signed the transaction.
+///
+/// Failures emit `SuspiciousActivity`. A failed transaction keeps its logs but
+/// none of its state, so attempts cannot be counted on-chain; monitoring
+/// counts the events instead.
+pub fn assert_authority(account: &AccountInfo, registry: &MemberRegistry) -> Result<()> {
+    check_signer_as(account, registry, account.key() == registry.authority)
+}
+
+/// Fails with `NotAuthorized` unless `account` holds `role` and signed the
+/// transaction. Privileged instructions all check through here, and failures
+/// emit `SuspiciousActivity` just as in `assert_authority`.
+pub fn assert_role(account: &AccountInfo, registry: &MemberRegistry, role: Role) -> Result<()> {
+    check_signer_as(account, registry, registry.has_role(&account.key(), &role))
+}
+
+/// Fails with `NotAuthorized` unless `account` is a config admin or the
+/// registry's governance signer, and signed the transaction.
+pub fn assert_config_signer(account: &AccountInfo, registry: &MemberRegistry) -> Result<()> {
+    let allowed =
+        registry.has_role(&account.key(), &Role::ConfigAdmin) || account.key() == registry.governance;
+    check_signer_as(account, registry, allowed)
+}
+
+fn check_signer_as(account: &AccountInfo, registry: &MemberRegistry, allowed: bool) -> Result<()> {
+    if !account.is_signer || !allowed {
+        if registry.logs(EventVerbosity::Errors) {
+            emit!(SuspiciousActivity {
+                authority: registry.authority,
+                signer: account.key(),
+                slot: Clock::get()?.slot,
+            });
+        }
+        return err!(MembershipError::NotAuthorized);
+    }
+    Ok(())
+}
+
+/// Fails with `CapabilityRenounced` once the authority has renounced
+/// `capability`.
+pub fn assert_capability(registry: &MemberRegistry, capability: Capability) -> Result<()> {
+    require!(
+        registry.renounced_capabilities & capability.bit() == 0,
+        MembershipError::CapabilityRenounced
+    );
+    Ok(())
+}
+
+/// Splits a multisig member's co-signers off the front of `accounts`,
+/// returning the accounts after them. Together with `voter`, the
+/// co-signers that signed must reach the member's threshold. Single-key
+/// members take no co-signers, so `accounts` comes back whole.
+pub fn split_member_signers<'a, 'info>(
+    member: &Member,
+    voter: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<&'a [AccountInfo<'info>]> {
+    if member.multisig_threshold == 0 {
+        return Ok(accounts);
+    }
+    let count = accounts
+        .iter()
+        .take_while(|info| member.multisig_signers.contains(info.key))
+        .count();
+    let (cosigners, rest) = accounts.split_at(count);
+
+    let mut approvals = vec![*voter];
+    for info in cosigners {
+        if info.is_signer && !approvals.contains(info.key) {
+            approvals.push(info.key());
+        }
+    }
+    require!(
+        approvals.len() >= member.multisig_threshold as usize,
+        MembershipError::MemberThresholdNotMet
+    );
+    Ok(rest)
+}
+
+/// Fails with `InvalidEthSignature` unless the instruction just before the
+/// current one, read through the `instructions` sysvar, is a Secp256k1
+/// program instruction verifying one signature by `eth_address` over
+/// `message`. The Secp256k1 program has already checked the signature by
+/// the time this runs; this only checks what it was a signature of.
+pub fn verify_eth_signature(
+    instructions: &AccountInfo,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, MembershipError::InvalidEthSignature);
+    let index = current - 1;
+    let ix = load_instruction_at_checked(index as usize, instructions)?;
+
+    // One signature, whose offsets all point into this same instruction:
+    // a count byte, then the signature, address and message offsets.
+    let data = &ix.data;
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    require!(
+        ix.program_id == secp256k1_program::ID && data.len() >= 12 && data[0] == 1,
+        MembershipError::InvalidEthSignature
+    );
+    let own_index = u8::try_from(index).map_err(|_| MembershipError::InvalidEthSignature)?;
+    require!(
+        data[3] == own_index && data[6] == own_index && data[11] == own_index,
+        MembershipError::InvalidEthSignature
+    );
+    let (address_at, message_at, message_len) = (read_u16(4), read_u16(7), read_u16(9));
+    require!(
+        data.get(address_at..address_at + 20) == Some(&eth_address[..])
+            && data.get(message_at..message_at + message_len) == Some(message),
+        MembershipError::InvalidEthSignature
+    );
+    Ok(())
+}
+
+/// Fails unless an AI member's sponsor co-signed, when the registry requires
+/// it. `signer` is the sponsor account supplied with the action, if any.
+pub fn check_sponsor(registry: &MemberRegistry, member: &Member, signer: Option<Pubkey>) -> Result<()> {
+    if registry.require_ai_sponsor && matches!(member.member_type, MemberType::AI) {
+        require!(
+            member.sponsor.is_some() && member.sponsor == signer,
+            MembershipError::SponsorSignatureRequired
+        );
+    }
+    Ok(())
+}
+
+/// Latest `joined_at` that may count towards a proposal created at
+/// `proposal_created_at`, given the registry's eligibility delay. A zero
+/// delay turns the check off, so anyone may vote whenever they joined.
+pub fn eligibility_cutoff(registry: &MemberRegistry, proposal_created_at: i64) -> i64 {
+    if registry.voting_eligibility_delay_secs == 0 {
+        return i64::MAX;
+    }
+    proposal_created_at.saturating_sub(registry.voting_eligibility_delay_secs)
+}
+
+/// Fails with `NotYetEligible` unless `member` joined at least
+/// `voting_eligibility_delay_secs` before the proposal was created.
+pub fn check_vote_eligibility(
+    registry: &MemberRegistry,
+    member: &Member,
+    proposal_created_at: i64,
+) -> Result<()> {
+    require!(
+        member.joined_at <= eligibility_cutoff(registry, proposal_created_at),
+        MembershipError::NotYetEligible
+    );
+    Ok(())
+}
+
+/// Moves a sub-agent's share of its parent's allocation from `old_power` to
+/// `new_power`. Members without a parent need no parent account.
+fn rebalance_sub_agent(
+    member: &Member,
+    parent: Option<&mut Account<Member>>,
+    old_power: u64,
+    new_power: u64,
+) -> Result<()> {
+    let Some(parent_id) = member.parent else {
+        return Ok(());
+    };
+    let parent = parent.ok_or(MembershipError::InvalidMemberAccount)?;
+    require!(
+        parent.id == parent_id && parent.registry == member.registry && !parent.pending,
+        MembershipError::InvalidMemberAccount
+    );
+
+    let used = parent
+        .sub_agent_power
+        .checked_sub(old_power)
+        .ok_or(MembershipError::SubAgentPowerMismatch)?
+        .checked_add(new_power)
+        .ok_or(MembershipError::PowerOverflow)?;
+    require!(
+        new_power <= old_power || used <= parent.voting_power,
+        MembershipError::SubAgentPowerExceeded
+    );
+    parent.sub_agent_power = used;
+    Ok(())
+}
+
+/// Whether `info` is already an account of this program.
+fn account_in_use(info: &AccountInfo) -> bool {
+    info.owner == &crate::ID && !info.data_is_empty()
+}
+
+/// Whether `entry` is the `NameEntry` for `legal_name` and already holds
+/// it, while the registry requires unique names. A missing or misplaced
+/// entry is left for `claim_name` to reject.
+fn name_taken(registry: &Account<MemberRegistry>, legal_name: &str, entry: Option<&AccountInfo>) -> bool {
+    registry.unique_names
+        && entry.is_some_and(|entry| {
+            entry.key() == name_entry_address(&registry.key(), legal_name).0 && account_in_use(entry)
+        })
+}
+
+/// Address of the `NameEntry` reserving `legal_name` in `registry`.
+pub fn name_entry_address(registry: &Pubkey, legal_name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"name", registry.as_ref(), hash(legal_name.as_bytes()).as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Reserves `legal_name` for `member` by creating its `NameEntry`, while
+/// the registry requires unique names. Fails with `MemberAlreadyExists`
+/// if the entry is already taken.
+fn claim_name<'info>(
+    registry: &Account<'info, MemberRegistry>,
+    legal_name: &str,
+    member: Pubkey,
+    entry: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if !registry.unique_names {
+        return Ok(());
+    }
+    let entry = entry.ok_or(MembershipError::InvalidNameEntry)?;
+    let (address, bump) = name_entry_address(&registry.key(), legal_name);
+    require_keys_eq!(entry.key(), address, MembershipError::InvalidNameEntry);
+    require!(
+        entry.owner != &crate::ID || entry.data_is_empty(),
+        MembershipError::MemberAlreadyExists
+    );
+
+    let registry_key = registry.key();
+    let name_hash = hash(legal_name.as_bytes());
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: entry.clone(),
+            },
+            &[&[b"name", registry_key.as_ref(), name_hash.as_ref(), &[bump]]],
+        ),
+        Rent::get()?.minimum_balance(NameEntry::MAX_ACCOUNT_SIZE),
+        NameEntry::MAX_ACCOUNT_SIZE as u64,
+        &crate::ID,
+    )?;
+    NameEntry { registry: registry_key, member }
+        .try_serialize(&mut &mut entry.try_borrow_mut_data()?[..])
+}
+
+/// Closes the `NameEntry` holding `member`'s name, refunding its rent to
+/// `recipient`, so the name can be registered again. The entry must be
+/// passed while names are unique; members who joined before that have
+/// none, and their uninitialized address is accepted.
+fn release_name<'info>(
+    registry: &Account<'info, MemberRegistry>,
+    member: &Account<'info, Member>,
+    entry: Option<&AccountInfo<'info>>,
+    recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    let Some(entry) = entry else {
+        require!(!registry.unique_names, MembershipError::InvalidNameEntry);
+        return Ok(());
+    };
+    let (address, _) = name_entry_address(&registry.key(), &member.legal_name);
+    require_keys_eq!(entry.key(), address, MembershipError::InvalidNameEntry);
+    if entry.owner != &crate::ID || entry.data_is_empty() {
+        return Ok(());
+    }
+    let held = NameEntry::try_deserialize(&mut &entry.try_borrow_data()?[..])?;
+    require_keys_eq!(held.member, member.key(), MembershipError::InvalidNameEntry);
+
+    let lamports = entry.lamports();
+    **entry.try_borrow_mut_lamports()? = 0;
+    **recipient.try_borrow_mut_lamports()? += lamports;
+    entry.assign(&system_program::ID);
+    entry.resize(0)?;
+    Ok(())
+}
+
+/// Leaf of a roster manifest's Merkle tree for the entry at `position`.
+pub fn manifest_leaf(position: u64, entry: &NewMember) -> Result<[u8; 32]> {
+    let data = anchor_lang::prelude::borsh::to_vec(entry)?;
+    Ok(hashv(&[&[0], &position.to_le_bytes(), &data]).to_bytes())
+}
+
+/// Whether `leaf` belongs to the Merkle tree with `root`, given its sibling
+/// hashes from the bottom up. Each pair is hashed in sorted order, so a
+/// proof needs no left/right flags; leaves and inner nodes carry different
+/// prefixes so neither can pass for the other.
+pub fn verify_merkle_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (low, high) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        hashv(&[&[1], &low, &high]).to_bytes()
+    });
+    computed == *root
+}
+
+/// Tops `account` up from `payer` so it holds at least the rent-exempt
+/// minimum plus `buffer`, leaving headroom for later growth.
+fn fund_rent_buffer<'info>(
+    buffer: u64,
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let required = Rent::get()?
+        .minimum_balance(account.data_len())
+        .checked_add(buffer)
+        .ok_or(MembershipError::InsufficientFunds)?;
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall == 0 {
+        return Ok(());
+    }
+    require!(payer.lamports() >= shortfall, MembershipError::InsufficientFunds);
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        ),
+        shortfall,
+    )
+}
+
+/// Moves a registration fee from `payer` into the registry's treasury.
+fn charge_registration_fee<'info>(
+    amount: u64,
+    payer: &Signer<'info>,
+    treasury: &Option<UncheckedAccount<'info>>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let treasury = treasury.as_ref().ok_or(MembershipError::InvalidTreasury)?;
+    require!(payer.lamports() >= amount, MembershipError::InsufficientFunds);
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: treasury.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// The part of the member's voting power vested at `now`. Of `voting_power`,
+/// `vesting_total` is locked until `vesting_start` and then released
+/// linearly over `vesting_duration` seconds; the rest is always available.
+pub fn vested_power(member: &Member, now: i64) -> u64 {
+    let locked = member.vesting_total.min(member.voting_power);
+    let unlocked = member.voting_power - locked;
+    if locked == 0 || now < member.vesting_start {
+        return unlocked;
+    }
+    let elapsed = now.saturating_sub(member.vesting_start);
+    if elapsed >= member.vesting_duration {
+        return member.voting_power;
+    }
+    unlocked + (locked as u128 * elapsed as u128 / member.vesting_duration as u128) as u64
+}
+
+/// Restarts `member`'s vesting schedule at `now` with only the share still
+/// locked, keeping its end time. The rest of the schedule has already been
+/// released, so `vested_power` is unchanged now and later, but the locked
+/// share no longer shrinks when the member gives power away.
+fn restart_vesting(member: &mut Member, now: i64) {
+    if now <= member.vesting_start {
+        return;
+    }
+    let ends_at = member.vesting_start.saturating_add(member.vesting_duration);
+    member.vesting_total = member.voting_power - vested_power(member, now);
+    member.vesting_start = now;
+    member.vesting_duration = ends_at.saturating_sub(now).max(0);
+}
+
+/// `power` after inactivity decay: once `grace_slots` pass without a vote,
+/// it drops by `bps_per_period` for every full `period_slots` elapsed, down
+/// to zero. A zero period or rate disables decay.
+pub fn decayed_power(member: &Member, power: u64, now_slot: u64, decay: &DecayConfig) -> u64 {
+    if decay.period_slots == 0 || decay.bps_per_period == 0 {
+        return power;
+    }
+    let idle = now_slot.saturating_sub(member.last_active_slot);
+    if idle <= decay.grace_slots {
+        return power;
+    }
+    let periods = (idle - decay.grace_slots) / decay.period_slots;
+    let reduction_bps = periods
+        .saturating_mul(decay.bps_per_period as u64)
+        .min(bps::MAX_BPS as u64) as u16;
+    bps::apply_bps(power, bps::MAX_BPS - reduction_bps).unwrap_or(u64::MAX)
+}
+
+/// Scales `power` by `ai_multiplier_bps` when `member` is an AI member. The
+/// stored `voting_power` is never touched.
+pub fn scaled_power(member: &Member, power: u64, ai_multiplier_bps: u16) -> u64 {
+    match member.member_type {
+        MemberType::AI => bps::apply_bps(power, ai_multiplier_bps).unwrap_or(u64::MAX),
+        _ => power,
+    }
+}
+
+/// Sums the active voting power of `delegators` after checking that each
+/// one's delegation chain resolves to `member`. Intermediate links of a chain
+/// must be included in `delegators`. Only vested power counts, and
+/// delegators who joined after `joined_by` still count as links but add no
+/// power.
+pub fn delegated_power<'info>(
+    member: Pubkey,
+    registry: Pubkey,
+    delegators: &'info [AccountInfo<'info>],
+    max_depth: u8,
+    ai_multiplier_bps: u16,
+    joined_by: i64,
+) -> Result<u64> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut links: Vec<(Pubkey, Option<Pubkey>)> = Vec::with_capacity(delegators.len());
+    let mut powers = Vec::with_capacity(delegators.len());
+    for info in delegators.iter() {
+        require!(
+            !links.iter().any(|(key, _)| *key == info.key()),
+            MembershipError::InvalidDelegation
+        );
+        let delegator = Account::<Member>::try_from(info)?;
+        require_keys_eq!(delegator.registry, registry, MembershipError::InvalidDelegation);
+        links.push((info.key(), delegator.delegate));
+        powers.push(if delegator.is_active && delegator.joined_at <= joined_by {
+            scaled_power(&delegator, vested_power(&delegator, now), ai_multiplier_bps)
+        } else {
+            0
+        });
+    }
+
+    let mut total = 0u64;
+    for ((key, _), power) in links.iter().zip(powers) {
+        delegation_depth(*key, member, &links, max_depth)?;
+        total += power;
+    }
+    Ok(total)
+}
+
+/// Follows the delegation chain starting at member account `start` until it
+/// reaches `voter`, returning the number of hops taken. `members` maps member
+/// account keys to their delegate and must contain every link of the chain.
+pub fn delegation_depth(
+    start: Pubkey,
+    voter: Pubkey,
+    members: &[(Pubkey, Option<Pubkey>)],
+    max_depth: u8,
+) -> Result<u8> {
+    let mut visited = vec![start];
+    let mut current = start;
+    let mut depth = 0u8;
+
+    loop {
+        let next = members
+            .iter()
+            .find(|(key, _)| *key == current)
+            .and_then(|(_, delegate)| *delegate)
+            .ok_or(MembershipError::InvalidDelegation)?;
+        depth += 1;
+
+        if next == voter {
+            require!(depth <= max_depth, MembershipError::DelegationTooDeep);
+            return Ok(depth);
+        }
+        require!(!visited.contains(&next), MembershipError::DelegationCycle);
+        // A chain leaving the members passed in never reaches `voter`,
+        // however deep it is allowed to go.
+        require!(
+            members.iter().any(|(key, _)| *key == next),
+            MembershipError::InvalidDelegation
+        );
+        require!(depth < max_depth, MembershipError::DelegationTooDeep);
+
+        visited.push(next);
+        current = next;
+    }
+}
+
+/// Converts a 32-byte slice (seed, hash or key bytes) into a `Pubkey`,
+/// rejecting slices of any other length instead of panicking.
+pub fn to_pubkey(bytes: &[u8]) -> Result<Pubkey> {
+    let array: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| error!(MembershipError::InvalidAccountData))?;
+    Ok(Pubkey::new_from_array(array))
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = authority, space = MemberRegistry::MAX_ACCOUNT_SIZE)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(init, payer = authority, space = AuditLog::MAX_ACCOUNT_SIZE)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RotateAuditLog<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(init, payer = authority, space = AuditLog::MAX_ACCOUNT_SIZE)]
+    pub new_audit_log: Account<'info, AuditLog>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetGovernance<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAgreementHash<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SealRoster<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetSuccessor<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAuthority<'info> {
+    #[account(
+        mut,
+        has_one = audit_log,
+        constraint = registry.successor != Pubkey::default()
+            && registry.successor == successor.key() @ MembershipError::NotAuthorized
+    )]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub successor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RenounceCapability<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReassignControl<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPowerTiers<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRoleMembers<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddMember<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(
+        init,
+        payer = authority,
+        space = Member::MAX_ACCOUNT_SIZE,
+        constraint = member.key() != registry.key() @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    /// CHECK: Member pubkey is validated by the program logic
+    pub member_pubkey: AccountInfo<'info>,
+    /// CHECK: Only receives lamports; must match `registry.treasury`
+    #[account(mut, address = registry.treasury @ MembershipError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    /// CHECK: The `NameEntry` PDA for the member's name, checked by the
+    /// program; required while the registry requires unique names
+    #[account(mut)]
+    pub name_entry: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReserveMemberId<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(init, payer = authority, space = MemberIdReservation::MAX_ACCOUNT_SIZE)]
+    pub reservation: Account<'info, MemberIdReservation>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitMember<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry, close = authority)]
+    pub reservation: Account<'info, MemberIdReservation>,
+    #[account(
+        init,
+        payer = authority,
+        space = Member::MAX_ACCOUNT_SIZE,
+        constraint = member.key() != registry.key() @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    /// CHECK: Only receives lamports; must match `registry.treasury`
+    #[account(mut, address = registry.treasury @ MembershipError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    /// CHECK: The `NameEntry` PDA for the member's name, checked by the
+    /// program; required while the registry requires unique names
+    #[account(mut)]
+    pub name_entry: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterSubAgent<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = parent.pubkey == owner.key() @ MembershipError::NotAuthorized,
+        constraint = parent.is_active
+            && matches!(parent.member_type, MemberType::Human)
+            && parent.parent.is_none() @ MembershipError::InvalidMemberAccount
+    )]
+    pub parent: Account<'info, Member>,
+    #[account(
+        init,
+        payer = owner,
+        space = Member::MAX_ACCOUNT_SIZE,
+        constraint = member.key() != registry.key() @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    /// CHECK: The agent's own key, recorded as the member pubkey
+    pub member_pubkey: AccountInfo<'info>,
+    /// CHECK: Only receives lamports; must match `registry.treasury`
+    #[account(mut, address = registry.treasury @ MembershipError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    /// CHECK: The `NameEntry` PDA for the member's name, checked by the
+    /// program; required while the registry requires unique names
+    #[account(mut)]
+    pub name_entry: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BeginRosterImport<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(init, payer = authority, space = RosterImport::MAX_ACCOUNT_SIZE)]
+    pub roster_import: Account<'info, RosterImport>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ImportRoster<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub roster_import: Account<'info, RosterImport>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddMembersBatch<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    /// CHECK: Only receives lamports; must match `registry.treasury`
+    #[account(mut, address = registry.treasury @ MembershipError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyForMembership<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        init,
+        payer = applicant,
+        space = Member::MAX_ACCOUNT_SIZE,
+        constraint = member.key() != registry.key() @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    /// CHECK: Only receives lamports; must match `registry.treasury`
+    #[account(mut, address = registry.treasury @ MembershipError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    /// CHECK: The `NameEntry` PDA for the member's name, checked by the
+    /// program; required while the registry requires unique names
+    #[account(mut)]
+    pub name_entry: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMembership<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = member.pending @ MembershipError::MemberNotPending
+    )]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferVotingPower<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub from: Account<'info, Member>,
+    #[account(mut, has_one = registry)]
+    pub to: Account<'info, Member>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdjustVotingPower<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = !member.pending @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+    /// Required when `member` is a sub-agent
+    #[account(mut)]
+    pub parent: Option<Account<'info, Member>>,
+}
+
+#[derive(Accounts)]
+pub struct SetModelId<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetContactHash<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = member.pubkey == owner.key() @ MembershipError::NotAuthorized
+    )]
+    pub member: Account<'info, Member>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMemberMetadata<'info> {
+    #[account(has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub member: Account<'info, Member>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetVesting<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetSponsor<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = matches!(member.member_type, MemberType::AI) @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FlagDispute<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry)]
+    pub member: Account<'info, Member>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMember<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(mut, has_one = registry, close = authority)]
+    pub member: Account<'info, Member>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: The `NameEntry` PDA for the member's name, checked by the
+    /// program; required while the registry requires unique names
+    #[account(mut)]
+    pub name_entry: Option<UncheckedAccount<'info>>,
+    /// Required when `member` is a sub-agent
+    #[account(mut)]
+    pub parent: Option<Account<'info, Member>>,
+}
+
+#[derive(Accounts)]
+pub struct LeaveMembership<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(
+        mut,
+        has_one = registry,
+        close = owner,
+        constraint = member.pubkey == owner.key() @ MembershipError::NotAuthorized
+    )]
+    pub member: Account<'info, Member>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: The `NameEntry` PDA for the member's name, checked by the
+    /// program; required while the registry requires unique names
+    #[account(mut)]
+    pub name_entry: Option<UncheckedAccount<'info>>,
+    /// Required when `member` is a sub-agent
+    #[account(mut)]
+    pub parent: Option<Account<'info, Member>>,
+}
+
+#[derive(Accounts)]
+pub struct RecomputeAggregates<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReconcileMemberCount<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SnapshotMembers<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    #[account(init, payer = authority, space = Snapshot::MAX_ACCOUNT_SIZE)]
+    pub snapshot: Account<'info, Snapshot>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ChargeDues<'info> {
+    #[account(mut, has_one = audit_log)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut)]
+    pub audit_log: Account<'info, AuditLog>,
+    pub keeper: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PayDues<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = !member.pending @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    /// CHECK: Only receives lamports; must match `registry.treasury`
+    #[account(mut, address = registry.treasury @ MembershipError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetDelegate<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = member.pubkey == owner.key() @ MembershipError::NotAuthorized
+    )]
+    pub member: Account<'info, Member>,
+    #[account(
+        has_one = registry,
+        constraint = delegate_member.key() != member.key() @ MembershipError::InvalidDelegation
+    )]
+    pub delegate_member: Account<'info, Member>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClearDelegate<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = member.pubkey == owner.key() @ MembershipError::NotAuthorized
+    )]
+    pub member: Account<'info, Member>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConvertToMultisigMember<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = member.pubkey == owner.key() @ MembershipError::NotAuthorized,
+        constraint = member.multisig_threshold == 0 && !member.pending @ MembershipError::InvalidMemberAccount
+    )]
+    pub member: Account<'info, Member>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetEthAddress<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = member.pubkey == owner.key() @ MembershipError::NotAuthorized
+    )]
+    pub member: Account<'info, Member>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(endorsee_id: u64)]
+pub struct EndorseMember<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(
+        has_one = registry,
+        constraint = endorser.pubkey == owner.key() @ MembershipError::NotAuthorized,
+        constraint = endorser.is_active && !endorser.pending @ MembershipError::InvalidMemberAccount
+    )]
+    pub endorser: Account<'info, Member>,
+    #[account(
+        mut,
+        has_one = registry,
+        constraint = endorsee.id == endorsee_id && !endorsee.pending @ MembershipError::InvalidMemberAccount,
+        constraint = endorsee.key() != endorser.key() @ MembershipError::SelfEndorsement
+    )]
+    pub endorsee: Account<'info, Member>,
+    #[account(
+        init,
+        payer = owner,
+        space = Endorsement::MAX_ACCOUNT_SIZE,
+        seeds = [b"endorsement", endorser.key().as_ref(), endorsee.key().as_ref()],
+        bump
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordActivity<'info> {
+    #[account(constraint = signer.key() == registry.governance @ MembershipError::NotAuthorized)]
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(mut, has_one = registry)]
+    pub member: Account<'info, Member>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExportState<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct ResizeMemberAccount<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, MemberRegistry>,
+    /// CHECK: May predate the current `Member` layout; deserialized by hand
+    /// once resized
+    #[account(mut, owner = crate::ID)]
+    pub member: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    /// Registration admin adopting an original-layout member; unused otherwise
+    pub authority: Option<Signer<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct GetEffectivePower<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(has_one = registry)]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct GetMemberRelations<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    #[account(has_one = registry)]
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct SimulateRegister<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    /// CHECK: Only its balance is read
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK: The would-be member account, only checked for being in use
+    pub member: UncheckedAccount<'info>,
+    /// CHECK: The `NameEntry` PDA for the member's name, only checked for
+    /// being taken
+    pub name_entry: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ValidateRoster<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct AggregateAttribute<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct GetParticipation<'info> {
+    pub member: Account<'info, Member>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyMembership<'info> {
+    pub registry: Account<'info, MemberRegistry>,
+    pub member: Account<'info, Member>,
+}
+
+#[account]
+pub struct MemberRegistry {
+    pub authority: Pubkey,
+    pub member_count: u64,
+    pub audit_log: Pubkey,
+    pub max_members: u64, // 0 means no cap
+    pub governance: Pubkey,
+    pub total_voting_power: u64,
+    pub max_delegation_depth: u8, // 1 means flat, single-hop delegation
+    pub next_member_id: u64,
+    pub reuse_ids: bool,
+    pub free_member_ids: Vec<u64>,
+    // Aggregates over active members. Organizations count toward the total only.
+    pub ai_voting_power: u64,
+    pub human_voting_power: u64,
+    pub active_member_count: u64,
+    pub treasury: Pubkey,
+    pub registration_fee: u64, // lamports, paid by the registering authority or applicant
+    pub decay: DecayConfig,
+    pub self_registration: bool,
+    pub default_voting_power: u64, // granted on approval unless overridden
+    pub roster_sealed: bool, // irreversible once set
+    pub require_ai_sponsor: bool,
+    // Fixed total that voting power is expressed as shares of. Changes that
+    // would exceed it are rejected rather than rescaling other members.
+    pub normalized_total: Option<u64>,
+    pub ai_power_multiplier_bps: u16, // AI members' effective share of their power
+    pub rent_buffer_lamports: u64, // kept in member accounts above rent exemption
+    pub registration_nonce: u64, // next nonce an authority registration must carry
+    pub max_member_power: u64, // per-member voting power cap, 0 means no cap
+    pub require_model_id: bool, // AI members must disclose a model identifier
+    pub voting_eligibility_delay_secs: i64, // members vote on proposals created this long after joining, 0 for any
+    pub require_contact_hash: bool, // members must register a non-zero contact hash
+    // Holders of each role, besides the authority, which holds them all.
+    pub registration_admins: Vec<Pubkey>,
+    pub treasury_admins: Vec<Pubkey>,
+    pub config_admins: Vec<Pubkey>,
+    // Largest treasury transfer a proposal of each category may execute, in
+    // lamports; 0 means no cap.
+    pub operational_cap: u64,
+    pub financial_cap: u64,
+    pub treasury_min_balance: u64, // lamports a governance treasury keeps above rent exemption, 0 for none
+    pub power_tiers: Vec<PowerTier>, // ascending by `from`
+    // Active AI and human members. Registries created before these were
+    // tracked count from zero until `recompute_aggregates` runs.
+    pub ai_member_count: u64,
+    pub human_member_count: u64,
+    pub require_human_majority_count: bool, // AI members may never outnumber human ones
+    pub renounced_capabilities: u64, // one bit per `Capability`, never cleared
+    pub event_verbosity: EventVerbosity, // which events and logs instructions emit
+    pub dues_amount: u64, // lamports per dues period
+    pub dues_period_secs: i64, // 0 means no dues are charged
+    pub unique_names: bool, // each legal name may be held by one member, see `NameEntry`
+    // Votes a member may cast within any `vote_window_slots` slots; 0 means
+    // no limit.
+    pub max_votes_per_window: u8,
+    pub vote_window_slots: u64,
+    pub agreement_hash: [u8; 32], // operating agreement new members acknowledge, all zero if none
+    // Key that may claim the authority after `succession_timeout_slots`
+    // without an authority action; default pubkey or 0 slots for none.
+    pub successor: Pubkey,
+    pub authority_last_active_slot: u64,
+    pub succession_timeout_slots: u64,
+    pub min_deposit: u64, // smallest treasury deposit in lamports, 0 for none
+    pub event_seq: u64, // `sequence` of the next event emitted for this registry
+}
+
+/// Power above `from`, up to the next tier's `from`, counts at `bps`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PowerTier {
+    pub from: u64,
+    pub bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DecayConfig {
+    pub grace_slots: u64,
+    pub period_slots: u64,
+    pub bps_per_period: u16,
+}
+
+impl MemberRegistry {
+    /// Allocated size, discriminator included, with the free-list and role
+    /// lists at capacity.
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 8 + 32 + 8 + 32 + 8 + 1 + 8 + 1
+        + 4 + MAX_FREE_MEMBER_IDS * 8
+        + 8 + 8 + 8 + 32 + 8 + 8 + 8 + 2 + 1 + 8 + 1 + 1 + 9 + 2 + 8 + 8
+        + 8 + 1 + 8 + 1
+        + 3 * (4 + MAX_ROLE_MEMBERS * 32)
+        + 8 + 8 + 8
+        + 4 + MAX_POWER_TIERS * (8 + 2)
+        + 8 + 8 + 1
+        + 8 + 1
+        + 8 + 8 + 1
+        + 1 + 8
+        + 32
+        + 32 + 8 + 8
+        + 8 + 8;
+
+    /// Stamps the authority as active when it is `signer`, putting off any
+    /// succession claim. Every instruction the authority may sign calls this.
+    pub fn note_activity(&mut self, signer: &Pubkey) -> Result<()> {
+        if *signer == self.authority {
+            self.authority_last_active_slot = Clock::get()?.slot;
+        }
+        Ok(())
+    }
+
+    /// Drops a departing member from the counts and, if enabled, frees their
+    /// ID for reuse. Once the free-list is full the ID is simply retired.
+    /// Pending applicants were never counted and hold no ID.
+    pub fn release(&mut self, member: &Member) {
+        if member.pending {
+            return;
+        }
+        self.member_count -= 1;
+        if member.is_active {
+            self.untrack_active(&member.member_type, member.voting_power);
+        }
+        if self.reuse_ids && self.free_member_ids.len() < MAX_FREE_MEMBER_IDS {
+            self.free_member_ids.push(member.id);
+        }
+        debug_assert_fits(self, Self::MAX_ACCOUNT_SIZE);
+    }
+
+    /// Whether `key` holds `role`. The authority holds every role.
+    pub fn has_role(&self, key: &Pubkey, role: &Role) -> bool {
+        *key == self.authority || self.role_members(role).contains(key)
+    }
+
+    pub fn role_members(&self, role: &Role) -> &Vec<Pubkey> {
+        match role {
+            Role::RegistrationAdmin => &self.registration_admins,
+            Role::TreasuryAdmin => &self.treasury_admins,
+            Role::ConfigAdmin => &self.config_admins,
+        }
+    }
+
+    fn role_members_mut(&mut self, role: &Role) -> &mut Vec<Pubkey> {
+        match role {
+            Role::RegistrationAdmin => &mut self.registration_admins,
+            Role::TreasuryAdmin => &mut self.treasury_admins,
+            Role::ConfigAdmin => &mut self.config_admins,
+        }
+    }
+
+    /// Hands out the next member ID, preferring a freed one when reuse is on.
+    pub fn assign_member_id(&mut self) -> u64 {
+        if self.reuse_ids {
+            if let Some(id) = self.free_member_ids.pop() {
+                return id;
+            }
+        }
+        self.next_member_id += 1;
+        self.next_member_id - 1
+    }
+
+    /// Total active power with AI members' share scaled by the multiplier.
+    /// This is the denominator quorum is measured against.
+    pub fn effective_total_power(&self) -> u64 {
+        let ai_scaled =
+            bps::apply_bps(self.ai_voting_power, self.ai_power_multiplier_bps).unwrap_or(u64::MAX);
+        self.total_voting_power - self.ai_voting_power + ai_scaled
+    }
+
+    /// Validates registering `entry`, paid for from `available_lamports`.
+    /// `nonce` is checked when the registration carries one. `member_in_use`
+    /// and `name_taken` say whether the member account and the name's
+    /// `NameEntry` are already held. Shared by every registration path and
+    /// `simulate_register`, so a simulation reports what the real
+    /// instruction would.
+    pub fn check_registration(
+        &self,
+        entry: &NewMember,
+        nonce: Option<u64>,
+        available_lamports: u64,
+        member_in_use: bool,
+        name_taken: bool,
+    ) -> RegistrationCheck {
+        let model_id = self.model_id_check(&entry.member_type, &entry.model_id);
+        if nonce.is_some_and(|nonce| nonce != self.registration_nonce) {
+            RegistrationCheck::InvalidNonce
+        } else if self.roster_sealed {
+            RegistrationCheck::RosterSealed
+        } else if self.max_members != 0 && self.member_count >= self.max_members {
+            RegistrationCheck::MaxMembersReached
+        } else if member_in_use {
+            RegistrationCheck::DuplicateMember
+        } else if entry.legal_name.len() > MAX_LEGAL_NAME_LEN {
+            RegistrationCheck::NameTooLong
+        } else if name_taken {
+            RegistrationCheck::NameTaken
+        } else if model_id != RegistrationCheck::Ok {
+            model_id
+        } else if self.check_contact_hash(&entry.contact_hash).is_err() {
+            RegistrationCheck::ContactHashRequired
+        } else if self.check_agreement(&entry.acknowledged_hash).is_err() {
+            RegistrationCheck::AgreementNotAcknowledged
+        } else if self.check_human_majority(&entry.member_type).is_err() {
+            RegistrationCheck::WouldBreakHumanMajority
+        } else if available_lamports < self.registration_fee {
+            RegistrationCheck::InsufficientFunds
+        } else if self.check_normalized_total(entry.voting_power).is_err() {
+            RegistrationCheck::ExceedsNormalizedTotal
+        } else if self.check_member_power(entry.voting_power).is_err() {
+            RegistrationCheck::ExceedsMemberPowerCap
+        } else {
+            RegistrationCheck::Ok
+        }
+    }
+
+    /// Consumes `nonce` if it is the current registration nonce.
+    pub fn use_registration_nonce(&mut self, nonce: u64) -> Result<()> {
+        require!(nonce == self.registration_nonce, MembershipError::InvalidNonce);
+        self.registration_nonce += 1;
+        Ok(())
+    }
+
+    /// Validates the model identifier given for a member of `member_type`.
+    /// Only AI members may have one, and they must when `require_model_id`
+    /// is on.
+    pub fn check_model_id(&self, member_type: &MemberType, model_id: &str) -> Result<()> {
+        self.model_id_check(member_type, model_id).into_result()
+    }
+
+    fn model_id_check(&self, member_type: &MemberType, model_id: &str) -> RegistrationCheck {
+        if model_id.len() > MAX_MODEL_ID_LEN {
+            RegistrationCheck::ModelIdTooLong
+        } else if !matches!(member_type, MemberType::AI) && !model_id.is_empty() {
+            RegistrationCheck::ModelIdNotAllowed
+        } else if matches!(member_type, MemberType::AI) && self.require_model_id && model_id.is_empty() {
+            RegistrationCheck::ModelIdRequired
+        } else {
+            RegistrationCheck::Ok
+        }
+    }
+
+    /// Fails unless `acknowledged_hash` matches the registry's operating
+    /// agreement. Registries without an agreement accept any hash.
+    pub fn check_agreement(&self, acknowledged_hash: &[u8; 32]) -> Result<()> {
+        require!(
+            self.agreement_hash == [0; 32] || *acknowledged_hash == self.agreement_hash,
+            MembershipError::AgreementNotAcknowledged
+        );
+        Ok(())
+    }
+
+    /// Fails on an all-zero contact hash while `require_contact_hash` is on.
+    pub fn check_contact_hash(&self, contact_hash: &[u8; 32]) -> Result<()> {
+        require!(
+            !self.require_contact_hash || *contact_hash != [0; 32],
+            MembershipError::ContactHashRequired
+        );
+        Ok(())
+    }
+
+    /// When dues first fall due for a member joining at `joined_at`: one
+    /// period later. Members who join while no dues are charged owe from
+    /// the day they joined, so switching dues on lapses them at the next
+    /// `charge_dues` until they pay.
+    pub fn dues_due_from(&self, joined_at: i64) -> i64 {
+        joined_at.saturating_add(self.dues_period_secs)
+    }
+
+    /// Whether instructions on this registry emit events and logs of `level`.
+    pub fn logs(&self, level: EventVerbosity) -> bool {
+        self.event_verbosity >= level
+    }
+
+    /// Hands out the `sequence` for an event about to be emitted. Numbers
+    /// run from 0 without gaps across instructions, so a client that sees
+    /// one skipped knows it missed an event.
+    pub fn next_event_seq(&mut self) -> u64 {
+        let sequence = self.event_seq;
+        self.event_seq += 1;
+        sequence
+    }
+
+    /// Voting power `raw` counts for once the progressive tiers are applied.
+    /// Power below the first tier counts in full and each tier's slice at
+    /// its `bps`. Every slice is rounded down on its own, so the result
+    /// never decreases as `raw` grows.
+    pub fn effective_power_tiered(&self, raw: u64) -> u64 {
+        let mut effective = raw.min(self.power_tiers.first().map_or(u64::MAX, |tier| tier.from));
+        for (i, tier) in self.power_tiers.iter().enumerate() {
+            if raw <= tier.from {
+                break;
+            }
+            let end = self.power_tiers.get(i + 1).map_or(raw, |next| raw.min(next.from));
+            let counted = bps::apply_bps(end - tier.from, tier.bps).unwrap_or(u64::MAX);
+            effective = effective.saturating_add(counted);
+        }
+        effective
+    }
+
+    /// Fails if `amount` lamports exceeds the spending cap for `category`.
+    pub fn check_spending_cap(&self, category: &SpendingCategory, amount: u64) -> Result<()> {
+        let cap = match category {
+            SpendingCategory::Operational => self.operational_cap,
+            SpendingCategory::Financial => self.financial_cap,
+        };
+        require!(cap == 0 || amount <= cap, MembershipError::SpendingCapExceeded);
+        Ok(())
+    }
+
+    /// Fails with `DepositTooSmall` if a treasury deposit of `amount` is
+    /// below `min_deposit`.
+    pub fn check_deposit(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_deposit, MembershipError::DepositTooSmall);
+        Ok(())
+    }
+
+    /// Fails if a treasury holding `balance` lamports, of which `rent_exempt`
+    /// is its rent-exempt minimum, would drop below `treasury_min_balance`
+    /// on top of that minimum by paying out `amount`.
+    pub fn check_treasury_minimum(&self, balance: u64, amount: u64, rent_exempt: u64) -> Result<()> {
+        if self.treasury_min_balance == 0 {
+            return Ok(());
+        }
+        let floor = rent_exempt.saturating_add(self.treasury_min_balance);
+        require!(
+            balance.checked_sub(amount).is_some_and(|left| left >= floor),
+            MembershipError::TreasuryMinimumBreached
+        );
+        Ok(())
+    }
+
+    /// Fails if admitting a member of `member_type` would leave active AI
+    /// members outnumbering human ones while `require_human_majority_count`
+    /// is on.
+    pub fn check_human_majority(&self, member_type: &MemberType) -> Result<()> {
+        require!(
+            !self.require_human_majority_count
+                || !matches!(member_type, MemberType::AI)
+                || self.ai_member_count < self.human_member_count,
+            MembershipError::WouldBreakHumanMajority
+        );
+        Ok(())
+    }
+
+    /// Fails if a single member holding `power` would exceed `max_member_power`.
+    pub fn check_member_power(&self, power: u64) -> Result<()> {
+        require!(
+            self.max_member_power == 0 || power <= self.max_member_power,
+            MembershipError::MemberPowerCapExceeded
+        );
+        Ok(())
+    }
+
+    /// Fails if `extra_power` would lift the total past `normalized_total`.
+    pub fn check_normalized_total(&self, extra_power: u64) -> Result<()> {
+        if let Some(normalized_total) = self.normalized_total {
+            require!(
+                self.total_voting_power
+                    .checked_add(extra_power)
+                    .is_some_and(|total| total <= normalized_total),
+                MembershipError::ExceedsNormalizedTotal
+            );
+        }
+        Ok(())
+    }
+
+    pub fn track_active(&mut self, member_type: &MemberType, voting_power: u64) {
+        self.total_voting_power += voting_power;
+        self.active_member_count += 1;
+        match member_type {
+            MemberType::AI => {
+                self.ai_voting_power += voting_power;
+                self.ai_member_count += 1;
+            }
+            MemberType::Human => {
+                self.human_voting_power += voting_power;
+                self.human_member_count += 1;
+            }
+            MemberType::Organization => {}
+        }
+    }
+
+    pub fn untrack_active(&mut self, member_type: &MemberType, voting_power: u64) {
+        self.total_voting_power -= voting_power;
+        self.active_member_count -= 1;
+        match member_type {
+            MemberType::AI => {
+                self.ai_voting_power -= voting_power;
+                self.ai_member_count = self.ai_member_count.saturating_sub(1);
+            }
+            MemberType::Human => {
+                self.human_voting_power -= voting_power;
+                self.human_member_count = self.human_member_count.saturating_sub(1);
+            }
+            MemberType::Organization => {}
+        }
+    }
+}
+
+/// Append-only record of every privileged instruction, kept for compliance.
+/// Actions members take on their own accounts are not recorded, so they
+/// cannot fill the log. A full log is rotated into a fresh account that
+/// links back to it.
+#[account]
+pub struct AuditLog {
+    pub registry: Pubkey,
+    pub previous: Pubkey,
+    pub next_offset: u64,
+    pub entries: Vec<AuditEntry>,
+}
+
+impl AuditLog {
+    /// Allocated size, discriminator included, with every entry slot used.
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 4 + AUDIT_LOG_CAPACITY * (8 + 1 + 32 + 32);
+
+    pub fn append(&mut self, kind: InstructionKind, actor: Pubkey, target: Pubkey) -> Result<()> {
+        require!(
+            (self.next_offset as usize) < AUDIT_LOG_CAPACITY,
+            MembershipError::AuditLogFull
+        );
+        self.entries.push(AuditEntry {
+            slot: Clock::get()?.slot,
+            kind,
+            actor,
+            target,
+        });
+        self.next_offset += 1;
+        debug_assert_fits(self, Self::MAX_ACCOUNT_SIZE);
+        Ok(())
+    }
+}
+
+/// Progress of importing a signed roster manifest, opened by
+/// `begin_roster_import`.
+#[account]
+pub struct RosterImport {
+    pub registry: Pubkey,
+    pub manifest_root: [u8; 32],
+    pub entry_count: u64,
+    pub cursor: u64, // manifest positions below this have been submitted
+}
+
+impl RosterImport {
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 32 + 8 + 8;
+}
+
+/// Reserves a legal name for one member while the registry requires
+/// unique names. Lives at `name_entry_address` and is closed when the
+/// member leaves or is removed.
+#[account]
+pub struct NameEntry {
+    pub registry: Pubkey,
+    pub member: Pubkey, // member account holding the name
+}
+
+impl NameEntry {
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 32;
+}
+
+/// One member's endorsement of another, at the PDA seeded by both
+/// member accounts.
+#[account]
+pub struct Endorsement {
+    pub registry: Pubkey,
+    pub endorser: Pubkey, // member account
+    pub endorsee: Pubkey, // member account
+    pub endorsee_id: u64,
+    pub created_at: i64,
+}
+
+impl Endorsement {
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8;
+}
+
+/// A member ID handed out by `reserve_member_id` and not yet used by
+/// `commit_member`.
+#[account]
+pub struct MemberIdReservation {
+    pub registry: Pubkey,
+    pub id: u64,
+    pub reserved_by: Pubkey,
+}
+
+impl MemberIdReservation {
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 8 + 32;
+}
+
+/// Roster of a registry as it stood at `slot`, written once by
+/// `snapshot_members` and never modified.
+#[account]
+pub struct Snapshot {
+    pub registry: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub entries: Vec<SnapshotEntry>,
+}
+
+impl Snapshot {
+    /// Allocated size, discriminator included, with every entry slot used.
+    pub const MAX_ACCOUNT_SIZE: usize = 8 + 32 + 8 + 8 + 4 + MAX_SNAPSHOT_MEMBERS * (8 + 32 + 8 + 1);
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SnapshotEntry {
+    pub id: u64,
+    pub pubkey: Pubkey,
+    pub voting_power: u64,
+    pub is_ai: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditEntry {
+    pub slot: u64,
+    pub kind: InstructionKind,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum InstructionKind {
+    Initialize,
+    AddMember,
+    RotateAuditLog,
+    SetGovernance,
+    UpdateConfig,
+    RemoveMember,
+    RecomputeAggregates,
+    SetTreasury,
+    LeaveMembership,
+    ApproveMembership,
+    SealRoster,
+    AdjustVotingPower,
+    SetSponsor,
+    FlagDispute,
+    ResolveDispute,
+    TransferVotingPower,
+    SnapshotMembers,
+    SetModelId,
+    SetVesting,
+    SetRoleMembers,
+    SetMemberMetadata,
+    ReserveMemberId,
+    BeginRosterImport,
+    SetPowerTiers,
+    ReassignControl,
+    RenounceCapability,
+    ReconcileMemberCount,
+    ChargeDues,
+    SetAgreementHash,
+    SetSuccessor,
+    ClaimAuthority,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum RegistryField {
+    MaxMembers,
+    MaxDelegationDepth,
+    ReuseIds,
+    RegistrationFee,
+    DecayGraceSlots,
+    DecayPeriodSlots,
+    DecayBpsPerPeriod,
+    SelfRegistration,
+    DefaultVotingPower,
+    RequireAiSponsor,
+    NormalizedTotal, // 0 clears it
+    AiPowerMultiplierBps,
+    RentBufferLamports,
+    MaxMemberPower,
+    RequireModelId,
+    VotingEligibilityDelaySecs,
+    RequireContactHash,
+    OperationalCap, // 0 means no cap
+    FinancialCap, // 0 means no cap
+    TreasuryMinBalance, // 0 means no minimum
+    RequireHumanMajorityCount,
+    EventVerbosity, // 0 off, 1 errors only, 2 all
+    DuesAmount,
+    DuesPeriodSecs, // 0 turns dues off
+    UniqueNames,
+    MaxVotesPerWindow, // 0 turns the limit off
+    VoteWindowSlots,
+    SuccessionTimeoutSlots, // 0 disables succession
+    MinDeposit, // 0 means no minimum
+}
+
+#[account]
+pub struct Member {
+    pub pubkey: Pubkey,
+    pub member_type: MemberType,
+    pub voting_power: u64,
+    pub joined_at: i64,
+    pub is_active: bool,
+    // Wyoming DAO LLC Compliance Fields
+    pub legal_name: String,
+    pub address: String,
+    pub tax_id: String, // SSN for individuals, EIN for entities
+    pub kyc_verified: bool,
+    pub registry: Pubkey,
+    pub delegate: Option<Pubkey>, // member account this member's vote is delegated to
+    pub id: u64,
+    pub last_active_slot: u64,
+    pub pending: bool, // applied via self-registration, awaiting approval
+    // Provenance, set once when the account is created
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub sponsor: Option<Pubkey>, // human co-signer for AI members
+    pub parent: Option<u64>, // ID of the human member a sub-agent belongs to
+    pub sub_agent_power: u64, // combined power of this member's sub-agents
+    pub under_dispute: bool, // barred from voting until resolved
+    // Participation, see `record_activity` for how eligibility is counted
+    pub votes_cast: u64,
+    pub proposals_eligible: u64,
+    pub eligible_from: Option<u64>,
+    pub model_id: String, // disclosed by AI members, empty for everyone else
+    pub contact_hash: [u8; 32], // hash of off-chain contact details, all zero if unset
+    // Vesting schedule, see `vested_power`
+    pub vesting_start: i64,
+    pub vesting_duration: i64,
+    pub vesting_total: u64,
+    pub metadata_uri: String, // off-chain profile, maintained by the member
+    pub dues_paid_through: i64, // deactivated by `charge_dues` once this passes
+    pub dues_lapsed: bool, // deactivated for unpaid dues; `pay_dues` reactivates
+    pub endorsement_count: u64, // endorsements received, one per endorsing member
+    pub recent_vote_slots: Vec<u64>, // slots of votes inside the rate-limit window, oldest first
+    pub agreement_hash: [u8; 32], // operating agreement acknowledged at registration
+    // Keys that jointly vote for a member converted by
+    // `convert_to_multisig_member`; a threshold of 0 means `pubkey` alone.
+    pub multisig_signers: Vec<Pubkey>,
+    pub multisig_threshold: u8,
+    pub eth_address: [u8; 20], // Ethereum key that may sign the member's votes, zero for none
+}
+
+/// Numeric member attribute `aggregate_attribute` can average.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum MemberAttribute {
+    VotingPower,
+    TenureSecs,
+    VotesCast,
+    ParticipationBps,
+    EndorsementCount,
+}
+
+impl MemberAttribute {
+    /// This attribute's value for `member` at `now`.
+    pub fn value(&self, member: &Member, now: i64) -> u64 {
+        match self {
+            MemberAttribute::VotingPower => member.voting_power,
+            MemberAttribute::TenureSecs => now.saturating_sub(member.joined_at).max(0) as u64,
+            MemberAttribute::VotesCast => member.votes_cast,
+            MemberAttribute::ParticipationBps => member.participation_bps(),
+            MemberAttribute::EndorsementCount => member.endorsement_count,
+        }
+    }
+}
+
+/// Outcome of `MemberRegistry::check_registration`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum RegistrationCheck {
+    Ok,
+    RosterSealed,
+    MaxMembersReached,
+    NameTooLong,
+    InsufficientFunds,
+    ExceedsNormalizedTotal,
+    ExceedsMemberPowerCap,
+    InvalidNonce,
+    DuplicateMember,
+    NameTaken,
+    ModelIdTooLong,
+    ModelIdRequired,
+    ModelIdNotAllowed,
+    ContactHashRequired,
+    AgreementNotAcknowledged,
+    WouldBreakHumanMajority,
+}
+
+impl RegistrationCheck {
+    pub fn into_result(self) -> Result<()> {
+        match self {
+            RegistrationCheck::Ok => Ok(()),
+            RegistrationCheck::RosterSealed => err!(MembershipError::RosterSealed),
+            RegistrationCheck::MaxMembersReached => err!(MembershipError::MaxMembersReached),
+            RegistrationCheck::NameTooLong => err!(MembershipError::NameTooLong),
+            RegistrationCheck::InsufficientFunds => err!(MembershipError::InsufficientFunds),
+            RegistrationCheck::ExceedsNormalizedTotal => {
+                err!(MembershipError::ExceedsNormalizedTotal)
+            }
+            RegistrationCheck::ExceedsMemberPowerCap => {
+                err!(MembershipError::MemberPowerCapExceeded)
+            }
+            RegistrationCheck::InvalidNonce => err!(MembershipError::InvalidNonce),
+            RegistrationCheck::DuplicateMember | RegistrationCheck::NameTaken => {
+                err!(MembershipError::MemberAlreadyExists)
+            }
+            RegistrationCheck::ModelIdTooLong => err!(MembershipError::ModelIdTooLong),
+            RegistrationCheck::ModelIdRequired => err!(MembershipError::ModelIdRequired),
+            RegistrationCheck::ModelIdNotAllowed => err!(MembershipError::ModelIdNotAllowed),
+            RegistrationCheck::ContactHashRequired => err!(MembershipError::ContactHashRequired),
+            RegistrationCheck::AgreementNotAcknowledged => {
+                err!(MembershipError::AgreementNotAcknowledged)
+            }
+            RegistrationCheck::WouldBreakHumanMajority => {
+                err!(MembershipError::WouldBreakHumanMajority)
+            }
+        }
+    }
+}
+
+/// Snapshot of a registry and some of its members, produced by
+/// `export_state` for backups and migrations.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StateExport {
+    pub version: u8,
+    pub registry: Pubkey,
+    pub state: MemberRegistry,
+    pub members: Vec<Member>,
+}
+
+/// Outcome of `validate_roster`. The roster is complete when all three
+/// counts are zero.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RosterReport {
+    pub missing_count: u64,
+    pub duplicate_count: u64,
+    pub out_of_range_count: u64,
+    pub first_missing: Option<u64>,
+    pub first_duplicate: Option<u64>,
+}
+
+/// A member's delegation and endorsement links, as returned by
+/// `get_member_relations`. Keys are member accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MemberRelations {
+    pub delegated_to: Option<Pubkey>,
+    pub delegated_from: Vec<Pubkey>, // only among the accounts passed
+    pub endorsers: Vec<Pubkey>,      // only among the accounts passed
+    pub endorsement_count: u64,      // every endorsement received
+}
+
+/// One registration in an `add_members_batch` call.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NewMember {
+    pub pubkey: Pubkey,
+    pub member_type: MemberType,
+    pub voting_power: u64,
+    pub legal_name: String,
+    pub address: String,
+    pub tax_id: String,
+    pub model_id: String,
+    pub contact_hash: [u8; 32],
+    pub acknowledged_hash: [u8; 32], // must match the registry's `agreement_hash`
+}
+
+impl Member {
+    /// Share of eligible proposals the member voted on, in basis points.
+    pub fn participation_bps(&self) -> u64 {
+        bps::ratio_bps(self.votes_cast, self.proposals_eligible).unwrap_or(0)
+    }
+
+    /// Allocated size, discriminator included, with every string at its limit.
+    pub const MAX_ACCOUNT_SIZE: usize =
+        8 + 32 + 1 + 8 + 8 + 1 + 256 + 512 + 64 + 1 + 32 + 33 + 8 + 8 + 1 + 32 + 8 + 33 + 9 + 8 + 1
+        + 8 + 8 + 9 + 4 + MAX_MODEL_ID_LEN + 32
+        + 8 + 8 + 8 + 4 + MAX_METADATA_URI_LEN
+        + 8 + 1
+        + 8
+        + 4 + MAX_VOTES_PER_WINDOW * 8
+        + 32
+        + 4 + MAX_MEMBER_SIGNERS * 32 + 1
+        + 20;
+
+    /// Whether `key` may act for this member in a vote: its own key, or
+    /// once converted to a multisig, any of its signers.
+    pub fn controlled_by(&self, key: &Pubkey) -> bool {
+        if self.multisig_threshold == 0 {
+            *key == self.pubkey
+        } else {
+            self.multisig_signers.contains(key)
+        }
+    }
+
+    /// Records a vote cast at `slot` against the registry's rate limit,
+    /// first forgetting votes that have left the window. Fails with
+    /// `VoteRateLimited` if the window already holds the maximum.
+    pub fn record_vote_slot(&mut self, registry: &MemberRegistry, slot: u64) -> Result<()> {
+        if registry.max_votes_per_window == 0 {
+            self.recent_vote_slots.clear();
+            return Ok(());
+        }
+        self.recent_vote_slots
+            .retain(|&cast| slot.saturating_sub(cast) < registry.vote_window_slots);
+        require!(
+            self.recent_vote_slots.len() < registry.max_votes_per_window as usize,
+            MembershipError::VoteRateLimited
+        );
+        self.recent_vote_slots.push(slot);
+        Ok(())
+    }
+}
+
+// Every account must fit within what the runtime lets a program allocate.
+const _: () = assert!(MemberRegistry::MAX_ACCOUNT_SIZE as u64 <= MAX_PERMITTED_DATA_LENGTH);
+const _: () = assert!(AuditLog::MAX_ACCOUNT_SIZE as u64 <= MAX_PERMITTED_DATA_LENGTH);
+const _: () = assert!(Member::MAX_ACCOUNT_SIZE as u64 <= MAX_PERMITTED_DATA_LENGTH);
+const _: () = assert!(Snapshot::MAX_ACCOUNT_SIZE as u64 <= MAX_PERMITTED_DATA_LENGTH);
+
+/// Debug-build check that an account's data still fits its allocation; the
+/// capacity guards in the write helpers keep this true in release builds.
+fn debug_assert_fits<T: AnchorSerialize>(account: &T, max_account_size: usize) {
+    debug_assert!(
+        8 + anchor_lang::prelude::borsh::to_vec(account).map_or(0, |data| data.len())
+            <= max_account_size
+    );
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum MemberType {
+    Human,
+    AI,
+    Organization,
+}
+
+/// What a governance proposal spends treasury funds on. Each category has
+/// its own spending cap on the registry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum SpendingCategory {
+    Operational,
+    Financial,
+}
+
+/// Powers the authority can renounce for good with `renounce_capability`.
+/// The discriminant is the capability's bit in `renounced_capabilities`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum Capability {
+    UpdateVotingPower, // adjust, transfer and vest voting power
+    AddMembers, // every authority-side registration path
+    RemoveMembers, // removal and upheld disputes
+    UpdateConfig, // registry settings and power tiers
+    SetGovernance,
+    SetTreasury,
+    ManageRoles, // role assignment and control handoff
+}
+
+impl Capability {
+    pub fn bit(self) -> u64 {
+        1 << self as u8
+    }
+}
+
+/// Which events and `msg!` logs instructions emit. `Errors` keeps only those
+/// reporting a failure or a problem found, such as `SuspiciousActivity`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, PartialOrd)]
+pub enum EventVerbosity {
+    Off,
+    Errors,
+    All,
+}
+
+/// Delegated administrative rights. Registration covers adding, approving,
+/// adjusting and removing members; treasury covers where fees are paid;
+/// config covers registry settings, governance, sealing and the roles
+/// themselves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum Role {
+    RegistrationAdmin,
+    TreasuryAdmin,
+    ConfigAdmin,
+}
+
+/// A failed authority check, against the registry whose authority is
+/// `authority`. The instruction fails, rolling back any registry change,
+/// so unlike other events this one carries no `sequence`.
+#[event]
+pub struct SuspiciousActivity {
+    pub authority: Pubkey,
+    pub signer: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ControlReassigned {
+    pub registry: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub treasury_admin: Pubkey,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct AuthorityClaimed {
+    pub registry: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct DisputeFlagged {
+    pub registry: Pubkey,
+    pub member_id: u64,
+    pub reason: String,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub registry: Pubkey,
+    pub member_id: u64,
+    pub upheld: bool,
+    pub sequence: u64,
+}
+
+#[error_code]
+pub enum MembershipError {
+    #[msg("Audit log is full; rotate to a new log")]
+    AuditLogFull,
+    #[msg("Signer is not authorized for this action")]
+    NotAuthorized,
+    #[msg("Registry has reached its maximum number of members")]
+    MaxMembersReached,
+    #[msg("Account data has an unexpected length or layout")]
+    InvalidAccountData,
+    #[msg("Config value is out of range")]
+    InvalidConfigValue,
+    #[msg("Delegator does not resolve to the voting member")]
+    InvalidDelegation,
+    #[msg("Delegation chain contains a cycle")]
+    DelegationCycle,
+    #[msg("Delegation chain exceeds the maximum depth")]
+    DelegationTooDeep,
+    #[msg("Member account does not match the expected member")]
+    InvalidMemberAccount,
+    #[msg("Every member account must be passed exactly once")]
+    IncompleteMemberSet,
+    #[msg("Treasury account is missing or does not match the registry")]
+    InvalidTreasury,
+    #[msg("Insufficient funds for the registration fee")]
+    InsufficientFunds,
+    #[msg("Member account or legal name is already registered")]
+    MemberAlreadyExists,
+    #[msg("Self-registration is not enabled for this registry")]
+    SelfRegistrationDisabled,
+    #[msg("Member is not a pending applicant")]
+    MemberNotPending,
+    #[msg("The member roster is sealed")]
+    RosterSealed,
+    #[msg("Voting power adjustment would drop below zero")]
+    PowerUnderflow,
+    #[msg("Voting power adjustment overflows")]
+    PowerOverflow,
+    #[msg("AI member's sponsor must co-sign this action")]
+    SponsorSignatureRequired,
+    #[msg("Sub-agents' combined power would exceed the parent's allocation")]
+    SubAgentPowerExceeded,
+    #[msg("Change would exceed the registry's normalized voting power total")]
+    ExceedsNormalizedTotal,
+    #[msg("Member is under dispute")]
+    MemberUnderDispute,
+    #[msg("Member is not under dispute")]
+    MemberNotUnderDispute,
+    #[msg("Legal name exceeds the maximum length")]
+    NameTooLong,
+    #[msg("Registration nonce does not match the registry")]
+    InvalidNonce,
+    #[msg("Instruction data exceeds the maximum length")]
+    InvalidInstruction,
+    #[msg("Voting power exceeds the per-member cap")]
+    MemberPowerCapExceeded,
+    #[msg("Registry has more members than a snapshot can hold")]
+    SnapshotTooLarge,
+    #[msg("Model identifier exceeds the maximum length")]
+    ModelIdTooLong,
+    #[msg("AI members must disclose a model identifier")]
+    ModelIdRequired,
+    #[msg("Only AI members may have a model identifier")]
+    ModelIdNotAllowed,
+    #[msg("Member joined too recently to vote on this proposal")]
+    NotYetEligible,
+    #[msg("A non-zero contact hash is required")]
+    ContactHashRequired,
+    #[msg("Too many pubkeys for one role")]
+    TooManyRoleMembers,
+    #[msg("Transfer exceeds the spending cap for its category")]
+    SpendingCapExceeded,
+    #[msg("Metadata URI exceeds the maximum length")]
+    MetadataUriTooLong,
+    #[msg("Reservation does not hold this member ID")]
+    InvalidReservation,
+    #[msg("Withdrawal would leave the treasury below its minimum balance")]
+    TreasuryMinimumBreached,
+    #[msg("Registry has already handed out member IDs")]
+    RegistryNotEmpty,
+    #[msg("Manifest entry is out of range or fails its proof")]
+    InvalidManifestEntry,
+    #[msg("Registering this AI member would leave AI members outnumbering humans")]
+    WouldBreakHumanMajority,
+    #[msg("Member is not eligible to vote on this proposal")]
+    NotEligibleForProposal,
+    #[msg("This capability has been permanently renounced")]
+    CapabilityRenounced,
+    #[msg("Name entry is missing or does not match the member's name")]
+    InvalidNameEntry,
+    #[msg("Members cannot endorse themselves")]
+    SelfEndorsement,
+    #[msg("Member has cast too many votes within the rate-limit window")]
+    VoteRateLimited,
+    #[msg("Acknowledged hash does not match the registry's operating agreement")]
+    AgreementNotAcknowledged,
+    #[msg("Authority has not been inactive long enough to be succeeded")]
+    SuccessionNotDue,
+    #[msg("Deposit is below the treasury's minimum deposit")]
+    DepositTooSmall,
+    #[msg("Member signers must be distinct, at most the maximum, with a threshold between 1 and their number")]
+    InvalidMemberSigners,
+    #[msg("Not enough of the member's signers signed")]
+    MemberThresholdNotMet,
+    #[msg("Relation account does not reference this member")]
+    RelationMismatch,
+    #[msg("No secp256k1 signature by the member's Ethereum address over this message precedes the instruction")]
+    InvalidEthSignature,
+    #[msg("Registry aggregates do not account for this member's power; run recompute_aggregates")]
+    AggregateMismatch,
+    #[msg("Parent's sub-agent power does not cover this sub-agent's power")]
+    SubAgentPowerMismatch,
+    #[msg("Member's sub-agents still hold power; remove them first")]
+    HasSubAgents,
+    #[msg("Transfer exceeds the member's vested voting power")]
+    PowerStillVesting,
 }
@@ -0,0 +1,53 @@
+mod common;
+
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError};
+
+fn adjust(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    delta: i64,
+) -> std::result::Result<(), TxError> {
+    let ix = adjust_voting_power_ix(rt, registry, member, None, delta);
+    rt.send(ix, &[registry.authority])
+}
+
+#[test]
+fn positive_and_negative_deltas_keep_the_total_in_step() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 100);
+    add_member(&mut rt, &registry, MemberType::Human, 50);
+
+    adjust(&mut rt, &registry, &member, 25).unwrap();
+    assert_eq!(common::member(&rt, &member).voting_power, 125);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).total_voting_power, 175);
+
+    adjust(&mut rt, &registry, &member, -100).unwrap();
+    assert_eq!(common::member(&rt, &member).voting_power, 25);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).total_voting_power, 75);
+}
+
+#[test]
+fn delta_below_zero_is_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 100);
+
+    assert_error(adjust(&mut rt, &registry, &member, -101), MembershipError::PowerUnderflow);
+    assert_error(adjust(&mut rt, &registry, &member, i64::MIN), MembershipError::PowerUnderflow);
+    adjust(&mut rt, &registry, &member, -100).unwrap();
+    assert_eq!(common::member(&rt, &member).voting_power, 0);
+}
+
+#[test]
+fn drifted_total_is_reported_instead_of_wrapping() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 100);
+    rt.update(&registry.key, |state: &mut MemberRegistry| state.total_voting_power = 40);
+
+    assert_error(adjust(&mut rt, &registry, &member, 10), MembershipError::AggregateMismatch);
+    assert_eq!(common::member(&rt, &member).voting_power, 100);
+}
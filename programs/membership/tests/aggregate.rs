@@ -0,0 +1,74 @@
+mod common;
+
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{MemberAttribute, MemberType, MembershipError};
+
+fn try_aggregate(
+    rt: &mut Runtime,
+    registry: &Registry,
+    attribute: MemberAttribute,
+    members: &[&TestMember],
+) -> std::result::Result<(), TxError> {
+    let payer = rt.wallet(SOL);
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::AggregateAttribute { registry: registry.key },
+        membership::instruction::AggregateAttribute { attribute },
+    );
+    ix.accounts
+        .extend(members.iter().map(|m| AccountMeta::new_readonly(m.account, false)));
+    rt.send(ix, &[payer])
+}
+
+fn aggregate(
+    rt: &mut Runtime,
+    registry: &Registry,
+    attribute: MemberAttribute,
+    members: &[&TestMember],
+) -> u64 {
+    try_aggregate(rt, registry, attribute, members).unwrap();
+    rt.returned()
+}
+
+#[test]
+fn tenure_is_averaged_by_voting_power() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let founder = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let suspended = add_member(&mut rt, &registry, MemberType::Human, 1_000);
+    flag_dispute(&mut rt, &registry, &suspended);
+    resolve_dispute(&mut rt, &registry, &suspended, true);
+    rt.advance_time(1_000);
+    let newcomer = add_member(&mut rt, &registry, MemberType::AI, 30);
+    rt.advance_time(1_000);
+
+    // (10 * 2000 + 30 * 1000) / 40; the suspended member is left out.
+    let members = [&founder, &suspended, &newcomer];
+    assert_eq!(aggregate(&mut rt, &registry, MemberAttribute::TenureSecs, &members), 1_250);
+    // (10 * 10 + 30 * 30) / 40, rounded down.
+    assert_eq!(aggregate(&mut rt, &registry, MemberAttribute::VotingPower, &members), 25);
+}
+
+#[test]
+fn no_power_averages_to_zero() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let idle = add_member(&mut rt, &registry, MemberType::Human, 0);
+    rt.advance_time(1_000);
+
+    assert_eq!(aggregate(&mut rt, &registry, MemberAttribute::TenureSecs, &[&idle]), 0);
+    assert_eq!(aggregate(&mut rt, &registry, MemberAttribute::TenureSecs, &[]), 0);
+}
+
+#[test]
+fn member_passed_twice_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    assert_error(
+        try_aggregate(&mut rt, &registry, MemberAttribute::TenureSecs, &[&member, &member]),
+        MembershipError::MemberAlreadyExists,
+    );
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError};
+
+fn recompute_ix(rt: &Runtime, registry: &Registry, members: &[Pubkey]) -> Instruction {
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::RecomputeAggregates {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::RecomputeAggregates {},
+    );
+    ix.accounts
+        .extend(members.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    ix
+}
+
+fn totals(registry: &MemberRegistry) -> [u64; 6] {
+    [
+        registry.total_voting_power,
+        registry.ai_voting_power,
+        registry.human_voting_power,
+        registry.active_member_count,
+        registry.ai_member_count,
+        registry.human_member_count,
+    ]
+}
+
+#[test]
+fn recompute_heals_corrupted_aggregates() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let human = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let ai = add_member(&mut rt, &registry, MemberType::AI, 40);
+    let expected = totals(&rt.get(&registry.key));
+    assert_eq!(expected, [140, 40, 100, 2, 1, 1]);
+
+    rt.update(&registry.key, |state: &mut MemberRegistry| {
+        state.total_voting_power = 9_999;
+        state.ai_voting_power = 0;
+        state.human_voting_power = 7;
+        state.active_member_count = 5;
+        state.ai_member_count = 0;
+        state.human_member_count = 3;
+    });
+    let ix = recompute_ix(&rt, &registry, &[human.account, ai.account]);
+    rt.send(ix, &[registry.authority]).unwrap();
+
+    assert_eq!(totals(&rt.get(&registry.key)), expected);
+}
+
+#[test]
+fn recompute_needs_every_member_once_and_only_member_accounts() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let second = add_member(&mut rt, &registry, MemberType::Human, 50);
+
+    let ix = recompute_ix(&rt, &registry, &[first.account]);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::IncompleteMemberSet);
+    let ix = recompute_ix(&rt, &registry, &[first.account, first.account]);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::IncompleteMemberSet);
+
+    // A copy of a member account owned by another program is refused.
+    let mut forged = rt.account(&second.account).unwrap().clone();
+    forged.owner = Pubkey::new_unique();
+    let forged_key = Pubkey::new_unique();
+    rt.set_account(forged_key, forged);
+    let ix = recompute_ix(&rt, &registry, &[first.account, forged_key]);
+    assert_error(
+        rt.send(ix, &[registry.authority]),
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram,
+    );
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberType, MembershipError, RegistryField};
+
+const AGREEMENT: [u8; 32] = [0xa5; 32];
+
+/// A registry open to applications that requires `AGREEMENT` to be
+/// acknowledged.
+fn registry_with_agreement(rt: &mut Runtime) -> Registry {
+    let registry = create_registry(rt);
+    update_config(rt, &registry, RegistryField::SelfRegistration, 1);
+    set_agreement_hash(rt, &registry, AGREEMENT);
+    registry
+}
+
+fn set_agreement_hash(rt: &mut Runtime, registry: &Registry, agreement_hash: [u8; 32]) {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetAgreementHash {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::SetAgreementHash { agreement_hash },
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+fn register(
+    rt: &mut Runtime,
+    registry: &Registry,
+    acknowledged_hash: [u8; 32],
+) -> std::result::Result<TestMember, TxError> {
+    let mut args = member_args(rt, registry, MemberType::Human, 10);
+    args.acknowledged_hash = acknowledged_hash;
+    try_add_member(rt, registry, args)
+}
+
+fn apply(
+    rt: &mut Runtime,
+    registry: &Registry,
+    acknowledged_hash: [u8; 32],
+) -> std::result::Result<TestMember, TxError> {
+    let applicant = TestMember {
+        account: Pubkey::new_unique(),
+        key: rt.wallet(SOL),
+    };
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::ApplyForMembership {
+            registry: registry.key,
+            member: applicant.account,
+            treasury: None,
+            name_entry: None,
+            applicant: applicant.key,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::ApplyForMembership {
+            member_type: MemberType::Human,
+            legal_name: format!("Applicant {}", applicant.key),
+            address: "1 Main St, Cheyenne WY".to_string(),
+            tax_id: "00-0000000".to_string(),
+            model_id: String::new(),
+            contact_hash: [0; 32],
+            acknowledged_hash,
+        },
+    );
+    rt.send(ix, &[applicant.account, applicant.key])?;
+    Ok(applicant)
+}
+
+#[test]
+fn registration_records_the_acknowledged_agreement() {
+    let mut rt = Runtime::new();
+    let registry = registry_with_agreement(&mut rt);
+
+    let registered = register(&mut rt, &registry, AGREEMENT).unwrap();
+    let applicant = apply(&mut rt, &registry, AGREEMENT).unwrap();
+
+    assert_eq!(member(&rt, &registered).agreement_hash, AGREEMENT);
+    assert_eq!(member(&rt, &applicant).agreement_hash, AGREEMENT);
+}
+
+#[test]
+fn mismatched_acknowledgement_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = registry_with_agreement(&mut rt);
+
+    for acknowledged in [[0; 32], [0x5a; 32]] {
+        assert_error(register(&mut rt, &registry, acknowledged), MembershipError::AgreementNotAcknowledged);
+        assert_error(apply(&mut rt, &registry, acknowledged), MembershipError::AgreementNotAcknowledged);
+    }
+}
+
+#[test]
+fn no_acknowledgement_needed_without_an_agreement() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+
+    let registered = register(&mut rt, &registry, [0; 32]).unwrap();
+    assert_eq!(member(&rt, &registered).agreement_hash, [0; 32]);
+}
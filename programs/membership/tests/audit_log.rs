@@ -0,0 +1,100 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{AuditLog, InstructionKind, MemberType, MembershipError, RegistryField};
+
+#[test]
+fn audit_log_appends_privileged_actions_in_order() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::MaxMembers, 10);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 100);
+
+    let log: AuditLog = rt.get(&registry.audit_log);
+    assert_eq!(log.registry, registry.key);
+    assert_eq!(log.previous, Pubkey::default());
+    assert_eq!(log.next_offset, 3);
+    let recorded: Vec<_> = log
+        .entries
+        .iter()
+        .map(|entry| (entry.kind.clone(), entry.actor, entry.target))
+        .collect();
+    assert!(
+        recorded
+            == vec![
+                (InstructionKind::Initialize, registry.authority, registry.key),
+                (InstructionKind::UpdateConfig, registry.authority, registry.key),
+                (InstructionKind::AddMember, registry.authority, member.account),
+            ]
+    );
+}
+
+#[test]
+fn member_self_service_does_not_fill_the_audit_log() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let delegate = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let entries_before = rt.get::<AuditLog>(&registry.audit_log).next_offset;
+
+    for _ in 0..membership::AUDIT_LOG_CAPACITY {
+        let set = instruction(
+            membership::ID,
+            membership::accounts::SetDelegate {
+                registry: registry.key,
+                member: member.account,
+                delegate_member: delegate.account,
+                owner: member.key,
+            },
+            membership::instruction::SetDelegate {},
+        );
+        let clear = instruction(
+            membership::ID,
+            membership::accounts::ClearDelegate {
+                registry: registry.key,
+                member: member.account,
+                owner: member.key,
+            },
+            membership::instruction::ClearDelegate {},
+        );
+        rt.send_all(&[set, clear], &[member.key]).unwrap();
+    }
+
+    assert_eq!(rt.get::<AuditLog>(&registry.audit_log).next_offset, entries_before);
+    update_config(&mut rt, &registry, RegistryField::MaxMembers, 10);
+}
+
+#[test]
+fn full_audit_log_blocks_until_rotated() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    for _ in 1..membership::AUDIT_LOG_CAPACITY {
+        update_config(&mut rt, &registry, RegistryField::MaxMembers, 10);
+    }
+    let ix = update_config_ix(&rt, &registry, RegistryField::MaxMembers, 10);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::AuditLogFull);
+
+    let new_audit_log = Pubkey::new_unique();
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::RotateAuditLog {
+                registry: registry.key,
+                new_audit_log,
+                authority: registry.authority,
+                system_program: anchor_lang::system_program::ID,
+            },
+            membership::instruction::RotateAuditLog {},
+        ),
+        &[new_audit_log, registry.authority],
+    )
+    .unwrap();
+    update_config(&mut rt, &registry, RegistryField::MaxMembers, 10);
+
+    let log: AuditLog = rt.get(&new_audit_log);
+    assert_eq!(log.previous, registry.audit_log);
+    assert_eq!(log.next_offset, 2);
+    assert!(log.entries[0].kind == InstructionKind::RotateAuditLog);
+    assert_eq!(log.entries[0].target, registry.audit_log);
+}
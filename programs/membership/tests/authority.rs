@@ -0,0 +1,53 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{assert_authority, assert_signer, MemberRegistry, MembershipError, RegistryField};
+
+/// Runs `check` against an account for `key`, signed or not.
+fn with_account<R>(key: Pubkey, is_signer: bool, check: impl FnOnce(&AccountInfo) -> R) -> R {
+    let (mut lamports, mut data, owner) = (0u64, Vec::new(), Pubkey::default());
+    let info = AccountInfo::new(&key, is_signer, false, &mut lamports, &mut data, &owner, false, 0);
+    check(&info)
+}
+
+fn not_authorized(result: Result<()>) -> bool {
+    result.err() == Some(MembershipError::NotAuthorized.into())
+}
+
+#[test]
+fn assert_signer_requires_a_signature() {
+    let _rt = Runtime::new();
+    let key = Pubkey::new_unique();
+    assert!(with_account(key, true, assert_signer).is_ok());
+    assert!(not_authorized(with_account(key, false, assert_signer)));
+}
+
+#[test]
+fn assert_authority_requires_the_authority_to_sign() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let state: MemberRegistry = rt.get(&registry.key);
+
+    assert!(with_account(registry.authority, true, |info| assert_authority(info, &state)).is_ok());
+    assert!(not_authorized(with_account(registry.authority, false, |info| {
+        assert_authority(info, &state)
+    })));
+    assert!(not_authorized(with_account(Pubkey::new_unique(), true, |info| {
+        assert_authority(info, &state)
+    })));
+}
+
+#[test]
+fn config_change_by_another_key_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let impostor = rt.wallet(SOL);
+    let mut ix = update_config_ix(&rt, &registry, RegistryField::MaxMembers, 10);
+    for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == registry.authority) {
+        meta.pubkey = impostor;
+    }
+
+    assert_error(rt.send(ix, &[impostor]), MembershipError::NotAuthorized);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).max_members, 0);
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, NewMember, RegistryField};
+
+/// Registers `entries` into `accounts`, in order, as the registry authority.
+fn add_batch(
+    rt: &mut Runtime,
+    registry: &Registry,
+    entries: Vec<NewMember>,
+    accounts: &[Pubkey],
+) -> std::result::Result<(), TxError> {
+    let ix = add_members_batch_ix(rt, registry, entries, accounts);
+    let mut signers = accounts.to_vec();
+    signers.push(registry.authority);
+    rt.send(ix, &signers)
+}
+
+#[test]
+fn batch_assigns_ids_in_account_order() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 1);
+    let first_id = rt.get::<MemberRegistry>(&registry.key).next_member_id;
+    let entries = vec![new_member(10), new_member(20), new_member(30)];
+    let accounts = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+    add_batch(&mut rt, &registry, entries.clone(), &accounts).unwrap();
+
+    for (i, (account, entry)) in accounts.iter().zip(&entries).enumerate() {
+        let member: membership::Member = rt.get(account);
+        assert_eq!(member.id, first_id + i as u64);
+        assert_eq!(member.pubkey, entry.pubkey);
+        assert_eq!(member.voting_power, entry.voting_power);
+    }
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 4);
+}
+
+#[test]
+fn batch_rejects_a_repeated_member_account() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let account = Pubkey::new_unique();
+
+    assert_error(
+        add_batch(&mut rt, &registry, vec![new_member(10), new_member(20)], &[account, account]),
+        MembershipError::MemberAlreadyExists,
+    );
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 0);
+}
+
+#[test]
+fn batch_entries_are_held_to_the_registration_checks() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::MaxMemberPower, 50);
+    let accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let mut long_name = new_member(10);
+    long_name.legal_name = "x".repeat(membership::MAX_LEGAL_NAME_LEN + 1);
+    assert_error(
+        add_batch(&mut rt, &registry, vec![new_member(10), long_name], &accounts),
+        MembershipError::NameTooLong,
+    );
+    assert_error(
+        add_batch(&mut rt, &registry, vec![new_member(10), new_member(51)], &accounts),
+        MembershipError::MemberPowerCapExceeded,
+    );
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 0);
+
+    add_batch(&mut rt, &registry, vec![new_member(10), new_member(50)], &accounts).unwrap();
+}
+
+#[test]
+fn batch_rejects_an_existing_member_account() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let existing = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    assert_error(
+        add_batch(&mut rt, &registry, vec![new_member(20)], &[existing.account]),
+        MembershipError::MemberAlreadyExists,
+    );
+    assert_eq!(member(&rt, &existing).voting_power, 10);
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{
+    MemberRegistry, MemberType, MembershipError, PowerTier, Role, MAX_FREE_MEMBER_IDS, MAX_POWER_TIERS,
+    MAX_ROLE_MEMBERS,
+};
+
+#[test]
+fn full_free_list_drops_further_ids() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    rt.update(&registry.key, |state: &mut MemberRegistry| {
+        state.reuse_ids = true;
+        state.free_member_ids = (100..100 + MAX_FREE_MEMBER_IDS as u64).collect();
+    });
+    let size = rt.account(&registry.key).unwrap().data.len();
+
+    remove_member(&mut rt, &registry, &member);
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.free_member_ids.len(), MAX_FREE_MEMBER_IDS);
+    assert!(!state.free_member_ids.contains(&0));
+    assert_eq!(rt.account(&registry.key).unwrap().data.len(), size);
+}
+
+#[test]
+fn role_list_beyond_capacity_is_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let set_roles = |rt: &Runtime, count: usize| {
+        instruction(
+            membership::ID,
+            membership::accounts::SetRoleMembers {
+                registry: registry.key,
+                audit_log: current_audit_log(rt, &registry),
+                authority: registry.authority,
+            },
+            membership::instruction::SetRoleMembers {
+                role: Role::ConfigAdmin,
+                pubkeys: (0..count).map(|_| Pubkey::new_unique()).collect(),
+            },
+        )
+    };
+
+    let ix = set_roles(&rt, MAX_ROLE_MEMBERS + 1);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::TooManyRoleMembers);
+    let ix = set_roles(&rt, MAX_ROLE_MEMBERS);
+    rt.send(ix, &[registry.authority]).unwrap();
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).config_admins.len(), MAX_ROLE_MEMBERS);
+}
+
+#[test]
+fn power_tiers_beyond_capacity_are_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let set_tiers = |rt: &Runtime, count: u64| {
+        instruction(
+            membership::ID,
+            membership::accounts::SetPowerTiers {
+                registry: registry.key,
+                audit_log: current_audit_log(rt, &registry),
+                authority: registry.authority,
+            },
+            membership::instruction::SetPowerTiers {
+                tiers: (1..=count).map(|i| PowerTier { from: i * 100, bps: 5_000 }).collect(),
+            },
+        )
+    };
+
+    let ix = set_tiers(&rt, MAX_POWER_TIERS as u64 + 1);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::InvalidConfigValue);
+    let ix = set_tiers(&rt, MAX_POWER_TIERS as u64);
+    rt.send(ix, &[registry.authority]).unwrap();
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).power_tiers.len(), MAX_POWER_TIERS);
+}
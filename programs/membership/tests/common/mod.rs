@@ -0,0 +1,1224 @@
+//! An in-process runtime for the integration tests. Instructions run
+//! against real program code: accounts are serialized in the loader's
+//! input layout, handed to the program's `entry`, and read back, while
+//! syscalls (clock, rent, logging, return data and cross-program
+//! invocation) are answered by stubs. The system program and the
+//! Secp256k1 precompile are emulated, and each transaction is checked the
+//! way the runtime would: signatures, account ownership, balance and rent.
+//!
+//! Shared by the membership and governance tests; the governance tests
+//! include this file by path.
+#![allow(dead_code, deprecated)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::{deserialize, ProgramResult, MAX_PERMITTED_DATA_INCREASE};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
+use anchor_lang::solana_program::sysvar::instructions::{
+    construct_instructions_data, store_current_index, BorrowedAccountMeta, BorrowedInstruction,
+};
+use anchor_lang::solana_program::{bpf_loader_upgradeable, keccak, secp256k1_program, system_program, sysvar};
+use anchor_lang::{Discriminator, Event, InstructionData};
+
+/// A program's `entry` function.
+pub type Processor = for<'a> fn(&Pubkey, &'a [AccountInfo<'a>], &[u8]) -> ProgramResult;
+
+/// Lamports every test wallet starts with.
+pub const SOL: u64 = 1_000_000_000;
+
+/// Deepest the invocation stack may go, top-level instruction included.
+const MAX_INVOKE_DEPTH: usize = 5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Account {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl Default for Account {
+    fn default() -> Self {
+        Account {
+            lamports: 0,
+            data: Vec::new(),
+            owner: system_program::ID,
+            executable: false,
+        }
+    }
+}
+
+/// Why a transaction failed. Nothing it did is kept.
+#[derive(Debug, PartialEq)]
+pub enum TxError {
+    MissingSignature(Pubkey),
+    /// Instruction `index` returned `error`, possibly from a program it
+    /// invoked.
+    Instruction { index: usize, error: ProgramError },
+    /// Instruction `index` broke a runtime rule, e.g. spent another
+    /// program's lamports or escalated a privilege in a CPI.
+    Runtime { index: usize, reason: String },
+    Panic { index: usize, message: String },
+    InsufficientFundsForRent(Pubkey),
+}
+
+impl TxError {
+    /// The program's custom error code, if it failed with one.
+    pub fn custom_code(&self) -> Option<u32> {
+        match self {
+            TxError::Instruction { error: ProgramError::Custom(code), .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+/// Fails the test unless `result` is the custom program error `code`.
+#[track_caller]
+pub fn assert_error<T: std::fmt::Debug>(result: std::result::Result<T, TxError>, code: impl Into<u32>) {
+    let code = code.into();
+    match result {
+        Err(err) => assert_eq!(err.custom_code(), Some(code), "expected error {code}, got {err:?}"),
+        Ok(value) => panic!("expected error {code}, got Ok({value:?})"),
+    }
+}
+
+struct Env {
+    programs: HashMap<Pubkey, Processor>,
+    clock: Clock,
+    stack: Vec<Pubkey>,
+    /// For each program on the stack, its accounts as last checked: when it
+    /// was invoked, updated by what its own CPIs changed.
+    baselines: Vec<Vec<Slot>>,
+    return_data: Option<(Pubkey, Vec<u8>)>,
+    logs: Vec<String>,
+    events: Vec<Vec<u8>>,
+    /// The first runtime failure inside a CPI, reported instead of
+    /// whatever error the caller turned it into.
+    cpi_failure: Option<String>,
+}
+
+thread_local! {
+    static ENV: RefCell<Env> = RefCell::new(Env {
+        programs: HashMap::new(),
+        clock: Clock::default(),
+        stack: Vec::new(),
+        baselines: Vec::new(),
+        return_data: None,
+        logs: Vec::new(),
+        events: Vec::new(),
+        cpi_failure: None,
+    });
+}
+
+fn with_env<R>(f: impl FnOnce(&mut Env) -> R) -> R {
+    ENV.with(|env| f(&mut env.borrow_mut()))
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, message: &str) {
+        with_env(|env| env.logs.push(message.to_string()));
+    }
+
+    fn sol_log_compute_units(&self) {}
+
+    fn sol_remaining_compute_units(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke(instruction, account_infos, signers_seeds).map_err(|failure| match failure {
+            CpiFailure::Program(error) => error,
+            CpiFailure::Runtime(reason) => {
+                with_env(|env| env.cpi_failure.get_or_insert(reason).clone());
+                ProgramError::Custom(u32::MAX)
+            }
+        })
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = with_env(|env| env.clock.clone());
+        unsafe { std::ptr::write(var_addr as *mut Clock, clock) };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { std::ptr::write(var_addr as *mut Rent, Rent::default()) };
+        0
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        with_env(|env| env.return_data.clone())
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        with_env(|env| {
+            let program = *env.stack.last().expect("return data set outside a program");
+            env.return_data = (!data.is_empty()).then(|| (program, data.to_vec()));
+        });
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        with_env(|env| env.events.push(fields.concat()));
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        with_env(|env| env.stack.len() as u64)
+    }
+}
+
+/// One account as an instruction sees it.
+#[derive(Clone)]
+struct Slot {
+    key: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+    account: Account,
+}
+
+enum CpiFailure {
+    Program(ProgramError),
+    Runtime(String),
+}
+
+/// Serializes `slots` in the loader's input layout, `metas` naming the slot
+/// behind each account the instruction lists.
+fn serialize(program_id: &Pubkey, slots: &[Slot], metas: &[usize], data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(metas.len() as u64).to_le_bytes());
+    for (position, &slot_index) in metas.iter().enumerate() {
+        if let Some(first) = metas[..position].iter().position(|&s| s == slot_index) {
+            buf.push(first as u8);
+            buf.extend_from_slice(&[0; 7]);
+            continue;
+        }
+        let slot = &slots[slot_index];
+        buf.push(u8::MAX);
+        buf.push(slot.is_signer as u8);
+        buf.push(slot.is_writable as u8);
+        buf.push(slot.account.executable as u8);
+        buf.extend_from_slice(&[0; 4]);
+        buf.extend_from_slice(slot.key.as_ref());
+        buf.extend_from_slice(slot.account.owner.as_ref());
+        buf.extend_from_slice(&slot.account.lamports.to_le_bytes());
+        buf.extend_from_slice(&(slot.account.data.len() as u64).to_le_bytes());
+        buf.extend_from_slice(&slot.account.data);
+        buf.resize(buf.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        buf.resize(buf.len().next_multiple_of(8), 0);
+        buf.extend_from_slice(&u64::MAX.to_le_bytes());
+    }
+    buf.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    buf.extend_from_slice(data);
+    buf.extend_from_slice(program_id.as_ref());
+    buf
+}
+
+/// Runs `program_id` over `slots`, writing back what it changed, and checks
+/// it changed only what it was allowed to.
+fn execute(
+    program_id: &Pubkey,
+    slots: &mut [Slot],
+    metas: &[usize],
+    data: &[u8],
+) -> std::result::Result<(), CpiFailure> {
+    if *program_id == system_program::ID {
+        let before = slots.to_vec();
+        run_system_program(slots, metas, data)?;
+        return check_changes(program_id, &before, slots, true).map_err(CpiFailure::Runtime);
+    }
+    let processor = with_env(|env| env.programs.get(program_id).copied())
+        .ok_or_else(|| CpiFailure::Runtime(format!("{program_id} is not a program")))?;
+
+    let bytes = serialize(program_id, slots, metas, data);
+    // Program input is 16-byte aligned, as the loader provides it.
+    let mut input = vec![0u128; bytes.len().div_ceil(16)];
+    let base = input.as_mut_ptr() as *mut u8;
+    unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), base, bytes.len()) };
+
+    with_env(|env| {
+        env.stack.push(*program_id);
+        env.baselines.push(slots.to_vec());
+        env.return_data = None;
+    });
+    let (id, infos, input_data) = unsafe { deserialize(base) };
+    let result = processor(id, &infos, input_data);
+    let before = with_env(|env| {
+        env.stack.pop();
+        env.baselines.pop().unwrap()
+    });
+
+    for (position, &slot_index) in metas.iter().enumerate() {
+        if metas[..position].contains(&slot_index) {
+            continue;
+        }
+        let info = &infos[position];
+        let account = &mut slots[slot_index].account;
+        account.lamports = info.lamports();
+        account.data = info.data.borrow().to_vec();
+        account.owner = *info.owner;
+    }
+
+    result.map_err(CpiFailure::Program)?;
+    check_changes(program_id, &before, slots, true).map_err(CpiFailure::Runtime)
+}
+
+/// The runtime's rules on what an instruction may change: only writable
+/// accounts, only its own accounts' data, lamports only out of its own
+/// accounts, and, when `check_balance` is set, no lamports created or
+/// destroyed.
+fn check_changes(
+    program_id: &Pubkey,
+    before: &[Slot],
+    after: &[Slot],
+    check_balance: bool,
+) -> std::result::Result<(), String> {
+    let mut total_before = 0u128;
+    let mut total_after = 0u128;
+    for (pre, post) in before.iter().zip(after) {
+        total_before += pre.account.lamports as u128;
+        total_after += post.account.lamports as u128;
+        if pre.account == post.account {
+            continue;
+        }
+        if !pre.is_writable {
+            return Err(format!("read-only account {} modified", pre.key));
+        }
+        if pre.account.owner != post.account.owner
+            && (pre.account.owner != *program_id || post.account.data.iter().any(|&b| b != 0))
+        {
+            return Err(format!("owner of {} changed illegally", pre.key));
+        }
+        if pre.account.owner != *program_id {
+            if pre.account.data != post.account.data && *program_id != system_program::ID {
+                return Err(format!("data of external account {} modified", pre.key));
+            }
+            if post.account.lamports < pre.account.lamports {
+                return Err(format!("lamports of external account {} spent", pre.key));
+            }
+        }
+    }
+    if check_balance && total_before != total_after {
+        return Err("instruction changed the total lamport balance".to_string());
+    }
+    Ok(())
+}
+
+/// CreateAccount, Assign, Transfer and Allocate, the system instructions
+/// programs here use.
+fn run_system_program(slots: &mut [Slot], metas: &[usize], data: &[u8]) -> std::result::Result<(), CpiFailure> {
+    let program_err = |error| Err(CpiFailure::Program(error));
+    let read_u64 = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+    let read_key = |at: usize| Pubkey::try_from(&data[at..at + 32]).unwrap();
+    let tag = u32::from_le_bytes(data[..4].try_into().unwrap());
+    let signed = |slots: &[Slot], position: usize| slots[metas[position]].is_signer;
+    match tag {
+        // CreateAccount { lamports, space, owner }
+        0 => {
+            let (lamports, space, owner) = (read_u64(4), read_u64(12) as usize, read_key(20));
+            let (from, to) = (metas[0], metas[1]);
+            if !signed(slots, 0) || !signed(slots, 1) {
+                return program_err(ProgramError::MissingRequiredSignature);
+            }
+            let target = &slots[to].account;
+            if target.lamports > 0 || !target.data.is_empty() || target.owner != system_program::ID {
+                // SystemError::AccountAlreadyInUse
+                return program_err(ProgramError::Custom(0));
+            }
+            if slots[from].account.lamports < lamports {
+                // SystemError::ResultWithNegativeLamports
+                return program_err(ProgramError::Custom(1));
+            }
+            slots[from].account.lamports -= lamports;
+            let target = &mut slots[to].account;
+            target.lamports = lamports;
+            target.data = vec![0; space];
+            target.owner = owner;
+        }
+        // Assign { owner }
+        1 => {
+            if !signed(slots, 0) {
+                return program_err(ProgramError::MissingRequiredSignature);
+            }
+            slots[metas[0]].account.owner = read_key(4);
+        }
+        // Transfer { lamports }
+        2 => {
+            let lamports = read_u64(4);
+            let (from, to) = (metas[0], metas[1]);
+            if !signed(slots, 0) {
+                return program_err(ProgramError::MissingRequiredSignature);
+            }
+            if !slots[from].account.data.is_empty() {
+                return program_err(ProgramError::InvalidArgument);
+            }
+            if slots[from].account.lamports < lamports {
+                return program_err(ProgramError::Custom(1));
+            }
+            slots[from].account.lamports -= lamports;
+            slots[to].account.lamports += lamports;
+        }
+        // Allocate { space }
+        8 => {
+            if !signed(slots, 0) {
+                return program_err(ProgramError::MissingRequiredSignature);
+            }
+            let target = &mut slots[metas[0]].account;
+            if !target.data.is_empty() || target.owner != system_program::ID {
+                return program_err(ProgramError::Custom(0));
+            }
+            target.data = vec![0; read_u64(4) as usize];
+        }
+        _ => return program_err(ProgramError::InvalidInstructionData),
+    }
+    Ok(())
+}
+
+/// A cross-program invocation from the program on top of the stack.
+fn invoke(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> std::result::Result<(), CpiFailure> {
+    let caller = with_env(|env| *env.stack.last().expect("invoke outside a program"));
+    let depth = with_env(|env| env.stack.len());
+    if depth >= MAX_INVOKE_DEPTH {
+        return Err(CpiFailure::Runtime("call depth exceeded".to_string()));
+    }
+    let reentrant = with_env(|env| env.stack.contains(&instruction.program_id));
+    if reentrant && caller != instruction.program_id {
+        return Err(CpiFailure::Runtime(format!(
+            "reentrant call into {}",
+            instruction.program_id
+        )));
+    }
+    let pda_signers = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &caller))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| CpiFailure::Program(ProgramError::InvalidSeeds))?;
+
+    let (mut slots, metas) = gather(&instruction.accounts, |meta| {
+        let info = account_infos
+            .iter()
+            .find(|info| *info.key == meta.pubkey)
+            .ok_or_else(|| format!("account {} missing from the CPI", meta.pubkey))?;
+        if meta.is_signer && !info.is_signer && !pda_signers.contains(info.key) {
+            return Err(format!("signer privilege escalated for {}", info.key));
+        }
+        if meta.is_writable && !info.is_writable {
+            return Err(format!("writable privilege escalated for {}", info.key));
+        }
+        Ok(Account {
+            lamports: info.lamports(),
+            data: info.data.borrow().to_vec(),
+            owner: *info.owner,
+            executable: info.executable,
+        })
+    })
+    .map_err(CpiFailure::Runtime)?;
+
+    // What the caller changed so far must stand on its own before the
+    // callee sees it.
+    let caller_before: Vec<Slot> = with_env(|env| {
+        let baseline = env.baselines.last().unwrap();
+        slots
+            .iter()
+            .map(|slot| baseline.iter().find(|b| b.key == slot.key).unwrap_or(slot).clone())
+            .collect()
+    });
+    let caller_now: Vec<Slot> = caller_before
+        .iter()
+        .zip(&slots)
+        .map(|(before, slot)| Slot { account: slot.account.clone(), ..before.clone() })
+        .collect();
+    check_changes(&caller, &caller_before, &caller_now, false).map_err(CpiFailure::Runtime)?;
+
+    execute(&instruction.program_id, &mut slots, &metas, &instruction.data)?;
+
+    with_env(|env| {
+        let baseline = env.baselines.last_mut().unwrap();
+        for (pre, post) in caller_now.iter().zip(&slots) {
+            if let Some(entry) = baseline.iter_mut().find(|b| b.key == post.key) {
+                entry.account.lamports = (entry.account.lamports as i128 + post.account.lamports as i128
+                    - pre.account.lamports as i128) as u64;
+                if pre.account.data != post.account.data {
+                    entry.account.data = post.account.data.clone();
+                }
+                if pre.account.owner != post.account.owner {
+                    entry.account.owner = post.account.owner;
+                }
+            }
+        }
+    });
+
+    for slot in slots.iter().filter(|slot| slot.is_writable) {
+        let info = account_infos.iter().find(|info| *info.key == slot.key).unwrap();
+        **info.try_borrow_mut_lamports().map_err(CpiFailure::Program)? = slot.account.lamports;
+        if *info.owner != slot.account.owner {
+            info.assign(&slot.account.owner);
+        }
+        info.resize(slot.account.data.len()).map_err(CpiFailure::Program)?;
+        info.try_borrow_mut_data()
+            .map_err(CpiFailure::Program)?
+            .copy_from_slice(&slot.account.data);
+    }
+    Ok(())
+}
+
+/// Merges `metas` into one slot per distinct account, a key signing or
+/// writable if any of its metas is.
+fn gather(
+    metas: &[AccountMeta],
+    mut load: impl FnMut(&AccountMeta) -> std::result::Result<Account, String>,
+) -> std::result::Result<(Vec<Slot>, Vec<usize>), String> {
+    let mut slots: Vec<Slot> = Vec::new();
+    let mut indices = Vec::with_capacity(metas.len());
+    for meta in metas {
+        match slots.iter().position(|slot| slot.key == meta.pubkey) {
+            Some(index) => {
+                slots[index].is_signer |= meta.is_signer;
+                slots[index].is_writable |= meta.is_writable;
+                indices.push(index);
+            }
+            None => {
+                slots.push(Slot {
+                    key: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                    account: load(meta)?,
+                });
+                indices.push(slots.len() - 1);
+            }
+        }
+    }
+    Ok((slots, indices))
+}
+
+/// Checks a Secp256k1 precompile instruction the way the runtime does
+/// before any program runs: every signature it lists must recover to the
+/// Ethereum address it names over the message it names.
+fn verify_secp256k1(data: &[u8], instructions: &[Instruction], index: usize) -> std::result::Result<(), String> {
+    let count = *data.first().ok_or("empty secp256k1 instruction")? as usize;
+    for n in 0..count {
+        let offsets = data.get(1 + n * 11..1 + (n + 1) * 11).ok_or("truncated offsets")?;
+        let u16_at = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]) as usize;
+        let bytes_in = |ix: u8| -> &[u8] {
+            match instructions.get(ix as usize) {
+                Some(other) if ix as usize != index => &other.data,
+                _ => data,
+            }
+        };
+        let signature = bytes_in(offsets[2])
+            .get(u16_at(0)..u16_at(0) + 65)
+            .ok_or("signature out of bounds")?;
+        let address = bytes_in(offsets[5])
+            .get(u16_at(3)..u16_at(3) + 20)
+            .ok_or("address out of bounds")?;
+        let message = bytes_in(offsets[10])
+            .get(u16_at(6)..u16_at(6) + u16_at(8))
+            .ok_or("message out of bounds")?;
+
+        let digest = keccak::hash(message);
+        let public_key = secp256k1_recover(digest.as_ref(), signature[64], &signature[..64])
+            .map_err(|_| "signature does not recover")?;
+        if keccak::hash(&public_key.to_bytes()).as_ref()[12..] != *address {
+            return Err("signature is not by the named address".to_string());
+        }
+    }
+    Ok(())
+}
+
+/// The accounts of a test validator and the programs it runs.
+pub struct Runtime {
+    accounts: HashMap<Pubkey, Account>,
+    return_data: Option<(Pubkey, Vec<u8>)>,
+    logs: Vec<String>,
+    events: Vec<Vec<u8>>,
+}
+
+impl Runtime {
+    /// A runtime running the membership program, at slot 1 and unix time
+    /// 1_700_000_000.
+    pub fn new() -> Self {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+        });
+        with_env(|env| {
+            env.programs.clear();
+            env.stack.clear();
+            env.clock = Clock {
+                slot: 1,
+                unix_timestamp: 1_700_000_000,
+                ..Clock::default()
+            };
+        });
+
+        let mut runtime = Runtime {
+            accounts: HashMap::new(),
+            return_data: None,
+            logs: Vec::new(),
+            events: Vec::new(),
+        };
+        runtime.accounts.insert(
+            system_program::ID,
+            Account {
+                lamports: 1,
+                owner: Pubkey::default(),
+                executable: true,
+                ..Account::default()
+            },
+        );
+        runtime.add_program(membership::ID, membership::process_instruction);
+        runtime
+    }
+
+    pub fn add_program(&mut self, program_id: Pubkey, processor: Processor) {
+        with_env(|env| env.programs.insert(program_id, processor));
+        self.accounts.insert(
+            program_id,
+            Account {
+                lamports: 1,
+                owner: bpf_loader_upgradeable::ID,
+                executable: true,
+                ..Account::default()
+            },
+        );
+    }
+
+    /// A fresh system account holding `lamports`.
+    pub fn wallet(&mut self, lamports: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.airdrop(&key, lamports);
+        key
+    }
+
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts.entry(*key).or_default().lamports += lamports;
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.get(key)
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: Account) {
+        self.accounts.insert(key, account);
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    /// Deserializes the Anchor account at `key`, panicking if it is missing
+    /// or not a `T`.
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).unwrap_or_else(|| panic!("no account {key}"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Rewrites the Anchor account at `key` in place, bypassing the
+    /// programs, to set up states they would not produce.
+    pub fn update<T: AccountSerialize + AccountDeserialize>(&mut self, key: &Pubkey, edit: impl FnOnce(&mut T)) {
+        let mut state: T = self.get(key);
+        edit(&mut state);
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        let account = self.accounts.get_mut(key).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+    }
+
+    pub fn clock(&self) -> Clock {
+        with_env(|env| env.clock.clone())
+    }
+
+    /// Moves the clock forward `secs` seconds and one slot per 400ms.
+    pub fn advance_time(&mut self, secs: i64) {
+        with_env(|env| {
+            env.clock.unix_timestamp += secs;
+            env.clock.slot += (secs as u64 * 5).div_ceil(2);
+        });
+    }
+
+    pub fn advance_slots(&mut self, slots: u64) {
+        with_env(|env| env.clock.slot += slots);
+    }
+
+    pub fn set_epoch(&mut self, epoch: u64) {
+        with_env(|env| env.clock.epoch = epoch);
+    }
+
+    /// Return data left by the last transaction, if any.
+    pub fn return_data(&self) -> Option<&[u8]> {
+        self.return_data.as_ref().map(|(_, data)| data.as_slice())
+    }
+
+    /// Decodes the last transaction's return data as a `T`.
+    pub fn returned<T: AnchorDeserialize>(&self) -> T {
+        T::try_from_slice(self.return_data().expect("no return data")).unwrap()
+    }
+
+    /// Program logs of the last transaction.
+    pub fn logs(&self) -> &[String] {
+        &self.logs
+    }
+
+    /// Raw program data the last transaction logged, in order.
+    pub fn program_data(&self) -> &[Vec<u8>] {
+        &self.events
+    }
+
+    /// Every `E` the last transaction emitted, in order.
+    pub fn events<E: Event + Discriminator>(&self) -> Vec<E> {
+        self.events
+            .iter()
+            .filter_map(|data| data.strip_prefix(E::DISCRIMINATOR))
+            .map(|mut data| E::deserialize(&mut data).unwrap())
+            .collect()
+    }
+
+    /// Sends `instruction` as a transaction of its own.
+    pub fn send(&mut self, instruction: Instruction, signers: &[Pubkey]) -> std::result::Result<(), TxError> {
+        self.send_all(&[instruction], signers)
+    }
+
+    /// Runs `instructions` as one transaction signed by `signers`, keeping
+    /// its changes only if every instruction succeeds.
+    pub fn send_all(&mut self, instructions: &[Instruction], signers: &[Pubkey]) -> std::result::Result<(), TxError> {
+        self.return_data = None;
+        self.logs.clear();
+        self.events.clear();
+        with_env(|env| {
+            env.logs.clear();
+            env.events.clear();
+            env.return_data = None;
+            env.cpi_failure = None;
+        });
+
+        let snapshot = self.accounts.clone();
+        let result = self.process(instructions, signers);
+        with_env(|env| {
+            env.stack.clear();
+            self.logs = std::mem::take(&mut env.logs);
+            self.events = std::mem::take(&mut env.events);
+            self.return_data = env.return_data.take();
+        });
+        if result.is_err() {
+            self.accounts = snapshot;
+            println!("transaction failed: {result:?}");
+            for line in &self.logs {
+                println!("  {line}");
+            }
+        }
+        result
+    }
+
+    fn process(&mut self, instructions: &[Instruction], signers: &[Pubkey]) -> std::result::Result<(), TxError> {
+        // Privileges are merged across the whole message.
+        let mut signing = Vec::new();
+        let mut writable = Vec::new();
+        for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+            if meta.is_signer {
+                if !signers.contains(&meta.pubkey) {
+                    return Err(TxError::MissingSignature(meta.pubkey));
+                }
+                signing.push(meta.pubkey);
+            }
+            if meta.is_writable {
+                writable.push(meta.pubkey);
+            }
+        }
+        let before = self.accounts.clone();
+
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|ix| BorrowedInstruction {
+                program_id: &ix.program_id,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: signing.contains(&meta.pubkey),
+                        is_writable: writable.contains(&meta.pubkey),
+                    })
+                    .collect(),
+                data: &ix.data,
+            })
+            .collect();
+        let mut sysvar_data = construct_instructions_data(&borrowed);
+
+        for (index, ix) in instructions.iter().enumerate() {
+            if ix.program_id == secp256k1_program::ID {
+                verify_secp256k1(&ix.data, instructions, index)
+                    .map_err(|reason| TxError::Runtime { index, reason })?;
+                continue;
+            }
+            store_current_index(&mut sysvar_data, index as u16);
+
+            let metas: Vec<AccountMeta> = ix
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: signing.contains(&meta.pubkey),
+                    is_writable: writable.contains(&meta.pubkey)
+                        && !self.accounts.get(&meta.pubkey).is_some_and(|a| a.executable),
+                })
+                .collect();
+            let (mut slots, indices) = gather(&metas, |meta| {
+                Ok(if meta.pubkey == sysvar::instructions::ID {
+                    Account {
+                        lamports: 1,
+                        data: sysvar_data.clone(),
+                        owner: sysvar::ID,
+                        executable: false,
+                    }
+                } else {
+                    self.accounts.get(&meta.pubkey).cloned().unwrap_or_default()
+                })
+            })
+            .unwrap();
+
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                execute(&ix.program_id, &mut slots, &indices, &ix.data)
+            }));
+            let cpi_failure = with_env(|env| env.cpi_failure.take());
+            match outcome {
+                Err(payload) => {
+                    let message = payload
+                        .downcast_ref::<String>()
+                        .cloned()
+                        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                        .unwrap_or_default();
+                    return Err(TxError::Panic { index, message });
+                }
+                Ok(Err(_)) if cpi_failure.is_some() => {
+                    return Err(TxError::Runtime { index, reason: cpi_failure.unwrap() });
+                }
+                Ok(Err(CpiFailure::Program(error))) => return Err(TxError::Instruction { index, error }),
+                Ok(Err(CpiFailure::Runtime(reason))) => return Err(TxError::Runtime { index, reason }),
+                Ok(Ok(())) => {}
+            }
+
+            for slot in slots.into_iter().filter(|slot| slot.is_writable) {
+                if slot.key != sysvar::instructions::ID {
+                    self.accounts.insert(slot.key, slot.account);
+                }
+            }
+        }
+
+        self.check_rent(&before)?;
+        self.accounts.retain(|_, account| account.lamports > 0);
+        Ok(())
+    }
+
+    /// No account may be left holding lamports below rent exemption unless
+    /// it already did, at the same size, and only lost lamports.
+    fn check_rent(&self, before: &HashMap<Pubkey, Account>) -> std::result::Result<(), TxError> {
+        let rent = Rent::default();
+        for (key, post) in &self.accounts {
+            if post.lamports == 0 || rent.is_exempt(post.lamports, post.data.len()) {
+                continue;
+            }
+            let pre = before.get(key);
+            if pre == Some(post) {
+                continue;
+            }
+            let still_paying = pre.is_some_and(|pre| {
+                pre.lamports > 0
+                    && !rent.is_exempt(pre.lamports, pre.data.len())
+                    && pre.data.len() == post.data.len()
+                    && post.lamports <= pre.lamports
+            });
+            if !still_paying {
+                return Err(TxError::InsufficientFundsForRent(*key));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds an instruction from an Anchor accounts struct and instruction
+/// argument struct.
+pub fn instruction(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// A registry created by `create_registry`, with its audit log and the
+/// authority that initialized it.
+#[derive(Clone, Copy)]
+pub struct Registry {
+    pub key: Pubkey,
+    pub audit_log: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// A member account and the key it belongs to.
+#[derive(Clone, Copy, Debug)]
+pub struct TestMember {
+    pub account: Pubkey,
+    pub key: Pubkey,
+}
+
+/// Initializes a registry whose authority holds 100 SOL.
+pub fn create_registry(rt: &mut Runtime) -> Registry {
+    let authority = rt.wallet(100 * SOL);
+    let (key, audit_log) = (Pubkey::new_unique(), Pubkey::new_unique());
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::Initialize {
+                registry: key,
+                audit_log,
+                authority,
+                system_program: system_program::ID,
+            },
+            membership::instruction::Initialize {},
+        ),
+        &[key, audit_log, authority],
+    )
+    .unwrap();
+    Registry { key, audit_log, authority }
+}
+
+/// The audit log the registry currently writes to.
+pub fn current_audit_log(rt: &Runtime, registry: &Registry) -> Pubkey {
+    rt.get::<membership::MemberRegistry>(&registry.key).audit_log
+}
+
+pub fn update_config_ix(
+    rt: &Runtime,
+    registry: &Registry,
+    field: membership::RegistryField,
+    value: u64,
+) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::UpdateConfig {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            signer: registry.authority,
+        },
+        membership::instruction::UpdateConfig { field, value },
+    )
+}
+
+/// Sets a registry config field as its authority.
+pub fn update_config(rt: &mut Runtime, registry: &Registry, field: membership::RegistryField, value: u64) {
+    let ix = update_config_ix(rt, registry, field, value);
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+/// `add_member` arguments for a member with nothing but a type, power and
+/// name, at the registry's current nonce.
+pub fn member_args(
+    rt: &Runtime,
+    registry: &Registry,
+    member_type: membership::MemberType,
+    voting_power: u64,
+) -> membership::instruction::AddMember {
+    membership::instruction::AddMember {
+        member_type,
+        voting_power,
+        legal_name: format!("Member {}", Pubkey::new_unique()),
+        address: "1 Main St, Cheyenne WY".to_string(),
+        tax_id: "00-0000000".to_string(),
+        nonce: rt.get::<membership::MemberRegistry>(&registry.key).registration_nonce,
+        model_id: String::new(),
+        contact_hash: [0; 32],
+        acknowledged_hash: [0; 32],
+    }
+}
+
+/// The `add_member` instruction registering `args` into a fresh member
+/// account for a fresh key.
+pub fn add_member_ix(
+    rt: &Runtime,
+    registry: &Registry,
+    args: membership::instruction::AddMember,
+) -> (Instruction, TestMember) {
+    let member = TestMember {
+        account: Pubkey::new_unique(),
+        key: Pubkey::new_unique(),
+    };
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::AddMember {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            member_pubkey: member.key,
+            treasury: None,
+            name_entry: None,
+            authority: registry.authority,
+            system_program: system_program::ID,
+        },
+        args,
+    );
+    (ix, member)
+}
+
+/// Registers a member as the registry authority, funding its key with
+/// 10 SOL.
+pub fn add_member(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member_type: membership::MemberType,
+    voting_power: u64,
+) -> TestMember {
+    let args = member_args(rt, registry, member_type, voting_power);
+    try_add_member(rt, registry, args).unwrap()
+}
+
+pub fn try_add_member(
+    rt: &mut Runtime,
+    registry: &Registry,
+    args: membership::instruction::AddMember,
+) -> std::result::Result<TestMember, TxError> {
+    let (ix, member) = add_member_ix(rt, registry, args);
+    rt.send(ix, &[member.account, registry.authority])?;
+    rt.airdrop(&member.key, 10 * SOL);
+    Ok(member)
+}
+
+pub fn member(rt: &Runtime, member: &TestMember) -> membership::Member {
+    rt.get(&member.account)
+}
+
+/// Points `member`'s vote at `delegate`, signed by the member.
+pub fn set_delegate(rt: &mut Runtime, registry: &Registry, member: &TestMember, delegate: &TestMember) {
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetDelegate {
+                registry: registry.key,
+                member: member.account,
+                delegate_member: delegate.account,
+                owner: member.key,
+            },
+            membership::instruction::SetDelegate {},
+        ),
+        &[member.key],
+    )
+    .unwrap();
+}
+
+pub fn remove_member_ix(rt: &Runtime, registry: &Registry, member: &TestMember, parent: Option<Pubkey>) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::RemoveMember {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+            name_entry: None,
+            parent,
+        },
+        membership::instruction::RemoveMember {},
+    )
+}
+
+/// Removes a member that is not a sub-agent as the registry authority.
+pub fn remove_member(rt: &mut Runtime, registry: &Registry, member: &TestMember) {
+    let ix = remove_member_ix(rt, registry, member, None);
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+/// The registry authority changing `member`'s voting power by `delta`.
+pub fn adjust_voting_power_ix(
+    rt: &Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    parent: Option<Pubkey>,
+    delta: i64,
+) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::AdjustVotingPower {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+            parent,
+        },
+        membership::instruction::AdjustVotingPower { delta },
+    )
+}
+
+/// Flags `member` as under dispute as the registry authority.
+pub fn flag_dispute(rt: &mut Runtime, registry: &Registry, member: &TestMember) {
+    let member_id = self::member(rt, member).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::FlagDispute {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+        },
+        membership::instruction::FlagDispute {
+            member_id,
+            reason: "Disputed".to_string(),
+        },
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+/// Resolves `member`'s dispute as the registry authority, suspending them
+/// if `uphold`.
+pub fn resolve_dispute(rt: &mut Runtime, registry: &Registry, member: &TestMember, uphold: bool) {
+    let member_id = self::member(rt, member).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::ResolveDispute {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+        },
+        membership::instruction::ResolveDispute { member_id, uphold },
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+/// A `NewMember` batch entry for a fresh key.
+pub fn new_member(voting_power: u64) -> membership::NewMember {
+    membership::NewMember {
+        pubkey: Pubkey::new_unique(),
+        member_type: membership::MemberType::Human,
+        voting_power,
+        legal_name: format!("Member {}", Pubkey::new_unique()),
+        address: "1 Main St, Cheyenne WY".to_string(),
+        tax_id: "00-0000000".to_string(),
+        model_id: String::new(),
+        contact_hash: [0; 32],
+        acknowledged_hash: [0; 32],
+    }
+}
+
+/// `add_members_batch` registering `entries` into `accounts`, in order, as
+/// the registry authority. Every account in `accounts` must sign.
+pub fn add_members_batch_ix(
+    rt: &Runtime,
+    registry: &Registry,
+    entries: Vec<membership::NewMember>,
+    accounts: &[Pubkey],
+) -> Instruction {
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::AddMembersBatch {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            treasury: None,
+            authority: registry.authority,
+            system_program: system_program::ID,
+        },
+        membership::instruction::AddMembersBatch {
+            entries,
+            nonce: rt.get::<membership::MemberRegistry>(&registry.key).registration_nonce,
+        },
+    );
+    ix.accounts
+        .extend(accounts.iter().map(|account| AccountMeta::new(*account, true)));
+    ix
+}
+
+/// A human applying for membership with a fresh, funded key, which signs
+/// along with the new member account.
+pub fn apply_for_membership_ix(rt: &mut Runtime, registry: &Registry) -> (Instruction, TestMember) {
+    let member = TestMember {
+        account: Pubkey::new_unique(),
+        key: rt.wallet(SOL),
+    };
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::ApplyForMembership {
+            registry: registry.key,
+            member: member.account,
+            treasury: None,
+            name_entry: None,
+            applicant: member.key,
+            system_program: system_program::ID,
+        },
+        membership::instruction::ApplyForMembership {
+            member_type: membership::MemberType::Human,
+            legal_name: format!("Applicant {}", member.key),
+            address: "1 Main St, Cheyenne WY".to_string(),
+            tax_id: "00-0000000".to_string(),
+            model_id: String::new(),
+            contact_hash: [0; 32],
+            acknowledged_hash: [0; 32],
+        },
+    );
+    (ix, member)
+}
+
+/// The registry authority approving a pending `member`.
+pub fn approve_membership_ix(
+    rt: &Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    voting_power: Option<u64>,
+) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::ApproveMembership {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+        },
+        membership::instruction::ApproveMembership { voting_power },
+    )
+}
+
+/// `member` leaving on their own, clearing the delegations of `delegators`.
+pub fn leave_membership_ix(
+    rt: &Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    parent: Option<Pubkey>,
+    delegators: &[&TestMember],
+) -> Instruction {
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::LeaveMembership {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            owner: member.key,
+            name_entry: None,
+            parent,
+        },
+        membership::instruction::LeaveMembership {},
+    );
+    ix.accounts
+        .extend(delegators.iter().map(|d| AccountMeta::new(d.account, false)));
+    ix
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberType, MembershipError, RegistryField};
+
+fn set_contact_hash(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    signer: Pubkey,
+    contact_hash: [u8; 32],
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetContactHash {
+            registry: registry.key,
+            member: member.account,
+            owner: signer,
+        },
+        membership::instruction::SetContactHash { contact_hash },
+    );
+    rt.send(ix, &[signer])
+}
+
+#[test]
+fn contact_hash_is_stored_at_registration_and_replaced_by_the_member() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+    args.contact_hash = [7; 32];
+    let member = try_add_member(&mut rt, &registry, args).unwrap();
+    assert_eq!(common::member(&rt, &member).contact_hash, [7; 32]);
+
+    set_contact_hash(&mut rt, &registry, &member, member.key, [9; 32]).unwrap();
+    assert_eq!(common::member(&rt, &member).contact_hash, [9; 32]);
+
+    let stranger = rt.wallet(SOL);
+    assert_error(
+        set_contact_hash(&mut rt, &registry, &member, stranger, [1; 32]),
+        MembershipError::NotAuthorized,
+    );
+    assert_eq!(common::member(&rt, &member).contact_hash, [9; 32]);
+}
+
+#[test]
+fn all_zero_contact_hash_is_refused_when_required() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    update_config(&mut rt, &registry, RegistryField::RequireContactHash, 1);
+
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::ContactHashRequired);
+    assert_error(
+        set_contact_hash(&mut rt, &registry, &member, member.key, [0; 32]),
+        MembershipError::ContactHashRequired,
+    );
+
+    let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+    args.contact_hash = [3; 32];
+    try_add_member(&mut rt, &registry, args).unwrap();
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{MemberRegistry, MemberType, RegistryField};
+
+const PERIOD: i64 = 30 * 86_400;
+const DUES: u64 = SOL / 10;
+
+/// A registry charging `DUES` every `PERIOD` into a fresh treasury.
+fn registry_with_dues(rt: &mut Runtime) -> (Registry, Pubkey) {
+    let registry = create_registry(rt);
+    let treasury = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetTreasury {
+                registry: registry.key,
+                audit_log: registry.audit_log,
+                authority: registry.authority,
+            },
+            membership::instruction::SetTreasury { treasury },
+        ),
+        &[registry.authority],
+    )
+    .unwrap();
+    update_config(rt, &registry, RegistryField::DuesAmount, DUES);
+    update_config(rt, &registry, RegistryField::DuesPeriodSecs, PERIOD as u64);
+    (registry, treasury)
+}
+
+fn charge_dues(rt: &mut Runtime, registry: &Registry, members: &[&TestMember]) {
+    let keeper = rt.wallet(SOL);
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::ChargeDues {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            keeper,
+        },
+        membership::instruction::ChargeDues {},
+    );
+    ix.accounts
+        .extend(members.iter().map(|m| AccountMeta::new(m.account, false)));
+    rt.send(ix, &[keeper]).unwrap();
+}
+
+fn pay_dues(rt: &mut Runtime, registry: &Registry, treasury: Pubkey, member: &TestMember, periods: u32) {
+    let payer = rt.wallet(SOL);
+    let member_id = common::member(rt, member).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::PayDues {
+            registry: registry.key,
+            member: member.account,
+            treasury: Some(treasury),
+            payer,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::PayDues { member_id, periods },
+    );
+    rt.send(ix, &[payer]).unwrap();
+}
+
+#[test]
+fn lapsed_member_is_reactivated_by_paying() {
+    let mut rt = Runtime::new();
+    let (registry, treasury) = registry_with_dues(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 40);
+    let joined_at = rt.clock().unix_timestamp;
+
+    charge_dues(&mut rt, &registry, &[&member]);
+    assert!(common::member(&rt, &member).is_active);
+
+    rt.advance_time(PERIOD + 1);
+    charge_dues(&mut rt, &registry, &[&member]);
+    let state = common::member(&rt, &member);
+    assert!(!state.is_active);
+    assert!(state.dues_lapsed);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).active_member_count, 0);
+
+    let before = rt.lamports(&treasury);
+    pay_dues(&mut rt, &registry, treasury, &member, 2);
+    let state = common::member(&rt, &member);
+    assert!(state.is_active);
+    assert!(!state.dues_lapsed);
+    assert_eq!(state.dues_paid_through, joined_at + PERIOD + 1 + 2 * PERIOD);
+    assert_eq!(rt.lamports(&treasury), before + 2 * DUES);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).active_member_count, 1);
+}
+
+#[test]
+fn upheld_dispute_is_not_undone_by_paying() {
+    let mut rt = Runtime::new();
+    let (registry, treasury) = registry_with_dues(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 40);
+    rt.advance_time(PERIOD + 1);
+    charge_dues(&mut rt, &registry, &[&member]);
+
+    flag_dispute(&mut rt, &registry, &member);
+    resolve_dispute(&mut rt, &registry, &member, true);
+    pay_dues(&mut rt, &registry, treasury, &member, 1);
+
+    let state = common::member(&rt, &member);
+    assert!(!state.is_active);
+    assert!(!state.dues_lapsed);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).active_member_count, 0);
+}
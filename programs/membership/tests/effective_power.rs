@@ -0,0 +1,59 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberType, MembershipError};
+
+fn get_effective_power(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    delegators: &[TestMember],
+) -> std::result::Result<u64, TxError> {
+    let member_id = common::member(rt, member).id;
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::GetEffectivePower {
+            registry: registry.key,
+            member: member.account,
+        },
+        membership::instruction::GetEffectivePower { member_id },
+    );
+    ix.accounts
+        .extend(delegators.iter().map(|m| AccountMeta::new_readonly(m.account, false)));
+    let payer = rt.wallet(SOL);
+    rt.send(ix, &[payer])?;
+    Ok(rt.returned())
+}
+
+#[test]
+fn effective_power_sums_own_and_delegated_power() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let delegate = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 20);
+    let second = add_member(&mut rt, &registry, MemberType::Human, 3);
+    set_delegate(&mut rt, &registry, &first, &delegate);
+    set_delegate(&mut rt, &registry, &second, &delegate);
+
+    assert_eq!(get_effective_power(&mut rt, &registry, &delegate, &[]).unwrap(), 100);
+    assert_eq!(
+        get_effective_power(&mut rt, &registry, &delegate, &[first, second]).unwrap(),
+        123
+    );
+}
+
+#[test]
+fn effective_power_rejects_members_delegating_elsewhere() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let delegate = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let other = add_member(&mut rt, &registry, MemberType::Human, 50);
+    let stranger = add_member(&mut rt, &registry, MemberType::Human, 20);
+    set_delegate(&mut rt, &registry, &stranger, &other);
+
+    assert_error(
+        get_effective_power(&mut rt, &registry, &delegate, &[stranger]),
+        MembershipError::InvalidDelegation,
+    );
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{Endorsement, MemberType, MembershipError};
+
+fn endorsement_address(endorser: &TestMember, endorsee: &TestMember) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"endorsement", endorser.account.as_ref(), endorsee.account.as_ref()],
+        &membership::ID,
+    )
+    .0
+}
+
+fn endorse(
+    rt: &mut Runtime,
+    registry: &Registry,
+    endorser: &TestMember,
+    endorsee: &TestMember,
+) -> std::result::Result<(), TxError> {
+    let endorsee_id = member(rt, endorsee).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::EndorseMember {
+            registry: registry.key,
+            endorser: endorser.account,
+            endorsee: endorsee.account,
+            endorsement: endorsement_address(endorser, endorsee),
+            owner: endorser.key,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::EndorseMember { endorsee_id },
+    );
+    rt.send(ix, &[endorser.key])
+}
+
+#[test]
+fn endorsement_is_recorded_and_counted() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let alice = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let bob = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let carol = add_member(&mut rt, &registry, MemberType::AI, 10);
+
+    endorse(&mut rt, &registry, &alice, &carol).unwrap();
+    endorse(&mut rt, &registry, &bob, &carol).unwrap();
+
+    assert_eq!(member(&rt, &carol).endorsement_count, 2);
+    assert_eq!(member(&rt, &alice).endorsement_count, 0);
+    let record: Endorsement = rt.get(&endorsement_address(&alice, &carol));
+    assert_eq!(record.registry, registry.key);
+    assert_eq!(record.endorser, alice.account);
+    assert_eq!(record.endorsee, carol.account);
+    assert_eq!(record.endorsee_id, member(&rt, &carol).id);
+    assert_eq!(record.created_at, rt.clock().unix_timestamp);
+}
+
+#[test]
+fn self_and_repeat_endorsements_are_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let alice = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let bob = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    assert_error(endorse(&mut rt, &registry, &alice, &alice), MembershipError::SelfEndorsement);
+    endorse(&mut rt, &registry, &alice, &bob).unwrap();
+    assert!(endorse(&mut rt, &registry, &alice, &bob).is_err());
+
+    assert_eq!(member(&rt, &alice).endorsement_count, 0);
+    assert_eq!(member(&rt, &bob).endorsement_count, 1);
+}
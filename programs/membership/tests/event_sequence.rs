@@ -0,0 +1,30 @@
+mod common;
+
+use common::*;
+use membership::{DisputeFlagged, DisputeResolved, MemberRegistry, MemberType, MembershipError};
+
+#[test]
+fn registry_events_are_numbered_consecutively() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    flag_dispute(&mut rt, &registry, &member);
+    assert_eq!(rt.events::<DisputeFlagged>()[0].sequence, 0);
+    resolve_dispute(&mut rt, &registry, &member, false);
+    assert_eq!(rt.events::<DisputeResolved>()[0].sequence, 1);
+
+    // A failed instruction uses up no number.
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    let intruder = Registry {
+        authority: rt.wallet(SOL),
+        ..registry
+    };
+    assert_error(try_add_member(&mut rt, &intruder, args), MembershipError::NotAuthorized);
+
+    flag_dispute(&mut rt, &registry, &member);
+    assert_eq!(rt.events::<DisputeFlagged>()[0].sequence, 2);
+    resolve_dispute(&mut rt, &registry, &member, true);
+    assert_eq!(rt.events::<DisputeResolved>()[0].sequence, 3);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).event_seq, 4);
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::*;
+use membership::{DisputeFlagged, MemberType, MembershipError, RegistryField, SuspiciousActivity};
+
+/// Flags a dispute, which logs at `All`, then has an intruder try to change
+/// config, which logs at `Errors`. Returns how many of each were emitted.
+fn events_at(verbosity: u64) -> (usize, usize) {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    update_config(&mut rt, &registry, RegistryField::EventVerbosity, verbosity);
+
+    flag_dispute(&mut rt, &registry, &member);
+    let flagged = rt.events::<DisputeFlagged>().len();
+
+    let intruder = Registry {
+        authority: rt.wallet(SOL),
+        ..registry
+    };
+    let ix = update_config_ix(&rt, &intruder, RegistryField::MaxMembers, 1);
+    assert_error(rt.send(ix, &[intruder.authority]), MembershipError::NotAuthorized);
+    (flagged, rt.events::<SuspiciousActivity>().len())
+}
+
+#[test]
+fn each_level_emits_only_what_it_covers() {
+    assert_eq!(events_at(0), (0, 0));
+    assert_eq!(events_at(1), (0, 1));
+    assert_eq!(events_at(2), (1, 1));
+}
+
+#[test]
+fn unknown_level_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+
+    let ix = update_config_ix(&rt, &registry, RegistryField::EventVerbosity, 3);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::InvalidConfigValue);
+}
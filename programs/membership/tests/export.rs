@@ -0,0 +1,76 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{MemberType, NewMember, RegistryField, StateExport};
+
+/// Exports `registry` with `members`, joining logged chunks when the
+/// export is too large for return data.
+fn export_state(rt: &mut Runtime, registry: &Registry, members: &[Pubkey]) -> StateExport {
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::ExportState { registry: registry.key },
+        membership::instruction::ExportState {},
+    );
+    ix.accounts
+        .extend(members.iter().map(|member| AccountMeta::new_readonly(*member, false)));
+    let payer = rt.wallet(SOL);
+    rt.send(ix, &[payer]).unwrap();
+    let data = match rt.return_data() {
+        Some(data) => data.to_vec(),
+        None => rt.program_data().concat(),
+    };
+    StateExport::try_from_slice(&data).unwrap()
+}
+
+#[test]
+fn small_dao_round_trips_through_export_and_import() {
+    let mut rt = Runtime::new();
+    let old = create_registry(&mut rt);
+    update_config(&mut rt, &old, RegistryField::DuesAmount, 1_000);
+    let human = add_member(&mut rt, &old, MemberType::Human, 100);
+    let ai = add_member(&mut rt, &old, MemberType::AI, 10);
+
+    let export = export_state(&mut rt, &old, &[human.account, ai.account]);
+    assert_eq!(export.version, membership::STATE_EXPORT_VERSION);
+    assert_eq!(export.registry, old.key);
+    assert_eq!(export.members.len(), 2);
+
+    // Replay it into a fresh registry the way docs/STATE_MIGRATION.md describes.
+    let new = create_registry(&mut rt);
+    update_config(&mut rt, &new, RegistryField::DuesAmount, export.state.dues_amount);
+    let entries: Vec<NewMember> = export
+        .members
+        .iter()
+        .map(|member| NewMember {
+            pubkey: member.pubkey,
+            member_type: member.member_type.clone(),
+            voting_power: member.voting_power,
+            legal_name: member.legal_name.clone(),
+            address: member.address.clone(),
+            tax_id: member.tax_id.clone(),
+            model_id: member.model_id.clone(),
+            contact_hash: member.contact_hash,
+            acknowledged_hash: member.agreement_hash,
+        })
+        .collect();
+    let accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let ix = add_members_batch_ix(&rt, &new, entries, &accounts);
+    let mut signers = accounts.to_vec();
+    signers.push(new.authority);
+    rt.send(ix, &signers).unwrap();
+
+    let imported = export_state(&mut rt, &new, &accounts);
+    assert_eq!(imported.state.dues_amount, 1_000);
+    assert_eq!(imported.state.member_count, export.state.member_count);
+    assert_eq!(imported.state.total_voting_power, export.state.total_voting_power);
+    for (old, new) in export.members.iter().zip(&imported.members) {
+        assert_eq!(new.id, old.id);
+        assert_eq!(new.pubkey, old.pubkey);
+        assert!(new.member_type == old.member_type);
+        assert_eq!(new.voting_power, old.voting_power);
+        assert_eq!(new.legal_name, old.legal_name);
+        assert_eq!(new.registry, imported.registry);
+    }
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, RegistryField};
+
+fn require_human_majority(rt: &mut Runtime) -> Registry {
+    let registry = create_registry(rt);
+    update_config(rt, &registry, RegistryField::RequireHumanMajorityCount, 1);
+    registry
+}
+
+fn try_add(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member_type: MemberType,
+) -> std::result::Result<TestMember, TxError> {
+    let args = member_args(rt, registry, member_type, 10);
+    try_add_member(rt, registry, args)
+}
+
+#[test]
+fn alternating_registrations_keep_ai_members_at_most_even() {
+    let mut rt = Runtime::new();
+    let registry = require_human_majority(&mut rt);
+
+    for member_type in [MemberType::Human, MemberType::AI, MemberType::Human, MemberType::AI] {
+        try_add(&mut rt, &registry, member_type).unwrap();
+    }
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.human_member_count, 2);
+    assert_eq!(state.ai_member_count, 2);
+}
+
+#[test]
+fn ai_member_that_would_outnumber_humans_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = require_human_majority(&mut rt);
+
+    assert_error(try_add(&mut rt, &registry, MemberType::AI), MembershipError::WouldBreakHumanMajority);
+    try_add(&mut rt, &registry, MemberType::Human).unwrap();
+    try_add(&mut rt, &registry, MemberType::AI).unwrap();
+    assert_error(try_add(&mut rt, &registry, MemberType::AI), MembershipError::WouldBreakHumanMajority);
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.human_member_count, 1);
+    assert_eq!(state.ai_member_count, 1);
+}
+
+#[test]
+fn ai_members_may_outnumber_humans_when_not_required() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+
+    try_add(&mut rt, &registry, MemberType::AI).unwrap();
+    try_add(&mut rt, &registry, MemberType::AI).unwrap();
+
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).ai_member_count, 2);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use common::*;
+use membership::{AuditLog, MemberRegistry};
+
+#[test]
+fn initialize_ignores_a_bogus_rent_account() {
+    let mut rt = Runtime::new();
+    // Rent-shaped data charging nothing, owned like the real sysvar.
+    let bogus_rent = Pubkey::new_unique();
+    let free = Rent {
+        lamports_per_byte_year: 0,
+        exemption_threshold: 0.0,
+        burn_percent: 0,
+    };
+    rt.set_account(
+        bogus_rent,
+        common::Account {
+            lamports: 1,
+            data: bincode_rent(&free),
+            owner: sysvar::ID,
+            executable: false,
+        },
+    );
+    let authority = rt.wallet(100 * SOL);
+    let (registry, audit_log) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::Initialize {
+            registry,
+            audit_log,
+            authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::Initialize {},
+    );
+    ix.accounts.push(AccountMeta::new_readonly(bogus_rent, false));
+
+    rt.send(ix, &[registry, audit_log, authority]).unwrap();
+
+    let rent = Rent::default();
+    assert_eq!(
+        rt.lamports(&registry),
+        rent.minimum_balance(MemberRegistry::MAX_ACCOUNT_SIZE)
+    );
+    assert_eq!(rt.lamports(&audit_log), rent.minimum_balance(AuditLog::MAX_ACCOUNT_SIZE));
+}
+
+/// `rent` in the sysvar's bincode layout.
+fn bincode_rent(rent: &Rent) -> Vec<u8> {
+    let mut data = rent.lamports_per_byte_year.to_le_bytes().to_vec();
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
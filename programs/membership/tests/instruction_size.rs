@@ -0,0 +1,45 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use common::*;
+use membership::{MemberRegistry, MembershipError, MAX_INSTRUCTION_DATA_LEN};
+
+fn raw(data: Vec<u8>) -> Instruction {
+    Instruction {
+        program_id: membership::ID,
+        accounts: Vec::new(),
+        data,
+    }
+}
+
+#[test]
+fn oversized_data_is_rejected_before_dispatch() {
+    let mut rt = Runtime::new();
+    let payer = rt.wallet(SOL);
+
+    // At the limit the data reaches Anchor, which finds no such instruction.
+    assert_error(
+        rt.send(raw(vec![0xff; MAX_INSTRUCTION_DATA_LEN]), &[payer]),
+        anchor_lang::error::ErrorCode::InstructionFallbackNotFound,
+    );
+    assert_error(
+        rt.send(raw(vec![0xff; MAX_INSTRUCTION_DATA_LEN + 1]), &[payer]),
+        MembershipError::InvalidInstruction,
+    );
+}
+
+#[test]
+fn oversized_batch_is_rejected_before_deserialization() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let entries: Vec<_> = (0..8).map(|_| new_member(10)).collect();
+    let accounts: Vec<_> = entries.iter().map(|_| Pubkey::new_unique()).collect();
+    let ix = add_members_batch_ix(&rt, &registry, entries, &accounts);
+    assert!(ix.data.len() > MAX_INSTRUCTION_DATA_LEN);
+
+    let mut signers = accounts.clone();
+    signers.push(registry.authority);
+    assert_error(rt.send(ix, &signers), MembershipError::InvalidInstruction);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 0);
+}
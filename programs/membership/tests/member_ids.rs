@@ -0,0 +1,41 @@
+mod common;
+
+use common::*;
+use membership::{MemberRegistry, MemberType, RegistryField};
+
+#[test]
+fn removed_ids_are_retired_by_default() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let second = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let removed_id = member(&rt, &first).id;
+
+    remove_member(&mut rt, &registry, &first);
+    let next = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    assert_eq!(member(&rt, &next).id, member(&rt, &second).id + 1);
+    assert_ne!(member(&rt, &next).id, removed_id);
+    assert!(rt.get::<MemberRegistry>(&registry.key).free_member_ids.is_empty());
+}
+
+#[test]
+fn removed_ids_are_handed_out_again_when_reuse_is_on() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::ReuseIds, 1);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let second = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let removed_id = member(&rt, &first).id;
+
+    remove_member(&mut rt, &registry, &first);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).free_member_ids, vec![removed_id]);
+    let reused = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let fresh = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    assert_eq!(member(&rt, &reused).id, removed_id);
+    assert_eq!(member(&rt, &fresh).id, member(&rt, &second).id + 1);
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert!(state.free_member_ids.is_empty());
+    assert_eq!(state.member_count, 3);
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{AuditLog, InstructionKind, MemberType, MembershipError};
+
+fn set_member_metadata(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    signer: Pubkey,
+    uri: &str,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetMemberMetadata {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            signer,
+        },
+        membership::instruction::SetMemberMetadata { uri: uri.to_string() },
+    );
+    rt.send(ix, &[signer])
+}
+
+fn audit_entries(rt: &Runtime, registry: &Registry) -> u64 {
+    rt.get::<AuditLog>(&current_audit_log(rt, registry)).next_offset
+}
+
+#[test]
+fn member_updates_their_own_metadata() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let logged = audit_entries(&rt, &registry);
+
+    set_member_metadata(&mut rt, &registry, &member, member.key, "ipfs://profile").unwrap();
+
+    assert_eq!(common::member(&rt, &member).metadata_uri, "ipfs://profile");
+    assert_eq!(audit_entries(&rt, &registry), logged);
+    let too_long = "x".repeat(membership::MAX_METADATA_URI_LEN + 1);
+    assert_error(
+        set_member_metadata(&mut rt, &registry, &member, member.key, &too_long),
+        MembershipError::MetadataUriTooLong,
+    );
+}
+
+#[test]
+fn stranger_cannot_update_someone_elses_metadata() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let other = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    assert_error(
+        set_member_metadata(&mut rt, &registry, &member, other.key, "ipfs://forged"),
+        MembershipError::NotAuthorized,
+    );
+    assert_eq!(common::member(&rt, &member).metadata_uri, "");
+}
+
+#[test]
+fn authority_override_is_audited() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let logged = audit_entries(&rt, &registry);
+
+    set_member_metadata(&mut rt, &registry, &member, registry.authority, "ipfs://corrected").unwrap();
+
+    assert_eq!(common::member(&rt, &member).metadata_uri, "ipfs://corrected");
+    let log: AuditLog = rt.get(&current_audit_log(&rt, &registry));
+    assert_eq!(log.next_offset, logged + 1);
+    let entry = &log.entries[logged as usize];
+    assert!(entry.kind == InstructionKind::SetMemberMetadata);
+    assert_eq!(entry.actor, registry.authority);
+}
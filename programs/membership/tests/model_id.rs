@@ -0,0 +1,74 @@
+mod common;
+
+use common::*;
+use membership::{MemberType, MembershipError, RegistryField};
+
+fn set_model_id(
+    rt: &mut Runtime,
+    registry: &Registry,
+    member: &TestMember,
+    model_id: &str,
+) -> std::result::Result<(), TxError> {
+    let member_id = common::member(rt, member).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetModelId {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+        },
+        membership::instruction::SetModelId {
+            member_id,
+            model_id: model_id.to_string(),
+        },
+    );
+    rt.send(ix, &[registry.authority])
+}
+
+#[test]
+fn ai_member_must_disclose_a_model_id_when_required() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 1_000);
+    update_config(&mut rt, &registry, RegistryField::RequireModelId, 1);
+
+    let anonymous = member_args(&rt, &registry, MemberType::AI, 10);
+    assert_error(try_add_member(&mut rt, &registry, anonymous), MembershipError::ModelIdRequired);
+
+    let mut disclosed = member_args(&rt, &registry, MemberType::AI, 10);
+    disclosed.model_id = "example-model-v2".to_string();
+    let ai = try_add_member(&mut rt, &registry, disclosed).unwrap();
+    assert_eq!(member(&rt, &ai).model_id, "example-model-v2");
+
+    set_model_id(&mut rt, &registry, &ai, "example-model-v3").unwrap();
+    assert_eq!(member(&rt, &ai).model_id, "example-model-v3");
+    assert_error(set_model_id(&mut rt, &registry, &ai, ""), MembershipError::ModelIdRequired);
+}
+
+#[test]
+fn ai_member_may_omit_the_model_id_unless_required() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 1_000);
+
+    let ai = add_member(&mut rt, &registry, MemberType::AI, 10);
+    assert_eq!(member(&rt, &ai).model_id, "");
+}
+
+#[test]
+fn human_member_may_not_have_a_model_id() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+
+    let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+    args.model_id = "example-model-v2".to_string();
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::ModelIdNotAllowed);
+
+    let human = add_member(&mut rt, &registry, MemberType::Human, 10);
+    assert_error(
+        set_model_id(&mut rt, &registry, &human, "example-model-v2"),
+        MembershipError::ModelIdNotAllowed,
+    );
+    set_model_id(&mut rt, &registry, &human, "").unwrap();
+}
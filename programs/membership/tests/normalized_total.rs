@@ -0,0 +1,41 @@
+mod common;
+
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, RegistryField};
+
+#[test]
+fn registrations_past_the_normalized_total_are_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::NormalizedTotal, 150);
+    add_member(&mut rt, &registry, MemberType::Human, 100);
+
+    let args = member_args(&rt, &registry, MemberType::Human, 51);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::ExceedsNormalizedTotal);
+    add_member(&mut rt, &registry, MemberType::Human, 50);
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.total_voting_power, 150);
+    assert_eq!(state.member_count, 2);
+}
+
+#[test]
+fn adjustments_past_the_normalized_total_are_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::NormalizedTotal, 150);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let second = add_member(&mut rt, &registry, MemberType::Human, 50);
+
+    let ix = adjust_voting_power_ix(&rt, &registry, &first, None, 1);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::ExceedsNormalizedTotal);
+
+    // Freed shares can be handed to another member; nobody is rescaled.
+    let ix = adjust_voting_power_ix(&rt, &registry, &second, None, -20);
+    rt.send(ix, &[registry.authority]).unwrap();
+    let ix = adjust_voting_power_ix(&rt, &registry, &first, None, 20);
+    rt.send(ix, &[registry.authority]).unwrap();
+    assert_eq!(member(&rt, &first).voting_power, 120);
+    assert_eq!(member(&rt, &second).voting_power, 30);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).total_voting_power, 150);
+}
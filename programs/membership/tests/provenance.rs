@@ -0,0 +1,27 @@
+mod common;
+
+use common::*;
+use membership::MemberType;
+
+#[test]
+fn member_records_who_created_it_and_when() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    rt.advance_time(3_600);
+    let created_at = rt.clock().unix_timestamp;
+
+    let added = add_member(&mut rt, &registry, MemberType::Human, 100);
+
+    let state = member(&rt, &added);
+    assert_eq!(state.created_by, registry.authority);
+    assert_eq!(state.created_at, created_at);
+
+    rt.advance_time(3_600);
+    let ix = adjust_voting_power_ix(&rt, &registry, &added, None, 50);
+    rt.send(ix, &[registry.authority]).unwrap();
+
+    let state = member(&rt, &added);
+    assert_eq!(state.voting_power, 150);
+    assert_eq!(state.created_by, registry.authority);
+    assert_eq!(state.created_at, created_at);
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{ControlReassigned, MemberRegistry, MemberType, MembershipError, Role};
+
+fn set_role_members(rt: &mut Runtime, registry: &Registry, role: Role, pubkeys: Vec<Pubkey>) {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetRoleMembers {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::SetRoleMembers { role, pubkeys },
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+fn reassign_control(
+    rt: &mut Runtime,
+    registry: &Registry,
+    new_authority: Pubkey,
+    new_treasury_admin: Pubkey,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::ReassignControl {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::ReassignControl {
+            new_authority,
+            new_treasury_admin,
+        },
+    );
+    rt.send(ix, &[registry.authority])
+}
+
+#[test]
+fn control_moves_to_the_new_operator_all_at_once() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let old = registry.authority;
+    let registrar = rt.wallet(SOL);
+    set_role_members(&mut rt, &registry, Role::RegistrationAdmin, vec![old, registrar]);
+    set_role_members(&mut rt, &registry, Role::ConfigAdmin, vec![old]);
+    set_role_members(&mut rt, &registry, Role::TreasuryAdmin, vec![old]);
+    let new_authority = rt.wallet(SOL);
+    let new_treasury_admin = Pubkey::new_unique();
+
+    reassign_control(&mut rt, &registry, new_authority, new_treasury_admin).unwrap();
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.authority, new_authority);
+    assert_eq!(state.treasury_admins, vec![new_treasury_admin]);
+    assert_eq!(state.registration_admins, vec![registrar]);
+    assert!(state.config_admins.is_empty());
+    let events = rt.events::<ControlReassigned>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].previous_authority, old);
+    assert_eq!(events[0].new_authority, new_authority);
+    assert_eq!(events[0].treasury_admin, new_treasury_admin);
+
+    // The old operator keeps no way back in.
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::NotAuthorized);
+    let new_operator = Registry {
+        authority: new_authority,
+        ..registry
+    };
+    add_member(&mut rt, &new_operator, MemberType::Human, 10);
+}
+
+#[test]
+fn invalid_key_leaves_every_field_unchanged() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    set_role_members(&mut rt, &registry, Role::ConfigAdmin, vec![registry.authority]);
+    let before: MemberRegistry = rt.get(&registry.key);
+
+    assert_error(
+        reassign_control(&mut rt, &registry, Pubkey::new_unique(), Pubkey::default()),
+        MembershipError::InvalidConfigValue,
+    );
+
+    let after: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(after.authority, before.authority);
+    assert_eq!(after.treasury_admins, before.treasury_admins);
+    assert_eq!(after.config_admins, before.config_admins);
+    assert!(rt.events::<ControlReassigned>().is_empty());
+}
+
+#[test]
+fn only_the_authority_may_reassign_control() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let config_admin = rt.wallet(SOL);
+    set_role_members(&mut rt, &registry, Role::ConfigAdmin, vec![config_admin]);
+    let as_config_admin = Registry {
+        authority: config_admin,
+        ..registry
+    };
+
+    assert_error(
+        reassign_control(&mut rt, &as_config_admin, config_admin, config_admin),
+        MembershipError::NotAuthorized,
+    );
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).authority, registry.authority);
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError};
+
+fn reconcile(rt: &mut Runtime, registry: &Registry, accounts: &[Pubkey]) -> std::result::Result<(), TxError> {
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::ReconcileMemberCount {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::ReconcileMemberCount {},
+    );
+    ix.accounts
+        .extend(accounts.iter().map(|&account| AccountMeta::new_readonly(account, false)));
+    rt.send(ix, &[registry.authority])
+}
+
+#[test]
+fn inflated_count_is_reset_to_the_live_members() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let second = add_member(&mut rt, &registry, MemberType::AI, 10);
+    rt.update(&registry.key, |state: &mut MemberRegistry| state.member_count = 5);
+
+    reconcile(&mut rt, &registry, &[first.account, second.account]).unwrap();
+
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 2);
+}
+
+#[test]
+fn foreign_or_repeated_accounts_are_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let other_registry = create_registry(&mut rt);
+    let outsider = add_member(&mut rt, &other_registry, MemberType::Human, 10);
+    let wallet = rt.wallet(SOL);
+    rt.update(&registry.key, |state: &mut MemberRegistry| state.member_count = 5);
+
+    assert_error(
+        reconcile(&mut rt, &registry, &[member.account, member.account]),
+        MembershipError::IncompleteMemberSet,
+    );
+    assert_error(
+        reconcile(&mut rt, &registry, &[member.account, outsider.account]),
+        MembershipError::InvalidMemberAccount,
+    );
+    assert!(reconcile(&mut rt, &registry, &[member.account, wallet]).is_err());
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 5);
+}
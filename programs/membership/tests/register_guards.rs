@@ -0,0 +1,20 @@
+mod common;
+
+use common::*;
+use membership::{MemberRegistry, MemberType};
+
+#[test]
+fn registry_account_cannot_be_registered_as_a_member() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let before = rt.account(&registry.key).unwrap().clone();
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    let (mut ix, _) = add_member_ix(&rt, &registry, args);
+    ix.accounts[2].pubkey = registry.key;
+
+    // `init` refuses an account already in use before the key constraint
+    // is reached; either way the registry must come through untouched.
+    assert!(rt.send(ix, &[registry.key, registry.authority]).is_err());
+    assert!(rt.account(&registry.key).unwrap().data == before.data);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 0);
+}
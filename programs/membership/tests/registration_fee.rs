@@ -0,0 +1,80 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberType, MembershipError, RegistryField};
+
+/// A registry charging `fee` per registration into a fresh treasury.
+fn registry_with_fee(rt: &mut Runtime, fee: u64) -> (Registry, Pubkey) {
+    let registry = create_registry(rt);
+    let treasury = rt.wallet(SOL);
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetTreasury {
+                registry: registry.key,
+                audit_log: registry.audit_log,
+                authority: registry.authority,
+            },
+            membership::instruction::SetTreasury { treasury },
+        ),
+        &[registry.authority],
+    )
+    .unwrap();
+    update_config(rt, &registry, RegistryField::RegistrationFee, fee);
+    (registry, treasury)
+}
+
+fn add_paying_member(
+    rt: &mut Runtime,
+    registry: &Registry,
+    treasury: Option<Pubkey>,
+) -> std::result::Result<(), TxError> {
+    let args = member_args(rt, registry, MemberType::Human, 10);
+    let (mut ix, member) = add_member_ix(rt, registry, args);
+    ix.accounts[4] = match treasury {
+        Some(key) => AccountMeta::new(key, false),
+        None => AccountMeta::new_readonly(membership::ID, false),
+    };
+    rt.send(ix, &[member.account, registry.authority])
+}
+
+#[test]
+fn registration_fee_is_paid_to_the_treasury() {
+    let mut rt = Runtime::new();
+    let (registry, treasury) = registry_with_fee(&mut rt, SOL / 2);
+
+    add_paying_member(&mut rt, &registry, Some(treasury)).unwrap();
+    assert_eq!(rt.lamports(&treasury), SOL + SOL / 2);
+
+    assert_error(
+        add_paying_member(&mut rt, &registry, None),
+        MembershipError::InvalidTreasury,
+    );
+    let elsewhere = rt.wallet(SOL);
+    assert_error(
+        add_paying_member(&mut rt, &registry, Some(elsewhere)),
+        MembershipError::InvalidTreasury,
+    );
+}
+
+#[test]
+fn registration_fee_needs_the_funds() {
+    let mut rt = Runtime::new();
+    let (registry, treasury) = registry_with_fee(&mut rt, 1_000 * SOL);
+
+    assert_error(
+        add_paying_member(&mut rt, &registry, Some(treasury)),
+        MembershipError::InsufficientFunds,
+    );
+    assert_eq!(rt.lamports(&treasury), SOL);
+}
+
+#[test]
+fn no_fee_is_charged_by_default() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let before = rt.lamports(&registry.authority);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    assert_eq!(rt.lamports(&registry.authority), before - rt.lamports(&member.account));
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError};
+
+fn nonce(rt: &Runtime, registry: &Registry) -> u64 {
+    rt.get::<MemberRegistry>(&registry.key).registration_nonce
+}
+
+#[test]
+fn each_registration_consumes_the_current_nonce() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    assert_eq!(nonce(&rt, &registry), 0);
+
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_eq!(args.nonce, 0);
+    try_add_member(&mut rt, &registry, args).unwrap();
+    assert_eq!(nonce(&rt, &registry), 1);
+}
+
+#[test]
+fn replayed_nonce_is_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    let (ix, first) = add_member_ix(&rt, &registry, args);
+    rt.send(ix.clone(), &[first.account, registry.authority]).unwrap();
+
+    // The same instruction data, replayed into a fresh member account.
+    let mut replay = ix;
+    let account = Pubkey::new_unique();
+    replay.accounts[2] = AccountMeta::new(account, true);
+    assert_error(rt.send(replay, &[account, registry.authority]), MembershipError::InvalidNonce);
+    assert_eq!(nonce(&rt, &registry), 1);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 1);
+}
+
+#[test]
+fn future_nonce_is_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+    args.nonce += 1;
+
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::InvalidNonce);
+    assert_eq!(nonce(&rt, &registry), 0);
+}
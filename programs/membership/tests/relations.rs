@@ -0,0 +1,123 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{MemberRelations, MemberType, MembershipError};
+
+fn endorsement_address(endorser: &TestMember, endorsee: &TestMember) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"endorsement", endorser.account.as_ref(), endorsee.account.as_ref()],
+        &membership::ID,
+    )
+    .0
+}
+
+fn endorse(rt: &mut Runtime, registry: &Registry, endorser: &TestMember, endorsee: &TestMember) {
+    let endorsee_id = member(rt, endorsee).id;
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::EndorseMember {
+            registry: registry.key,
+            endorser: endorser.account,
+            endorsee: endorsee.account,
+            endorsement: endorsement_address(endorser, endorsee),
+            owner: endorser.key,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::EndorseMember { endorsee_id },
+    );
+    rt.send(ix, &[endorser.key]).unwrap();
+}
+
+fn try_relations(
+    rt: &mut Runtime,
+    registry: &Registry,
+    subject: &TestMember,
+    related: &[Pubkey],
+) -> std::result::Result<(), TxError> {
+    let member_id = member(rt, subject).id;
+    let payer = rt.wallet(SOL);
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::GetMemberRelations {
+            registry: registry.key,
+            member: subject.account,
+        },
+        membership::instruction::GetMemberRelations { member_id },
+    );
+    ix.accounts
+        .extend(related.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+    rt.send(ix, &[payer])
+}
+
+fn get_relations(
+    rt: &mut Runtime,
+    registry: &Registry,
+    subject: &TestMember,
+    related: &[Pubkey],
+) -> MemberRelations {
+    try_relations(rt, registry, subject, related).unwrap();
+    rt.returned()
+}
+
+#[test]
+fn relation_graph_is_read_back() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let alice = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let bob = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let carol = add_member(&mut rt, &registry, MemberType::AI, 10);
+    let dave = add_member(&mut rt, &registry, MemberType::Human, 10);
+    set_delegate(&mut rt, &registry, &alice, &bob);
+    set_delegate(&mut rt, &registry, &carol, &bob);
+    set_delegate(&mut rt, &registry, &bob, &dave);
+    endorse(&mut rt, &registry, &alice, &bob);
+    endorse(&mut rt, &registry, &dave, &bob);
+
+    let related = [
+        alice.account,
+        endorsement_address(&dave, &bob),
+        carol.account,
+        endorsement_address(&alice, &bob),
+    ];
+    let relations = get_relations(&mut rt, &registry, &bob, &related);
+    assert_eq!(relations.delegated_to, Some(dave.account));
+    assert_eq!(relations.delegated_from, vec![alice.account, carol.account]);
+    assert_eq!(relations.endorsers, vec![dave.account, alice.account]);
+    assert_eq!(relations.endorsement_count, 2);
+
+    // The count covers endorsements that were not passed.
+    let relations = get_relations(&mut rt, &registry, &bob, &[]);
+    assert!(relations.delegated_from.is_empty() && relations.endorsers.is_empty());
+    assert_eq!(relations.endorsement_count, 2);
+
+    let relations = get_relations(&mut rt, &registry, &dave, &[bob.account]);
+    assert_eq!(relations.delegated_to, None);
+    assert_eq!(relations.delegated_from, vec![bob.account]);
+    assert_eq!(relations.endorsement_count, 0);
+}
+
+#[test]
+fn accounts_not_referencing_the_member_are_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let alice = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let bob = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let carol = add_member(&mut rt, &registry, MemberType::Human, 10);
+    set_delegate(&mut rt, &registry, &alice, &bob);
+    endorse(&mut rt, &registry, &alice, &carol);
+
+    assert_error(
+        try_relations(&mut rt, &registry, &bob, &[carol.account]),
+        MembershipError::RelationMismatch,
+    );
+    assert_error(
+        try_relations(&mut rt, &registry, &bob, &[endorsement_address(&alice, &carol)]),
+        MembershipError::RelationMismatch,
+    );
+    assert_error(
+        try_relations(&mut rt, &registry, &bob, &[alice.account, alice.account]),
+        MembershipError::MemberAlreadyExists,
+    );
+}
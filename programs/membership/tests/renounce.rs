@@ -0,0 +1,65 @@
+mod common;
+
+use common::*;
+use membership::{Capability, MemberRegistry, MemberType, MembershipError};
+
+fn renounce(rt: &mut Runtime, registry: &Registry, capability: Capability) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::RenounceCapability {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::RenounceCapability { capability },
+    );
+    rt.send(ix, &[registry.authority])
+}
+
+#[test]
+fn renounced_power_updates_are_refused_for_good() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 100);
+
+    renounce(&mut rt, &registry, Capability::UpdateVotingPower).unwrap();
+
+    let ix = adjust_voting_power_ix(&rt, &registry, &member, None, 10);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::CapabilityRenounced);
+    assert_eq!(common::member(&rt, &member).voting_power, 100);
+    // Renouncing again changes nothing, and other capabilities are untouched.
+    renounce(&mut rt, &registry, Capability::UpdateVotingPower).unwrap();
+    assert_eq!(
+        rt.get::<MemberRegistry>(&registry.key).renounced_capabilities,
+        Capability::UpdateVotingPower.bit()
+    );
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+}
+
+#[test]
+fn renounced_registration_blocks_new_members() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+
+    renounce(&mut rt, &registry, Capability::AddMembers).unwrap();
+
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::CapabilityRenounced);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 0);
+}
+
+#[test]
+fn only_the_authority_may_renounce() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let outsider = Registry {
+        authority: rt.wallet(SOL),
+        ..registry
+    };
+
+    assert_error(
+        renounce(&mut rt, &outsider, Capability::UpdateConfig),
+        MembershipError::NotAuthorized,
+    );
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).renounced_capabilities, 0);
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{Member, MemberType, MembershipError, RegistryField};
+
+#[test]
+fn registration_funds_the_buffer_above_exemption() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::RentBufferLamports, 5_000);
+
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    let exempt = Rent::default().minimum_balance(Member::MAX_ACCOUNT_SIZE);
+    assert_eq!(rt.lamports(&member.account), exempt + 5_000);
+}
+
+#[test]
+fn account_left_at_the_exemption_minimum_is_rejected() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let balance = rt.lamports(&registry.authority);
+    // The authority can pay for the account but not for the buffer on top.
+    update_config(&mut rt, &registry, RegistryField::RentBufferLamports, balance);
+
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::InsufficientFunds);
+
+    update_config(&mut rt, &registry, RegistryField::RentBufferLamports, 0);
+    let member = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let exempt = Rent::default().minimum_balance(Member::MAX_ACCOUNT_SIZE);
+    assert_eq!(rt.lamports(&member.account), exempt);
+}
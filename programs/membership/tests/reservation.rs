@@ -0,0 +1,105 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use common::*;
+use membership::{MemberIdReservation, MembershipError};
+
+fn reserve_ix(rt: &Runtime, registry: &Registry, reservation: Pubkey) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::ReserveMemberId {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            reservation,
+            authority: registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::ReserveMemberId {},
+    )
+}
+
+fn reserve(rt: &mut Runtime, registry: &Registry) -> (Pubkey, u64) {
+    let reservation = Pubkey::new_unique();
+    let ix = reserve_ix(rt, registry, reservation);
+    rt.send(ix, &[reservation, registry.authority]).unwrap();
+    (reservation, rt.returned())
+}
+
+fn commit(
+    rt: &mut Runtime,
+    registry: &Registry,
+    reservation: Pubkey,
+    reserved_id: u64,
+) -> std::result::Result<TestMember, TxError> {
+    let entry = new_member(10);
+    let member = TestMember {
+        account: Pubkey::new_unique(),
+        key: entry.pubkey,
+    };
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::CommitMember {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            reservation,
+            member: member.account,
+            treasury: None,
+            name_entry: None,
+            authority: registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::CommitMember { reserved_id, entry },
+    );
+    rt.send(ix, &[member.account, registry.authority])?;
+    Ok(member)
+}
+
+#[test]
+fn reservations_in_one_transaction_get_distinct_ids() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ixs = [reserve_ix(&rt, &registry, first), reserve_ix(&rt, &registry, second)];
+
+    rt.send_all(&ixs, &[first, second, registry.authority]).unwrap();
+
+    let first_id = rt.get::<MemberIdReservation>(&first).id;
+    let second_id = rt.get::<MemberIdReservation>(&second).id;
+    assert_ne!(first_id, second_id);
+    let (_, third_id) = reserve(&mut rt, &registry);
+    assert!(third_id != first_id && third_id != second_id);
+}
+
+#[test]
+fn commit_creates_the_member_at_its_reserved_id() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let (first, first_id) = reserve(&mut rt, &registry);
+    let (second, second_id) = reserve(&mut rt, &registry);
+
+    let later = commit(&mut rt, &registry, second, second_id).unwrap();
+    let earlier = commit(&mut rt, &registry, first, first_id).unwrap();
+
+    assert_eq!(member(&rt, &earlier).id, first_id);
+    assert_eq!(member(&rt, &later).id, second_id);
+    assert!(rt.account(&first).is_none());
+    assert!(rt.account(&second).is_none());
+}
+
+#[test]
+fn commit_refuses_an_id_other_than_the_reserved_one() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let (reservation, id) = reserve(&mut rt, &registry);
+
+    assert_error(
+        commit(&mut rt, &registry, reservation, id + 1),
+        MembershipError::InvalidReservation,
+    );
+    commit(&mut rt, &registry, reservation, id).unwrap();
+    assert_error(
+        commit(&mut rt, &registry, reservation, id),
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+}
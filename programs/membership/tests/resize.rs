@@ -0,0 +1,122 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::Discriminator;
+use common::*;
+use membership::{Member, MemberRegistry, MemberType, MembershipError};
+
+/// Size the original `AddMember` allocated for every member account.
+const V1_ACCOUNT_SIZE: usize = 8 + 32 + 1 + 8 + 8 + 1 + 256 + 512 + 64 + 1;
+
+/// Writes a member account in the original layout, which ended at
+/// `kyc_verified` and had no link to a registry.
+fn v1_member(rt: &mut Runtime, pubkey: Pubkey, voting_power: u64) -> Pubkey {
+    let mut data = Member::DISCRIMINATOR.to_vec();
+    let fields = (
+        pubkey,
+        MemberType::Human,
+        voting_power,
+        1_700_000_000i64,
+        true,
+        "Ada Lovelace".to_string(),
+        "1 Main St, Cheyenne WY".to_string(),
+        "00-0000000".to_string(),
+        true,
+    );
+    data.extend(anchor_lang::prelude::borsh::to_vec(&fields).unwrap());
+    data.resize(V1_ACCOUNT_SIZE, 0);
+    let account = Pubkey::new_unique();
+    rt.set_account(
+        account,
+        common::Account {
+            lamports: Rent::default().minimum_balance(V1_ACCOUNT_SIZE),
+            data,
+            owner: membership::ID,
+            executable: false,
+        },
+    );
+    account
+}
+
+fn resize_ix(registry: &Registry, member: Pubkey, payer: Pubkey, authority: Option<Pubkey>) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::ResizeMemberAccount {
+            registry: registry.key,
+            member,
+            payer,
+            system_program: anchor_lang::system_program::ID,
+            authority,
+        },
+        membership::instruction::ResizeMemberAccount {},
+    )
+}
+
+#[test]
+fn v1_member_is_resized_with_its_data_intact() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let pubkey = Pubkey::new_unique();
+    let account = v1_member(&mut rt, pubkey, 40);
+    let payer = rt.wallet(SOL);
+
+    rt.send(resize_ix(&registry, account, payer, Some(registry.authority)), &[payer, registry.authority])
+        .unwrap();
+
+    assert_eq!(rt.account(&account).unwrap().data.len(), Member::MAX_ACCOUNT_SIZE);
+    let exempt = Rent::default().minimum_balance(Member::MAX_ACCOUNT_SIZE);
+    assert!(rt.lamports(&account) >= exempt);
+    let member: Member = rt.get(&account);
+    assert_eq!(member.pubkey, pubkey);
+    assert!(member.member_type == MemberType::Human);
+    assert_eq!(member.voting_power, 40);
+    assert_eq!(member.joined_at, 1_700_000_000);
+    assert!(member.is_active);
+    assert_eq!(member.legal_name, "Ada Lovelace");
+    assert_eq!(member.address, "1 Main St, Cheyenne WY");
+    assert_eq!(member.tax_id, "00-0000000");
+    assert!(member.kyc_verified);
+    assert_eq!(member.registry, registry.key);
+    assert_eq!(member.delegate, None);
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.member_count, 1);
+    assert_eq!(state.total_voting_power, 40);
+
+    // A second call finds the account at the current size and does nothing.
+    rt.send(resize_ix(&registry, account, payer, None), &[payer]).unwrap();
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 1);
+    assert_eq!(rt.get::<Member>(&account).id, member.id);
+}
+
+#[test]
+fn adopting_a_v1_member_needs_a_registration_admin() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let account = v1_member(&mut rt, Pubkey::new_unique(), 40);
+    let payer = rt.wallet(SOL);
+
+    assert_error(
+        rt.send(resize_ix(&registry, account, payer, None), &[payer]),
+        MembershipError::NotAuthorized,
+    );
+    assert_error(
+        rt.send(resize_ix(&registry, account, payer, Some(payer)), &[payer]),
+        MembershipError::NotAuthorized,
+    );
+    assert_eq!(rt.account(&account).unwrap().data.len(), V1_ACCOUNT_SIZE);
+}
+
+#[test]
+fn other_program_accounts_are_not_resized() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let payer = rt.wallet(SOL);
+    let audit_log = current_audit_log(&rt, &registry);
+
+    assert_error(
+        rt.send(resize_ix(&registry, audit_log, payer, Some(registry.authority)), &[payer, registry.authority]),
+        MembershipError::InvalidMemberAccount,
+    );
+}
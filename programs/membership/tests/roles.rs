@@ -0,0 +1,94 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, Role};
+
+fn set_role_members(
+    rt: &mut Runtime,
+    registry: &Registry,
+    role: Role,
+    pubkeys: Vec<Pubkey>,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetRoleMembers {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::SetRoleMembers { role, pubkeys },
+    );
+    rt.send(ix, &[registry.authority])
+}
+
+fn set_treasury(rt: &mut Runtime, registry: &Registry, treasury: Pubkey) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetTreasury {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            authority: registry.authority,
+        },
+        membership::instruction::SetTreasury { treasury },
+    );
+    rt.send(ix, &[registry.authority])
+}
+
+#[test]
+fn each_role_only_reaches_its_own_instructions() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let registrar = rt.wallet(SOL);
+    let treasurer = rt.wallet(SOL);
+    set_role_members(&mut rt, &registry, Role::RegistrationAdmin, vec![registrar]).unwrap();
+    set_role_members(&mut rt, &registry, Role::TreasuryAdmin, vec![treasurer]).unwrap();
+    let as_registrar = Registry {
+        authority: registrar,
+        ..registry
+    };
+    let as_treasurer = Registry {
+        authority: treasurer,
+        ..registry
+    };
+
+    add_member(&mut rt, &as_registrar, MemberType::Human, 10);
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &as_treasurer, args), MembershipError::NotAuthorized);
+
+    let treasury = Pubkey::new_unique();
+    assert_error(set_treasury(&mut rt, &as_registrar, treasury), MembershipError::NotAuthorized);
+    set_treasury(&mut rt, &as_treasurer, treasury).unwrap();
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).treasury, treasury);
+}
+
+#[test]
+fn only_a_config_admin_assigns_roles() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let registrar = rt.wallet(SOL);
+    let config_admin = rt.wallet(SOL);
+    set_role_members(&mut rt, &registry, Role::RegistrationAdmin, vec![registrar]).unwrap();
+
+    let as_registrar = Registry {
+        authority: registrar,
+        ..registry
+    };
+    assert_error(
+        set_role_members(&mut rt, &as_registrar, Role::RegistrationAdmin, vec![registrar, config_admin]),
+        MembershipError::NotAuthorized,
+    );
+
+    set_role_members(&mut rt, &registry, Role::ConfigAdmin, vec![config_admin]).unwrap();
+    let as_config_admin = Registry {
+        authority: config_admin,
+        ..registry
+    };
+    set_role_members(&mut rt, &as_config_admin, Role::RegistrationAdmin, Vec::new()).unwrap();
+    assert!(rt.get::<MemberRegistry>(&registry.key).registration_admins.is_empty());
+
+    // With the role emptied only the authority still registers members.
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &as_registrar, args), MembershipError::NotAuthorized);
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+}
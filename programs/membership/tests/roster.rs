@@ -0,0 +1,67 @@
+mod common;
+
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{MemberType, RosterReport};
+
+fn validate_roster(rt: &mut Runtime, registry: &Registry, members: &[&TestMember]) -> RosterReport {
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::ValidateRoster { registry: registry.key },
+        membership::instruction::ValidateRoster {},
+    );
+    ix.accounts
+        .extend(members.iter().map(|member| AccountMeta::new_readonly(member.account, false)));
+    let payer = rt.wallet(SOL);
+    rt.send(ix, &[payer]).unwrap();
+    rt.returned()
+}
+
+fn roster(rt: &mut Runtime, registry: &Registry) -> [TestMember; 3] {
+    [
+        add_member(rt, registry, MemberType::Human, 10),
+        add_member(rt, registry, MemberType::Human, 10),
+        add_member(rt, registry, MemberType::Human, 10),
+    ]
+}
+
+#[test]
+fn complete_roster_reports_nothing() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let [first, second, third] = roster(&mut rt, &registry);
+
+    let report = validate_roster(&mut rt, &registry, &[&third, &first, &second]);
+    assert_eq!(report.missing_count, 0);
+    assert_eq!(report.duplicate_count, 0);
+    assert_eq!(report.out_of_range_count, 0);
+    assert_eq!(report.first_missing, None);
+    assert_eq!(report.first_duplicate, None);
+}
+
+#[test]
+fn gap_in_the_roster_is_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let [first, second, third] = roster(&mut rt, &registry);
+
+    let report = validate_roster(&mut rt, &registry, &[&first, &third]);
+    assert_eq!(report.missing_count, 1);
+    assert_eq!(report.first_missing, Some(member(&rt, &second).id));
+
+    let report = validate_roster(&mut rt, &registry, &[&first]);
+    assert_eq!(report.missing_count, 2);
+    assert_eq!(report.first_missing, Some(member(&rt, &second).id));
+}
+
+#[test]
+fn duplicate_member_is_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let [first, second, third] = roster(&mut rt, &registry);
+
+    let report = validate_roster(&mut rt, &registry, &[&first, &second, &second, &third]);
+    assert_eq!(report.duplicate_count, 1);
+    assert_eq!(report.first_duplicate, Some(member(&rt, &second).id));
+    assert_eq!(report.missing_count, 0);
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use common::*;
+use membership::{manifest_leaf, MemberRegistry, MembershipError, NewMember, RosterImport};
+
+/// A four-entry manifest, its Merkle root and each entry's proof.
+struct Manifest {
+    entries: Vec<NewMember>,
+    root: [u8; 32],
+    proofs: Vec<Vec<[u8; 32]>>,
+}
+
+fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[1], &low, &high]).to_bytes()
+}
+
+fn manifest() -> Manifest {
+    let entries: Vec<NewMember> = (1..=4).map(|i| new_member(i * 10)).collect();
+    let leaves: Vec<[u8; 32]> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| manifest_leaf(i as u64, entry).unwrap())
+        .collect();
+    let pairs = [node(leaves[0], leaves[1]), node(leaves[2], leaves[3])];
+    let proofs = (0..4).map(|i| vec![leaves[i ^ 1], pairs[1 - i / 2]]).collect();
+    Manifest {
+        entries,
+        root: node(pairs[0], pairs[1]),
+        proofs,
+    }
+}
+
+fn member_pda(registry: &Registry, id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"member", registry.key.as_ref(), &id.to_le_bytes()], &membership::ID).0
+}
+
+fn begin_import(rt: &mut Runtime, registry: &Registry, manifest: &Manifest) -> Pubkey {
+    let roster_import = Pubkey::new_unique();
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::BeginRosterImport {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            roster_import,
+            authority: registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::BeginRosterImport {
+            manifest_root: manifest.root,
+            entry_count: manifest.entries.len() as u64,
+        },
+    );
+    rt.send(ix, &[roster_import, registry.authority]).unwrap();
+    roster_import
+}
+
+fn import_chunk(
+    rt: &mut Runtime,
+    registry: &Registry,
+    roster_import: Pubkey,
+    start: u64,
+    entries: Vec<NewMember>,
+    proofs: Vec<Vec<[u8; 32]>>,
+) -> std::result::Result<(), TxError> {
+    let ids = start..start + entries.len() as u64;
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::ImportRoster {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            roster_import,
+            payer: registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::ImportRoster { start, entries, proofs },
+    );
+    ix.accounts
+        .extend(ids.map(|id| AccountMeta::new(member_pda(registry, id), false)));
+    rt.send(ix, &[registry.authority])
+}
+
+/// Submits manifest positions `range` with their own proofs.
+fn import_range(
+    rt: &mut Runtime,
+    registry: &Registry,
+    roster_import: Pubkey,
+    manifest: &Manifest,
+    range: std::ops::Range<usize>,
+) -> std::result::Result<(), TxError> {
+    let entries = manifest.entries[range.clone()].to_vec();
+    let proofs = manifest.proofs[range.clone()].to_vec();
+    import_chunk(rt, registry, roster_import, range.start as u64, entries, proofs)
+}
+
+#[test]
+fn manifest_imports_in_chunks_and_resubmission_is_skipped() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let manifest = manifest();
+    let roster_import = begin_import(&mut rt, &registry, &manifest);
+
+    import_range(&mut rt, &registry, roster_import, &manifest, 0..2).unwrap();
+    assert_eq!(rt.get::<RosterImport>(&roster_import).cursor, 2);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 2);
+
+    // Re-running the whole manifest writes only what is still missing.
+    // Four entries with proofs are too large for one instruction.
+    for _ in 0..2 {
+        import_range(&mut rt, &registry, roster_import, &manifest, 0..2).unwrap();
+        import_range(&mut rt, &registry, roster_import, &manifest, 2..4).unwrap();
+    }
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.member_count, 4);
+    assert_eq!(state.total_voting_power, 100);
+    assert_eq!(rt.get::<RosterImport>(&roster_import).cursor, 4);
+    for (id, entry) in manifest.entries.iter().enumerate() {
+        let member: membership::Member = rt.get(&member_pda(&registry, id as u64));
+        assert_eq!(member.id, id as u64);
+        assert_eq!(member.pubkey, entry.pubkey);
+        assert_eq!(member.voting_power, entry.voting_power);
+    }
+}
+
+#[test]
+fn entry_outside_the_manifest_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let manifest = manifest();
+    let roster_import = begin_import(&mut rt, &registry, &manifest);
+
+    let mut forged = manifest.entries[0].clone();
+    forged.voting_power = 1_000;
+    assert_error(
+        import_chunk(&mut rt, &registry, roster_import, 0, vec![forged], vec![manifest.proofs[0].clone()]),
+        MembershipError::InvalidManifestEntry,
+    );
+    // Entries may not be skipped ahead of the cursor either.
+    assert_error(
+        import_range(&mut rt, &registry, roster_import, &manifest, 2..4),
+        MembershipError::InvalidManifestEntry,
+    );
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).member_count, 0);
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, RegistryField};
+
+#[test]
+fn sealed_roster_refuses_every_registration_path() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::SelfRegistration, 1);
+    let (ix, applicant) = apply_for_membership_ix(&mut rt, &registry);
+    rt.send(ix, &[applicant.account, applicant.key]).unwrap();
+
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SealRoster {
+                registry: registry.key,
+                audit_log: current_audit_log(&rt, &registry),
+                authority: registry.authority,
+            },
+            membership::instruction::SealRoster {},
+        ),
+        &[registry.authority],
+    )
+    .unwrap();
+    assert!(rt.get::<MemberRegistry>(&registry.key).roster_sealed);
+
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::RosterSealed);
+
+    let account = Pubkey::new_unique();
+    let ix = add_members_batch_ix(&rt, &registry, vec![new_member(10)], &[account]);
+    assert_error(rt.send(ix, &[account, registry.authority]), MembershipError::RosterSealed);
+
+    let (ix, late) = apply_for_membership_ix(&mut rt, &registry);
+    assert_error(rt.send(ix, &[late.account, late.key]), MembershipError::RosterSealed);
+
+    let ix = approve_membership_ix(&rt, &registry, &applicant, None);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::RosterSealed);
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.member_count, 0);
+    assert!(member(&rt, &applicant).pending);
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use common::*;
+use membership::MemberType;
+
+/// A System Program transfer of `lamports` from `from` to `to`.
+fn transfer(from: Pubkey, to: Pubkey, lamports: u64) -> Instruction {
+    let mut data = 2u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&lamports.to_le_bytes());
+    Instruction {
+        program_id: anchor_lang::system_program::ID,
+        accounts: vec![AccountMeta::new(from, true), AccountMeta::new(to, false)],
+        data,
+    }
+}
+
+#[test]
+fn member_refunded_in_the_same_transaction_stays_closed() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 1);
+    let removed = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let funder = rt.wallet(10 * SOL);
+
+    let instructions = [
+        remove_member_ix(&rt, &registry, &removed, None),
+        transfer(funder, removed.account, SOL),
+        adjust_voting_power_ix(&rt, &registry, &removed, None, 5),
+    ];
+    let result = rt.send_all(&instructions, &[registry.authority, funder]);
+
+    assert!(matches!(result, Err(TxError::Instruction { index: 2, .. })));
+    assert_error(result, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+    // The failed transaction is rolled back, so the member is untouched.
+    assert_eq!(member(&rt, &removed).voting_power, 10);
+}
+
+#[test]
+fn refunded_member_account_is_not_a_member() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 1);
+    let removed = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let funder = rt.wallet(10 * SOL);
+
+    let instructions = [
+        remove_member_ix(&rt, &registry, &removed, None),
+        transfer(funder, removed.account, SOL),
+    ];
+    rt.send_all(&instructions, &[registry.authority, funder]).unwrap();
+
+    let account = rt.account(&removed.account).unwrap();
+    assert_eq!(account.owner, anchor_lang::system_program::ID);
+    assert!(account.data.is_empty());
+    let ix = adjust_voting_power_ix(&rt, &registry, &removed, None, 5);
+    assert_error(
+        rt.send(ix, &[registry.authority]),
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram,
+    );
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::*;
+use membership::{MemberRegistry, RegistryField};
+
+fn apply(rt: &mut Runtime, registry: &Registry) -> TestMember {
+    let (ix, member) = apply_for_membership_ix(rt, registry);
+    rt.send(ix, &[member.account, member.key]).unwrap();
+    member
+}
+
+fn approve(rt: &mut Runtime, registry: &Registry, member: &TestMember, voting_power: Option<u64>) {
+    let ix = approve_membership_ix(rt, registry, member, voting_power);
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+fn open_registry(rt: &mut Runtime) -> Registry {
+    let registry = create_registry(rt);
+    update_config(rt, &registry, RegistryField::SelfRegistration, 1);
+    update_config(rt, &registry, RegistryField::DefaultVotingPower, 25);
+    registry
+}
+
+#[test]
+fn approval_grants_the_default_voting_power() {
+    let mut rt = Runtime::new();
+    let registry = open_registry(&mut rt);
+    let applicant = apply(&mut rt, &registry);
+    let pending = member(&rt, &applicant);
+    assert!(pending.pending && !pending.is_active);
+    assert_eq!(pending.voting_power, 0);
+
+    approve(&mut rt, &registry, &applicant, None);
+
+    let approved = member(&rt, &applicant);
+    assert!(!approved.pending && approved.is_active);
+    assert_eq!(approved.voting_power, 25);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).total_voting_power, 25);
+}
+
+#[test]
+fn approval_may_override_the_default_voting_power() {
+    let mut rt = Runtime::new();
+    let registry = open_registry(&mut rt);
+    let applicant = apply(&mut rt, &registry);
+
+    approve(&mut rt, &registry, &applicant, Some(40));
+
+    assert_eq!(member(&rt, &applicant).voting_power, 40);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).total_voting_power, 40);
+}
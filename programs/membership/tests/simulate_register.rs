@@ -0,0 +1,233 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, NewMember, RegistrationCheck, RegistryField};
+
+/// Simulates registering `entry` into `member` with the registry's current
+/// nonce, paid for by a wallet holding `payer_lamports`.
+fn simulate(
+    rt: &mut Runtime,
+    registry: &Registry,
+    entry: NewMember,
+    member: Pubkey,
+    name_entry: Option<Pubkey>,
+    payer_lamports: u64,
+) -> RegistrationCheck {
+    let nonce = rt.get::<MemberRegistry>(&registry.key).registration_nonce;
+    simulate_with_nonce(rt, registry, entry, member, name_entry, payer_lamports, nonce)
+}
+
+fn simulate_with_nonce(
+    rt: &mut Runtime,
+    registry: &Registry,
+    entry: NewMember,
+    member: Pubkey,
+    name_entry: Option<Pubkey>,
+    payer_lamports: u64,
+    nonce: u64,
+) -> RegistrationCheck {
+    let payer = rt.wallet(payer_lamports);
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SimulateRegister {
+                registry: registry.key,
+                payer,
+                member,
+                name_entry,
+            },
+            membership::instruction::SimulateRegister { entry, nonce },
+        ),
+        &[payer],
+    )
+    .unwrap();
+    rt.returned()
+}
+
+/// Simulates `entry` into a fresh account with a well-funded payer.
+fn check(rt: &mut Runtime, registry: &Registry, entry: NewMember) -> RegistrationCheck {
+    simulate(rt, registry, entry, Pubkey::new_unique(), None, 10 * SOL)
+}
+
+fn ai_member(voting_power: u64) -> NewMember {
+    NewMember {
+        member_type: MemberType::AI,
+        ..new_member(voting_power)
+    }
+}
+
+#[test]
+fn valid_registration_simulates_ok_without_writing() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let before: MemberRegistry = rt.get(&registry.key);
+
+    assert_eq!(check(&mut rt, &registry, new_member(10)), RegistrationCheck::Ok);
+
+    let after: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(after.registration_nonce, before.registration_nonce);
+    assert_eq!(after.member_count, before.member_count);
+}
+
+#[test]
+fn stale_nonce_is_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    let stale = simulate_with_nonce(&mut rt, &registry, new_member(10), Pubkey::new_unique(), None, SOL, 0);
+    assert_eq!(stale, RegistrationCheck::InvalidNonce);
+}
+
+#[test]
+fn roster_limits_are_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let existing = add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    let duplicate = simulate(&mut rt, &registry, new_member(10), existing.account, None, SOL);
+    assert_eq!(duplicate, RegistrationCheck::DuplicateMember);
+
+    update_config(&mut rt, &registry, RegistryField::MaxMembers, 1);
+    assert_eq!(check(&mut rt, &registry, new_member(10)), RegistrationCheck::MaxMembersReached);
+
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SealRoster {
+                registry: registry.key,
+                audit_log: current_audit_log(&rt, &registry),
+                authority: registry.authority,
+            },
+            membership::instruction::SealRoster {},
+        ),
+        &[registry.authority],
+    )
+    .unwrap();
+    assert_eq!(check(&mut rt, &registry, new_member(10)), RegistrationCheck::RosterSealed);
+}
+
+#[test]
+fn name_problems_are_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let mut long_name = new_member(10);
+    long_name.legal_name = "x".repeat(membership::MAX_LEGAL_NAME_LEN + 1);
+    assert_eq!(check(&mut rt, &registry, long_name), RegistrationCheck::NameTooLong);
+
+    update_config(&mut rt, &registry, RegistryField::UniqueNames, 1);
+    let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+    args.legal_name = "Ada Lovelace".to_string();
+    let (name_entry, _) = membership::name_entry_address(&registry.key, "Ada Lovelace");
+    let (mut ix, holder) = add_member_ix(&rt, &registry, args);
+    // `name_entry` follows `treasury` in `AddMember`.
+    ix.accounts[5] = AccountMeta::new(name_entry, false);
+    rt.send(ix, &[holder.account, registry.authority]).unwrap();
+
+    let mut same_name = new_member(10);
+    same_name.legal_name = "Ada Lovelace".to_string();
+    let taken = simulate(&mut rt, &registry, same_name, Pubkey::new_unique(), Some(name_entry), SOL);
+    assert_eq!(taken, RegistrationCheck::NameTaken);
+}
+
+#[test]
+fn model_id_problems_are_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    let mut too_long = ai_member(10);
+    too_long.model_id = "m".repeat(membership::MAX_MODEL_ID_LEN + 1);
+    assert_eq!(check(&mut rt, &registry, too_long), RegistrationCheck::ModelIdTooLong);
+
+    let mut human_with_model = new_member(10);
+    human_with_model.model_id = "gpt".to_string();
+    assert_eq!(check(&mut rt, &registry, human_with_model), RegistrationCheck::ModelIdNotAllowed);
+
+    update_config(&mut rt, &registry, RegistryField::RequireModelId, 1);
+    assert_eq!(check(&mut rt, &registry, ai_member(10)), RegistrationCheck::ModelIdRequired);
+}
+
+#[test]
+fn disclosure_problems_are_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::RequireContactHash, 1);
+    assert_eq!(check(&mut rt, &registry, new_member(10)), RegistrationCheck::ContactHashRequired);
+
+    let mut with_contact = new_member(10);
+    with_contact.contact_hash = [1; 32];
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::SetAgreementHash {
+                registry: registry.key,
+                audit_log: current_audit_log(&rt, &registry),
+                authority: registry.authority,
+            },
+            membership::instruction::SetAgreementHash { agreement_hash: [7; 32] },
+        ),
+        &[registry.authority],
+    )
+    .unwrap();
+    assert_eq!(
+        check(&mut rt, &registry, with_contact.clone()),
+        RegistrationCheck::AgreementNotAcknowledged
+    );
+
+    with_contact.acknowledged_hash = [7; 32];
+    assert_eq!(check(&mut rt, &registry, with_contact), RegistrationCheck::Ok);
+}
+
+#[test]
+fn human_majority_is_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::RequireHumanMajorityCount, 1);
+
+    assert_eq!(check(&mut rt, &registry, ai_member(10)), RegistrationCheck::WouldBreakHumanMajority);
+    assert_eq!(check(&mut rt, &registry, new_member(10)), RegistrationCheck::Ok);
+}
+
+#[test]
+fn funding_and_power_problems_are_reported() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+
+    update_config(&mut rt, &registry, RegistryField::RegistrationFee, SOL);
+    let poor = simulate(&mut rt, &registry, new_member(10), Pubkey::new_unique(), None, SOL / 2);
+    assert_eq!(poor, RegistrationCheck::InsufficientFunds);
+
+    update_config(&mut rt, &registry, RegistryField::NormalizedTotal, 50);
+    assert_eq!(check(&mut rt, &registry, new_member(41)), RegistrationCheck::ExceedsNormalizedTotal);
+
+    update_config(&mut rt, &registry, RegistryField::MaxMemberPower, 30);
+    assert_eq!(check(&mut rt, &registry, new_member(31)), RegistrationCheck::ExceedsMemberPowerCap);
+}
+
+#[test]
+fn simulation_agrees_with_add_member() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+    update_config(&mut rt, &registry, RegistryField::RequireContactHash, 1);
+    update_config(&mut rt, &registry, RegistryField::MaxMemberPower, 30);
+
+    assert_eq!(check(&mut rt, &registry, new_member(10)), RegistrationCheck::ContactHashRequired);
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::ContactHashRequired);
+
+    let mut over_cap = new_member(31);
+    over_cap.contact_hash = [1; 32];
+    assert_eq!(check(&mut rt, &registry, over_cap), RegistrationCheck::ExceedsMemberPowerCap);
+    let mut args = member_args(&rt, &registry, MemberType::Human, 31);
+    args.contact_hash = [1; 32];
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::MemberPowerCapExceeded);
+
+    let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+    args.contact_hash = [1; 32];
+    args.nonce += 1;
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::InvalidNonce);
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MemberType, MembershipError, Snapshot};
+
+fn snapshot_members(rt: &mut Runtime, registry: &Registry, members: &[&TestMember]) -> std::result::Result<Pubkey, TxError> {
+    let snapshot = Pubkey::new_unique();
+    let mut ix = instruction(
+        membership::ID,
+        membership::accounts::SnapshotMembers {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            snapshot,
+            authority: registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::SnapshotMembers {},
+    );
+    ix.accounts
+        .extend(members.iter().map(|m| AccountMeta::new_readonly(m.account, false)));
+    rt.send(ix, &[snapshot, registry.authority])?;
+    Ok(snapshot)
+}
+
+#[test]
+fn snapshot_records_every_member() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let second = add_member(&mut rt, &registry, MemberType::AI, 20);
+    let third = add_member(&mut rt, &registry, MemberType::Human, 30);
+    rt.advance_slots(5);
+
+    let snapshot = snapshot_members(&mut rt, &registry, &[&first, &second, &third]).unwrap();
+
+    let record: Snapshot = rt.get(&snapshot);
+    assert_eq!(record.registry, registry.key);
+    assert_eq!(record.slot, rt.clock().slot);
+    assert_eq!(record.timestamp, rt.clock().unix_timestamp);
+    assert_eq!(record.entries.len(), 3);
+    for (entry, (test_member, is_ai)) in record
+        .entries
+        .iter()
+        .zip([(&first, false), (&second, true), (&third, false)])
+    {
+        let state = member(&rt, test_member);
+        assert_eq!(entry.id, state.id);
+        assert_eq!(entry.pubkey, test_member.key);
+        assert_eq!(entry.voting_power, state.voting_power);
+        assert_eq!(entry.is_ai, is_ai);
+    }
+
+    // Later changes leave the record as it was.
+    remove_member(&mut rt, &registry, &second);
+    assert_eq!(rt.get::<Snapshot>(&snapshot).entries.len(), 3);
+}
+
+#[test]
+fn snapshot_needs_the_whole_member_set() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let first = add_member(&mut rt, &registry, MemberType::Human, 10);
+    let second = add_member(&mut rt, &registry, MemberType::Human, 20);
+
+    assert_error(
+        snapshot_members(&mut rt, &registry, &[&first]),
+        MembershipError::IncompleteMemberSet,
+    );
+    assert_error(
+        snapshot_members(&mut rt, &registry, &[&first, &first]),
+        MembershipError::IncompleteMemberSet,
+    );
+    snapshot_members(&mut rt, &registry, &[&second, &first]).unwrap();
+}
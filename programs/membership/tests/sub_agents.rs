@@ -0,0 +1,119 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{AuditLog, Member, MemberRegistry, MemberType, MembershipError};
+
+fn register_sub_agent(
+    rt: &mut Runtime,
+    registry: &Registry,
+    parent: &TestMember,
+    voting_power: u64,
+) -> std::result::Result<TestMember, TxError> {
+    let agent = TestMember {
+        account: Pubkey::new_unique(),
+        key: Pubkey::new_unique(),
+    };
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::RegisterSubAgent {
+            registry: registry.key,
+            parent: parent.account,
+            member: agent.account,
+            member_pubkey: agent.key,
+            treasury: None,
+            name_entry: None,
+            owner: parent.key,
+            system_program: anchor_lang::system_program::ID,
+        },
+        membership::instruction::RegisterSubAgent {
+            voting_power,
+            legal_name: format!("Agent {}", agent.key),
+            address: "1 Main St, Cheyenne WY".to_string(),
+            tax_id: String::new(),
+            model_id: String::new(),
+            contact_hash: [0; 32],
+            acknowledged_hash: [0; 32],
+        },
+    );
+    rt.send(ix, &[agent.account, parent.key])?;
+    Ok(agent)
+}
+
+/// A human parent with 100 power and two more humans, so AI sub-agents
+/// never outnumber humans.
+fn setup(rt: &mut Runtime) -> (Registry, TestMember) {
+    let registry = create_registry(rt);
+    let parent = add_member(rt, &registry, MemberType::Human, 100);
+    add_member(rt, &registry, MemberType::Human, 1);
+    add_member(rt, &registry, MemberType::Human, 1);
+    (registry, parent)
+}
+
+#[test]
+fn sub_agents_share_the_parents_power_cap() {
+    let mut rt = Runtime::new();
+    let (registry, parent) = setup(&mut rt);
+
+    let first = register_sub_agent(&mut rt, &registry, &parent, 60).unwrap();
+    assert_error(
+        register_sub_agent(&mut rt, &registry, &parent, 41),
+        MembershipError::SubAgentPowerExceeded,
+    );
+    register_sub_agent(&mut rt, &registry, &parent, 40).unwrap();
+    assert_eq!(member(&rt, &parent).sub_agent_power, 100);
+    let agent = member(&rt, &first);
+    assert!(agent.member_type == MemberType::AI);
+    assert_eq!(agent.parent, Some(member(&rt, &parent).id));
+
+    let ix = adjust_voting_power_ix(&rt, &registry, &first, Some(parent.account), 1);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::SubAgentPowerExceeded);
+    let ix = adjust_voting_power_ix(&rt, &registry, &first, Some(parent.account), -10);
+    rt.send(ix, &[registry.authority]).unwrap();
+    assert_eq!(member(&rt, &parent).sub_agent_power, 90);
+}
+
+#[test]
+fn parent_with_sub_agents_cannot_be_removed_or_leave() {
+    let mut rt = Runtime::new();
+    let (registry, parent) = setup(&mut rt);
+    let agent = register_sub_agent(&mut rt, &registry, &parent, 30).unwrap();
+
+    let ix = remove_member_ix(&rt, &registry, &parent, None);
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::HasSubAgents);
+    let ix = leave_membership_ix(&rt, &registry, &parent, None, &[]);
+    assert_error(rt.send(ix, &[parent.key]), MembershipError::HasSubAgents);
+
+    let ix = remove_member_ix(&rt, &registry, &agent, Some(parent.account));
+    rt.send(ix, &[registry.authority]).unwrap();
+    assert_eq!(member(&rt, &parent).sub_agent_power, 0);
+    remove_member(&mut rt, &registry, &parent);
+}
+
+#[test]
+fn drifted_parent_allocation_is_reported_instead_of_wrapping() {
+    let mut rt = Runtime::new();
+    let (registry, parent) = setup(&mut rt);
+    let agent = register_sub_agent(&mut rt, &registry, &parent, 30).unwrap();
+    rt.update(&parent.account, |state: &mut Member| state.sub_agent_power = 10);
+
+    let ix = remove_member_ix(&rt, &registry, &agent, Some(parent.account));
+    assert_error(rt.send(ix, &[registry.authority]), MembershipError::SubAgentPowerMismatch);
+}
+
+#[test]
+fn sub_agents_pass_the_shared_registration_checks() {
+    let mut rt = Runtime::new();
+    let (registry, parent) = setup(&mut rt);
+    rt.update(&registry.key, |state: &mut MemberRegistry| state.registration_fee = u64::MAX);
+
+    assert_error(
+        register_sub_agent(&mut rt, &registry, &parent, 30),
+        MembershipError::InsufficientFunds,
+    );
+
+    rt.update(&registry.key, |state: &mut MemberRegistry| state.registration_fee = 0);
+    let entries = rt.get::<AuditLog>(&current_audit_log(&rt, &registry)).next_offset;
+    register_sub_agent(&mut rt, &registry, &parent, 30).unwrap();
+    assert_eq!(rt.get::<AuditLog>(&current_audit_log(&rt, &registry)).next_offset, entries);
+}
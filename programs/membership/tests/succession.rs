@@ -0,0 +1,93 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{AuthorityClaimed, MemberRegistry, MemberType, MembershipError, RegistryField};
+
+const TIMEOUT_SLOTS: u64 = 100;
+
+/// A registry whose authority can be succeeded by a fresh wallet after
+/// `TIMEOUT_SLOTS` of inactivity. Returns the registry and the successor.
+fn registry_with_successor(rt: &mut Runtime) -> (Registry, Pubkey) {
+    let registry = create_registry(rt);
+    let successor = rt.wallet(SOL);
+    update_config(rt, &registry, RegistryField::SuccessionTimeoutSlots, TIMEOUT_SLOTS);
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetSuccessor {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, &registry),
+            authority: registry.authority,
+        },
+        membership::instruction::SetSuccessor { successor },
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+    (registry, successor)
+}
+
+fn claim_authority(
+    rt: &mut Runtime,
+    registry: &Registry,
+    claimant: Pubkey,
+) -> std::result::Result<(), TxError> {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::ClaimAuthority {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            successor: claimant,
+        },
+        membership::instruction::ClaimAuthority {},
+    );
+    rt.send(ix, &[claimant])
+}
+
+#[test]
+fn successor_takes_over_after_the_timeout() {
+    let mut rt = Runtime::new();
+    let (registry, successor) = registry_with_successor(&mut rt);
+
+    rt.advance_slots(TIMEOUT_SLOTS);
+    claim_authority(&mut rt, &registry, successor).unwrap();
+
+    let state: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(state.authority, successor);
+    assert_eq!(state.successor, Pubkey::default());
+    let events = rt.events::<AuthorityClaimed>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].previous_authority, registry.authority);
+    assert_eq!(events[0].new_authority, successor);
+
+    let args = member_args(&rt, &registry, MemberType::Human, 10);
+    assert_error(try_add_member(&mut rt, &registry, args), MembershipError::NotAuthorized);
+    let new_authority = Registry {
+        authority: successor,
+        ..registry
+    };
+    add_member(&mut rt, &new_authority, MemberType::Human, 10);
+}
+
+#[test]
+fn claim_before_the_timeout_is_refused() {
+    let mut rt = Runtime::new();
+    let (registry, successor) = registry_with_successor(&mut rt);
+
+    rt.advance_slots(TIMEOUT_SLOTS - 1);
+    assert_error(claim_authority(&mut rt, &registry, successor), MembershipError::SuccessionNotDue);
+
+    // Any authority action restarts the clock.
+    add_member(&mut rt, &registry, MemberType::Human, 10);
+    rt.advance_slots(1);
+    assert_error(claim_authority(&mut rt, &registry, successor), MembershipError::SuccessionNotDue);
+    assert_eq!(rt.get::<MemberRegistry>(&registry.key).authority, registry.authority);
+}
+
+#[test]
+fn only_the_named_successor_may_claim() {
+    let mut rt = Runtime::new();
+    let (registry, _) = registry_with_successor(&mut rt);
+    let stranger = rt.wallet(SOL);
+
+    rt.advance_slots(TIMEOUT_SLOTS);
+    assert_error(claim_authority(&mut rt, &registry, stranger), MembershipError::NotAuthorized);
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{MembershipError, RegistryField, SuspiciousActivity};
+
+fn update_config_as(rt: &mut Runtime, registry: &Registry, signer: Pubkey) -> std::result::Result<(), TxError> {
+    rt.send(
+        instruction(
+            membership::ID,
+            membership::accounts::UpdateConfig {
+                registry: registry.key,
+                audit_log: current_audit_log(rt, registry),
+                signer,
+            },
+            membership::instruction::UpdateConfig {
+                field: RegistryField::MaxMembers,
+                value: 1,
+            },
+        ),
+        &[signer],
+    )
+}
+
+#[test]
+fn each_failed_authority_check_emits_an_event() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let intruder = rt.wallet(SOL);
+
+    for _ in 0..3 {
+        assert_error(update_config_as(&mut rt, &registry, intruder), MembershipError::NotAuthorized);
+        let events = rt.events::<SuspiciousActivity>();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].authority, registry.authority);
+        assert_eq!(events[0].signer, intruder);
+        assert_eq!(events[0].slot, rt.clock().slot);
+        rt.advance_slots(1);
+    }
+
+    update_config_as(&mut rt, &registry, registry.authority).unwrap();
+    assert!(rt.events::<SuspiciousActivity>().is_empty());
+}
+
+#[test]
+fn silenced_registry_emits_nothing() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    update_config(&mut rt, &registry, RegistryField::EventVerbosity, 0);
+    let intruder = rt.wallet(SOL);
+
+    assert_error(update_config_as(&mut rt, &registry, intruder), MembershipError::NotAuthorized);
+    assert!(rt.events::<SuspiciousActivity>().is_empty());
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use membership::{to_pubkey, MembershipError};
+
+#[test]
+fn to_pubkey_accepts_exactly_32_bytes() {
+    let key = Pubkey::new_unique();
+    assert_eq!(to_pubkey(key.as_ref()).unwrap(), key);
+}
+
+#[test]
+fn to_pubkey_rejects_other_lengths_without_panicking() {
+    for len in [0, 31, 33, 64] {
+        let err = to_pubkey(&vec![7; len]).unwrap_err();
+        assert_eq!(err, MembershipError::InvalidAccountData.into());
+    }
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use common::*;
+use membership::{MemberRegistry, MemberType, MembershipError, RegistryField, SuspiciousActivity};
+
+fn transfer_ix(rt: &Runtime, registry: &Registry, from: &TestMember, to: &TestMember, signer: Pubkey, amount: u64) -> Instruction {
+    instruction(
+        membership::ID,
+        membership::accounts::TransferVotingPower {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            from: from.account,
+            to: to.account,
+            signer,
+        },
+        membership::instruction::TransferVotingPower {
+            from_id: member(rt, from).id,
+            to_id: member(rt, to).id,
+            amount,
+        },
+    )
+}
+
+fn set_vesting(rt: &mut Runtime, registry: &Registry, member: &TestMember, duration: i64, total: u64) {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::SetVesting {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+        },
+        membership::instruction::SetVesting {
+            member_id: common::member(rt, member).id,
+            start: rt.clock().unix_timestamp,
+            duration,
+            total,
+        },
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+#[test]
+fn transfer_moves_power_and_conserves_the_total() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let from = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let to = add_member(&mut rt, &registry, MemberType::AI, 20);
+    let before: MemberRegistry = rt.get(&registry.key);
+
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 30);
+    rt.send(ix, &[from.key]).unwrap();
+
+    assert_eq!(member(&rt, &from).voting_power, 70);
+    assert_eq!(member(&rt, &to).voting_power, 50);
+    let after: MemberRegistry = rt.get(&registry.key);
+    assert_eq!(after.total_voting_power, before.total_voting_power);
+    assert_eq!(after.human_voting_power, before.human_voting_power - 30);
+    assert_eq!(after.ai_voting_power, before.ai_voting_power + 30);
+}
+
+#[test]
+fn transfer_rejects_underflow_cap_and_strangers() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let from = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let to = add_member(&mut rt, &registry, MemberType::Human, 20);
+    update_config(&mut rt, &registry, RegistryField::MaxMemberPower, 100);
+
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 101);
+    assert_error(rt.send(ix, &[from.key]), MembershipError::PowerUnderflow);
+
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 81);
+    assert_error(rt.send(ix, &[from.key]), MembershipError::MemberPowerCapExceeded);
+
+    let ix = transfer_ix(&rt, &registry, &from, &to, to.key, 10);
+    assert_error(rt.send(ix, &[to.key]), MembershipError::NotAuthorized);
+    let events = rt.events::<SuspiciousActivity>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].signer, to.key);
+
+    let ix = transfer_ix(&rt, &registry, &from, &to, registry.authority, 80);
+    rt.send(ix, &[registry.authority]).unwrap();
+    assert_eq!(member(&rt, &to).voting_power, 100);
+}
+
+#[test]
+fn only_vested_power_can_be_transferred() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+    let from = add_member(&mut rt, &registry, MemberType::Human, 100);
+    let to = add_member(&mut rt, &registry, MemberType::Human, 20);
+    set_vesting(&mut rt, &registry, &from, 100, 60);
+
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 41);
+    assert_error(rt.send(ix, &[from.key]), MembershipError::PowerStillVesting);
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 40);
+    rt.send(ix, &[from.key]).unwrap();
+
+    rt.advance_time(50);
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 31);
+    assert_error(rt.send(ix, &[from.key]), MembershipError::PowerStillVesting);
+    let ix = transfer_ix(&rt, &registry, &from, &to, from.key, 30);
+    rt.send(ix, &[from.key]).unwrap();
+
+    // The 30 still locked keep vesting on the original schedule.
+    let state = member(&rt, &from);
+    assert_eq!(state.voting_power, 30);
+    assert_eq!(membership::vested_power(&state, rt.clock().unix_timestamp), 0);
+    assert_eq!(membership::vested_power(&state, rt.clock().unix_timestamp + 50), 30);
+}
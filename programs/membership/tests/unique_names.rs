@@ -0,0 +1,96 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use membership::{name_entry_address, MemberType, MembershipError, NameEntry, RegistryField};
+
+fn registry_with_unique_names(rt: &mut Runtime) -> Registry {
+    let registry = create_registry(rt);
+    update_config(rt, &registry, RegistryField::UniqueNames, 1);
+    registry
+}
+
+fn add_named(
+    rt: &mut Runtime,
+    registry: &Registry,
+    legal_name: &str,
+) -> std::result::Result<TestMember, TxError> {
+    let mut args = member_args(rt, registry, MemberType::Human, 10);
+    args.legal_name = legal_name.to_string();
+    let member = TestMember {
+        account: Pubkey::new_unique(),
+        key: Pubkey::new_unique(),
+    };
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::AddMember {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            member_pubkey: member.key,
+            treasury: None,
+            name_entry: Some(name_entry_address(&registry.key, legal_name).0),
+            authority: registry.authority,
+            system_program: anchor_lang::system_program::ID,
+        },
+        args,
+    );
+    rt.send(ix, &[member.account, registry.authority])?;
+    Ok(member)
+}
+
+fn remove_named(rt: &mut Runtime, registry: &Registry, member: &TestMember, legal_name: &str) {
+    let ix = instruction(
+        membership::ID,
+        membership::accounts::RemoveMember {
+            registry: registry.key,
+            audit_log: current_audit_log(rt, registry),
+            member: member.account,
+            authority: registry.authority,
+            name_entry: Some(name_entry_address(&registry.key, legal_name).0),
+            parent: None,
+        },
+        membership::instruction::RemoveMember {},
+    );
+    rt.send(ix, &[registry.authority]).unwrap();
+}
+
+#[test]
+fn second_member_with_the_same_name_is_refused() {
+    let mut rt = Runtime::new();
+    let registry = registry_with_unique_names(&mut rt);
+
+    let first = add_named(&mut rt, &registry, "Ada Lovelace").unwrap();
+    let entry: NameEntry = rt.get(&name_entry_address(&registry.key, "Ada Lovelace").0);
+    assert_eq!(entry.registry, registry.key);
+    assert_eq!(entry.member, first.account);
+
+    assert_error(add_named(&mut rt, &registry, "Ada Lovelace"), MembershipError::MemberAlreadyExists);
+    add_named(&mut rt, &registry, "Ada King").unwrap();
+}
+
+#[test]
+fn removing_a_member_frees_their_name() {
+    let mut rt = Runtime::new();
+    let registry = registry_with_unique_names(&mut rt);
+    let first = add_named(&mut rt, &registry, "Ada Lovelace").unwrap();
+
+    remove_named(&mut rt, &registry, &first, "Ada Lovelace");
+    assert_eq!(rt.lamports(&name_entry_address(&registry.key, "Ada Lovelace").0), 0);
+
+    let second = add_named(&mut rt, &registry, "Ada Lovelace").unwrap();
+    let entry: NameEntry = rt.get(&name_entry_address(&registry.key, "Ada Lovelace").0);
+    assert_eq!(entry.member, second.account);
+}
+
+#[test]
+fn names_may_repeat_when_uniqueness_is_off() {
+    let mut rt = Runtime::new();
+    let registry = create_registry(&mut rt);
+
+    for _ in 0..2 {
+        let mut args = member_args(&rt, &registry, MemberType::Human, 10);
+        args.legal_name = "Ada Lovelace".to_string();
+        try_add_member(&mut rt, &registry, args).unwrap();
+    }
+}
@@ -30,7 +30,9 @@ async function main(){
   await program.methods.initialize(
     'MicroAI DAO LLC',
     '1621 Central Ave, Cheyenne, WY 82001',
-    '123 Innovation Drive, Tech City, CA 94000'
+    '123 Innovation Drive, Tech City, CA 94000',
+    process.env.LEGAL_ENTITY_ID || '',
+    'Wyoming'
   ).accounts({
     dao: dao.publicKey,
     authority: wallet.publicKey,
@@ -43,7 +45,11 @@ async function main(){
   await program.methods.createProposal(
     'Fund Wyoming DAO LLC Registration',
     'Allocate funds for legal registration and compliance',
-    new anchor.BN(1000)
+    new anchor.BN(1000),
+    { none: {} },
+    null,
+    { operational: {} },
+    { all: {} }
   ).accounts({
     dao: dao.publicKey,
     proposal: proposal.publicKey,
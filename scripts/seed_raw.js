@@ -40,6 +40,8 @@ async function main(){
     encodeString('MicroAI DAO LLC'),
     encodeString('1621 Central Ave, Cheyenne, WY 82001'),
     encodeString('123 Innovation Drive, Tech City, CA 94000'),
+    encodeString(process.env.LEGAL_ENTITY_ID || ''), // state filing ID, if already issued
+    encodeString('Wyoming'),
   ]);
 
   const dao = Keypair.generate();
@@ -66,6 +68,10 @@ async function main(){
     encodeString('Fund Wyoming DAO LLC Registration'),
     encodeString('Allocate funds for legal registration and compliance'),
     u64ToLE(1000),
+    Buffer.from([0]), // ExecutionPayload::None
+    Buffer.from([0]), // no quorum override
+    Buffer.from([0]), // SpendingCategory::Operational
+    Buffer.from([0]), // VoterEligibility::All
   ]);
 
   const cpIx = new TransactionInstruction({
@@ -4,15 +4,27 @@ const { Connection, PublicKey, Keypair, SystemProgram, Transaction, TransactionI
 
 const RPC_URL = process.env.RPC_URL || 'https://api.devnet.solana.com';
 const PROGRAM_ID = new PublicKey(process.env.GOVERNANCE_PROGRAM_ID || '6amHFyNoPK9MmbBKqthLMeoxTB4TV7CdVE5K4RXi1eDC');
+const MEMBERSHIP_PROGRAM_ID = new PublicKey(process.env.MEMBERSHIP_PROGRAM_ID || 'FotEuL6PaHRDYuDmtqNrbbS52AwVX49MQSBjNwCWqRA4');
 
 function usage() {
-  console.error('Usage: node vote_raw.js <proposal_pubkey> <approve|reject>');
+  console.error('Usage: node vote_raw.js <proposal_pubkey> <member_pubkey> <approve|reject> [rationale]');
   process.exit(1);
 }
 
+// Dao layout: authority, proposal_count, member_count, three strings,
+// formation_date, two strings, then the linked registry.
+function readDaoRegistry(data){
+  let offset = 8 + 32 + 8 + 8;
+  const skipStr = () => { offset += 4 + data.readUInt32LE(offset); };
+  skipStr(); skipStr(); skipStr();
+  offset += 8;
+  skipStr(); skipStr();
+  return new PublicKey(data.slice(offset, offset + 32));
+}
+
 async function main(){
-  const [proposalArg, decisionArg] = process.argv.slice(2);
-  if (!proposalArg || !decisionArg) usage();
+  const [proposalArg, memberArg, decisionArg, rationaleArg = ''] = process.argv.slice(2);
+  if (!proposalArg || !memberArg || !decisionArg) usage();
   const approve = /^(approve|true|yes|1)$/i.test(decisionArg);
 
   const connection = new Connection(RPC_URL, 'confirmed');
@@ -20,21 +32,49 @@ async function main(){
     Uint8Array.from(JSON.parse(fs.readFileSync(path.join(process.env.HOME, '.config/solana/id.json'), 'utf8')))
   );
   const proposal = new PublicKey(proposalArg);
-  const voteRecord = Keypair.generate();
+  const member = new PublicKey(memberArg);
+  const proposalInfo = await connection.getAccountInfo(proposal);
+  const dao = new PublicKey(proposalInfo.data.slice(8, 40));
+  const daoInfo = await connection.getAccountInfo(dao);
+  const registry = readDaoRegistry(daoInfo.data);
+  const [voteRecord] = PublicKey.findProgramAddressSync(
+    [Buffer.from('vote'), proposal.toBuffer(), member.toBuffer()],
+    PROGRAM_ID
+  );
+  const [governanceSigner] = PublicKey.findProgramAddressSync(
+    [Buffer.from('governance'), dao.toBuffer()],
+    PROGRAM_ID
+  );
 
   // Discriminator for "vote" from IDL
   const disc = Buffer.from([227,110,155,23,136,126,172,25]);
-  const data = Buffer.concat([disc, Buffer.from([approve ? 1 : 0])]);
+  const rationale = Buffer.from(rationaleArg, 'utf8');
+  const rationaleLen = Buffer.alloc(4);
+  rationaleLen.writeUInt32LE(rationale.length);
+  const data = Buffer.concat([disc, Buffer.from([approve ? 1 : 0]), rationaleLen, rationale]);
 
   const keys = [
+    { pubkey: dao, isSigner: false, isWritable: true },
+    { pubkey: registry, isSigner: false, isWritable: false },
     { pubkey: proposal, isSigner: false, isWritable: true },
-    { pubkey: voteRecord.publicKey, isSigner: true, isWritable: true },
+    { pubkey: member, isSigner: false, isWritable: true },
+    { pubkey: voteRecord, isSigner: false, isWritable: true },
     { pubkey: voter.publicKey, isSigner: true, isWritable: true },
+    { pubkey: governanceSigner, isSigner: false, isWritable: false },
+    { pubkey: MEMBERSHIP_PROGRAM_ID, isSigner: false, isWritable: false },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    // No AI sponsor co-signing; the program ID stands in for the optional account
+    { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    // Nor an epoch snapshot; live-power proposals do not take one
+    { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    // Nor a vote commitment, which only reveal_vote takes
+    { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    // Nor the Instructions sysvar; the voter signs for the member here
+    { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
   ];
   const ix = new TransactionInstruction({ programId: PROGRAM_ID, keys, data });
   const tx = new Transaction().add(ix);
-  const sig = await sendAndConfirmTransaction(connection, tx, [voter, voteRecord], { commitment: 'confirmed' });
+  const sig = await sendAndConfirmTransaction(connection, tx, [voter], { commitment: 'confirmed' });
   console.log('Vote sent, signature:', sig);
 }
 